@@ -3,7 +3,7 @@ use yarge::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
 use yarge::{
     Entry, Event, Game, event_builder,
-    keyboard::{KeyboardKey, Special},
+    keyboard::{KeyboardKey, KeyboardModifiers, Special},
     mouse::MouseButton,
 };
 
@@ -35,6 +35,9 @@ impl Game for TestBedGame {
     fn on_keyboard_key_pressed(
         &mut self,
         keyboard_key: KeyboardKey,
+        _physical_key: KeyboardKey,
+        _scancode: u32,
+        _modifiers: KeyboardModifiers,
     ) -> Result<VecDeque<Event>, ErrorType> {
         let mut events = VecDeque::new();
         log_info!("Keyboard's {:?} key pressed", keyboard_key);
@@ -47,6 +50,9 @@ impl Game for TestBedGame {
     fn on_keyboard_key_released(
         &mut self,
         keyboard_key: KeyboardKey,
+        _physical_key: KeyboardKey,
+        _scancode: u32,
+        _modifiers: KeyboardModifiers,
     ) -> Result<VecDeque<Event>, ErrorType> {
         log_info!("Keyboard's {:?} key released", keyboard_key);
         Ok(VecDeque::new())
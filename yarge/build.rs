@@ -51,17 +51,21 @@ fn main() {
     cfg_aliases! {
         // Platforms
         linux_platform: { target_os = "linux" },
-        x11_platform: { all(feature = "x", linux_platform) },
+        x11_platform: { all(feature = "x", linux_platform, not(feature = "headless")) },
         wayland_platform: { all(feature = "wayland", linux_platform) },
         web_platform: { all(target_family = "wasm", target_os = "unknown") },
         windows_platform: { target_os = "windows" },
         macos_platform: { target_os = "macos" },
+        headless_platform: { feature = "headless" },
         // Renderers
         vulkan_renderer: { all(feature = "vulkan") },
         opengl_renderer: { all(feature = "opengl") },
         wgpu_renderer: { all(feature = "wgpu") },
         directx_renderer: { all(feature = "directx") },
         metal_renderer: { all(feature = "metal") },
+        // Logging bridges
+        log_bridge: { feature = "log" },
+        tracing_bridge: { feature = "tracing" },
         // STD usage
         bare_metal: { all(feature = "no_std") },
     }
@@ -1,10 +1,13 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Serialize};
+
 use crate::config::Version;
 
 /// The configuration for the application
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub(crate) struct ApplicationConfig {
     /// The application's name
     pub(crate) name: String,
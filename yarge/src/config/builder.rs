@@ -0,0 +1,285 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::path::Path;
+
+use crate::{
+    LogFilter, LogLevel, LogSink, LogTarget,
+    config::{Config, Version, layers::ConfigLayers},
+    error::{WarningType, report_warning},
+    maths::Vector2f32,
+    renderer_types::RenderingApplicationType,
+};
+
+#[cfg(vulkan_renderer)]
+use crate::renderer_types::{formats::ImageFormat, present::PresentMode};
+
+/// A builder to create the engine configuration from code
+/// Any value that is not set keeps its default value
+/// # Examples
+/// ```no_run
+/// use yarge::config::{ConfigBuilder, Version};
+///
+/// let config = ConfigBuilder::default()
+///     .window_title("MyGame")
+///     .window_size(0.5, 0.5)
+///     .window_position(0.25, 0.25)
+///     .application_name("MyGame")
+///     .application_version(Version::default().major(2))
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct ConfigBuilder {
+    /// The configuration being built
+    config: Config,
+}
+
+impl ConfigBuilder {
+    /// Starts from a TOML configuration file instead of the default configuration
+    pub fn from_file(config_file: &Path) -> Result<Self, ErrorType> {
+        match ConfigLayers::default()
+            .file(config_file)
+            .and_then(ConfigLayers::build)
+        {
+            Ok(config) => Ok(Self { config }),
+            Err(err) => {
+                log_error!(
+                    "Failed to read the configuration file when creating a config builder: {:?}",
+                    err
+                );
+                Err(err)
+            }
+        }
+    }
+
+    /// Sets the window's title
+    pub fn window_title(mut self, title: &str) -> Self {
+        self.config.window_config.title = String::from(title);
+        self
+    }
+
+    /// Sets the window's position
+    /// The position must be between [0., 0.] (left, top) and [1., 1.] (right, bottom)
+    pub fn window_position(mut self, x: f32, y: f32) -> Self {
+        self.config.window_config.position = Vector2f32::new(x, y);
+        self
+    }
+
+    /// Sets the window's size
+    /// The width and height must be between 0. and 1., 1. being the screen size
+    pub fn window_size(mut self, width: f32, height: f32) -> Self {
+        self.config.window_config.width = width;
+        self.config.window_config.height = height;
+        self
+    }
+
+    /// Sets the window's border width in pixels
+    pub fn window_border_width(mut self, border_width: u16) -> Self {
+        self.config.window_config.border_width = border_width;
+        self
+    }
+
+    /// Sets the `LC_CTYPE' locale from the environment before opening the window
+    /// Needed for the dead keys and compose sequences of the text input, see [crate::Game::on_text_input]
+    pub fn window_use_system_locale(mut self, use_system_locale: bool) -> Self {
+        self.config.window_config.use_system_locale = use_system_locale;
+        self
+    }
+
+    /// Sets the minimum level of the displayed logs
+    pub fn log_level(mut self, min_level: LogLevel) -> Self {
+        self.config.logger_config.min_level = min_level;
+        self
+    }
+
+    /// Sets the minimum level of the displayed logs for a module and its sub modules
+    /// Overrides the global minimum level, see [ConfigBuilder::log_level]
+    pub fn log_filter(mut self, module: &str, min_level: LogLevel) -> Self {
+        self.config.logger_config.filters.push(LogFilter {
+            module: String::from(module),
+            min_level,
+        });
+        self
+    }
+
+    /// Sets where to log
+    pub fn log_target(mut self, target: LogTarget) -> Self {
+        self.config.logger_config.target = target;
+        self
+    }
+
+    /// Adds an output receiving the logs alongside the main target
+    pub fn log_sink(mut self, sink: LogSink) -> Self {
+        self.config.logger_config.sinks.push(sink);
+        self
+    }
+
+    /// Sets the application's name
+    pub fn application_name(mut self, name: &str) -> Self {
+        self.config.application_config.name = String::from(name);
+        self
+    }
+
+    /// Sets the application's version
+    pub fn application_version(mut self, version: Version) -> Self {
+        self.config.application_config.version = version;
+        self
+    }
+
+    /// Enables or disables the crash reports written when panicking
+    pub fn crash_report(mut self, enabled: bool) -> Self {
+        self.config.crash_report_config.enabled = enabled;
+        self
+    }
+
+    /// Sets the directory where the crash reports are written
+    pub fn crash_report_directory(mut self, directory: &Path) -> Self {
+        self.config.crash_report_config.directory = directory.to_path_buf();
+        self
+    }
+
+    /// Sets the maximum number of recent log lines in a crash report
+    pub fn crash_report_log_lines(mut self, log_lines: usize) -> Self {
+        self.config.crash_report_config.log_lines = log_lines;
+        self
+    }
+
+    /// Sets the maximum number of distinct warnings kept per frame, 0 meaning no limit
+    pub fn max_warnings_per_frame(mut self, max_per_frame: usize) -> Self {
+        self.config.warnings_config.max_per_frame = max_per_frame;
+        self
+    }
+
+    /// Sets the frame duration in milliseconds above which a `SlowFrame` warning is raised
+    /// 0 disables the check
+    pub fn slow_frame_threshold(mut self, slow_frame_threshold: u64) -> Self {
+        self.config.warnings_config.slow_frame_threshold = slow_frame_threshold;
+        self
+    }
+
+    /// Sets the number of fixed updates per second
+    /// See [crate::Game::on_fixed_update]
+    pub fn fixed_step_rate(mut self, step_rate: u32) -> Self {
+        self.config.timestep_config.step_rate = step_rate;
+        self
+    }
+
+    /// Sets the maximum number of fixed updates run in a single frame
+    pub fn max_catch_up_steps(mut self, max_catch_up_steps: u32) -> Self {
+        self.config.timestep_config.max_catch_up_steps = max_catch_up_steps;
+        self
+    }
+
+    /// Sets the maximum number of frames per second while the window is focused
+    /// 0 means no limit, the default cap is 60
+    pub fn target_fps(mut self, target_fps: u32) -> Self {
+        self.config.frame_limiter_config.target_fps = target_fps;
+        self
+    }
+
+    /// Sets the maximum number of frames per second while the window is unfocused
+    /// 0 means no limit other than the target one
+    pub fn unfocused_fps(mut self, unfocused_fps: u32) -> Self {
+        self.config.frame_limiter_config.unfocused_fps = unfocused_fps;
+        self
+    }
+
+    /// Sets the maximum number of frames per second while the window is minimized
+    /// 0 means no limit other than the target and unfocused ones
+    pub fn minimized_fps(mut self, minimized_fps: u32) -> Self {
+        self.config.frame_limiter_config.minimized_fps = minimized_fps;
+        self
+    }
+
+    /// Sets the virtual screen's size in pixels of the headless platform
+    #[cfg(headless_platform)]
+    pub fn headless_screen_size(mut self, width: u16, height: u16) -> Self {
+        self.config.headless_config.screen_width = width;
+        self.config.headless_config.screen_height = height;
+        self
+    }
+
+    /// Sets the time between two frames in milliseconds of the headless platform
+    /// 0 runs the frames as fast as possible
+    #[cfg(headless_platform)]
+    pub fn headless_frame_period(mut self, frame_period: u64) -> Self {
+        self.config.headless_config.frame_period = frame_period;
+        self
+    }
+
+    /// Sets the number of frames before the virtual window of the headless platform is closed
+    /// 0 never closes it
+    #[cfg(headless_platform)]
+    pub fn headless_max_frames(mut self, max_frames: u64) -> Self {
+        self.config.headless_config.max_frames = max_frames;
+        self
+    }
+
+    /// Adds an event replayed by the virtual window of the headless platform
+    /// The event is sent during the given frame, the first frame being 0
+    #[cfg(headless_platform)]
+    pub fn headless_event(mut self, frame: u64, event: crate::PlatformEvent) -> Self {
+        let script = &mut self.config.headless_config.script;
+        // Keeps the script sorted by frame, in insertion order for the same frame
+        let index = script.partition_point(|(other_frame, _)| *other_frame <= frame);
+        script.insert(index, (frame, event));
+        self
+    }
+
+    /// Sets the type of rendering application
+    pub fn application_type(mut self, application_type: RenderingApplicationType) -> Self {
+        self.config.renderer_config.application_type = application_type;
+        self
+    }
+
+    /// Sets the preferred swapchain present modes in order of preference
+    #[cfg(vulkan_renderer)]
+    pub fn preferred_present_modes(mut self, present_modes: &[PresentMode]) -> Self {
+        self.config
+            .renderer_config
+            .vulkan_parameters
+            .preferred_swapchain_present_modes = present_modes.to_vec();
+        self
+    }
+
+    /// Sets the preferred swapchain formats in order of preference
+    #[cfg(vulkan_renderer)]
+    pub fn preferred_swapchain_formats(mut self, formats: &[ImageFormat]) -> Self {
+        self.config
+            .renderer_config
+            .vulkan_parameters
+            .preferred_swapchain_formats = formats.to_vec();
+        self
+    }
+
+    /// Sets the preferred swapchain present modes in order of preference
+    /// Deprecated, see [ConfigBuilder::preferred_present_modes]
+    #[cfg(vulkan_renderer)]
+    #[deprecated(note = "use `preferred_present_modes' instead")]
+    pub fn prefered_present_modes(self, present_modes: &[PresentMode]) -> Self {
+        report_warning(WarningType::DeprecatedApi(
+            String::from("ConfigBuilder::prefered_present_modes"),
+            String::from("ConfigBuilder::preferred_present_modes"),
+        ));
+        self.preferred_present_modes(present_modes)
+    }
+
+    /// Sets the preferred swapchain formats in order of preference
+    /// Deprecated, see [ConfigBuilder::preferred_swapchain_formats]
+    #[cfg(vulkan_renderer)]
+    #[deprecated(note = "use `preferred_swapchain_formats' instead")]
+    pub fn prefered_swapchain_formats(self, formats: &[ImageFormat]) -> Self {
+        report_warning(WarningType::DeprecatedApi(
+            String::from("ConfigBuilder::prefered_swapchain_formats"),
+            String::from("ConfigBuilder::preferred_swapchain_formats"),
+        ));
+        self.preferred_swapchain_formats(formats)
+    }
+
+    /// Creates the configuration
+    /// Fails if the configuration is invalid, see [Config::validate]
+    pub fn build(self) -> Result<Config, ErrorType> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
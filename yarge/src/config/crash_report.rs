@@ -0,0 +1,33 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// The configuration for the crash reports written when the engine or the game panics
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct CrashReportConfig {
+    /// Whether a crash report is written when panicking
+    pub(crate) enabled: bool,
+
+    /// The directory where the crash reports are written
+    /// Each report is named after the time of the crash, for instance `crash_1718035200000.txt`
+    pub(crate) directory: PathBuf,
+
+    /// The maximum number of recent log lines in a report
+    /// The lines are the ones kept by the `LogTarget::Memory` targets,
+    /// a memory sink with this capacity is added if there is none
+    pub(crate) log_lines: usize,
+}
+
+impl Default for CrashReportConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            directory: PathBuf::from("crash_reports"),
+            log_lines: 128,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use serde::{Deserialize, Serialize};
+
+/// The configuration of the frame rate limiter
+/// A cap of 0 means no limit, the lowest applicable cap is used
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct FrameLimiterConfig {
+    /// The maximum number of frames per second while the window is focused
+    pub(crate) target_fps: u32,
+
+    /// The maximum number of frames per second while the window is unfocused
+    pub(crate) unfocused_fps: u32,
+
+    /// The maximum number of frames per second while the window is minimized
+    pub(crate) minimized_fps: u32,
+
+    /// The time in microseconds before the end of a frame spent spinning instead of sleeping
+    /// Sleeping is cheaper but can overshoot by the scheduler's granularity
+    pub(crate) spin_time: u64,
+}
+
+impl Default for FrameLimiterConfig {
+    fn default() -> Self {
+        Self {
+            target_fps: 60,
+            unfocused_fps: 30,
+            minimized_fps: 10,
+            spin_time: 1000,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use serde::{Deserialize, Serialize};
+
+use crate::platform_layer::event::Event;
+
+/// The configuration of the virtual window system of the headless platform
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct HeadlessConfig {
+    /// The virtual screen's width in pixels
+    /// The window's width is relative to it, see [super::WindowConfig]
+    pub(crate) screen_width: u16,
+
+    /// The virtual screen's height in pixels
+    /// The window's height is relative to it, see [super::WindowConfig]
+    pub(crate) screen_height: u16,
+
+    /// The time between two frames in milliseconds
+    /// 0 runs the frames as fast as possible
+    pub(crate) frame_period: u64,
+
+    /// The number of frames before the virtual window is closed
+    /// 0 never closes it
+    pub(crate) max_frames: u64,
+
+    /// The events replayed by the virtual window, each with the frame it is sent at
+    /// An event is sent during the given frame, the first frame being 0
+    /// It can only be set from code, see [crate::config::ConfigBuilder::headless_event]
+    #[serde(skip)]
+    pub(crate) script: Vec<(u64, Event)>,
+}
+
+impl Default for HeadlessConfig {
+    fn default() -> Self {
+        Self {
+            screen_width: 1920,
+            screen_height: 1080,
+            frame_period: 16,
+            max_frames: 0,
+            script: Vec::new(),
+        }
+    }
+}
@@ -1,8 +1,11 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Serialize};
+
 /// A struct representing a version
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct Version {
     /// The variant
     pub variant: u8,
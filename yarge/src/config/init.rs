@@ -3,30 +3,171 @@ use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
 use std::path::Path;
 
-use super::{ApplicationConfig, LoggerConfig, RendererConfig, WindowConfig};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ApplicationConfig, CrashReportConfig, FrameLimiterConfig, LoggerConfig, RendererConfig,
+    TimestepConfig, WarningsConfig, WindowConfig, layers::ConfigLayers,
+};
 
 /// A structure containing all the engine configuration
-#[derive(Default)]
-pub(crate) struct Config {
+/// It can be read from a TOML file where each sub configuration has its own table:
+/// ```toml
+/// [window]
+/// title = "MyGame"
+/// position = [0.25, 0.25]
+///
+/// [logger]
+/// min_level = "Warn"
+///
+/// [application]
+/// name = "MyGame"
+/// version = { major = 1, minor = 2 }
+///
+/// [renderer.vulkan]
+/// preferred_swapchain_present_modes = ["Vsync"]
+///
+/// [crash_report]
+/// directory = "logs/crashes"
+///
+/// [timestep]
+/// step_rate = 120
+/// ```
+/// Any missing key falls back to its default value
+#[derive(Default, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
     /// The window's configuration
+    #[serde(rename = "window")]
     pub(crate) window_config: WindowConfig,
 
     /// The logger's configuration
+    #[serde(rename = "logger")]
     pub(crate) logger_config: LoggerConfig,
 
     /// The renderer's configuration
+    #[serde(rename = "renderer")]
     pub(crate) renderer_config: RendererConfig,
 
     /// The application's configuration
+    #[serde(rename = "application")]
     pub(crate) application_config: ApplicationConfig,
+
+    /// The crash reports' configuration
+    #[serde(rename = "crash_report")]
+    pub(crate) crash_report_config: CrashReportConfig,
+
+    /// The warnings' configuration
+    #[serde(rename = "warnings")]
+    pub(crate) warnings_config: WarningsConfig,
+
+    /// The fixed timestep's configuration
+    #[serde(rename = "timestep")]
+    pub(crate) timestep_config: TimestepConfig,
+
+    /// The frame rate limiter's configuration
+    #[serde(rename = "frame_limiter")]
+    pub(crate) frame_limiter_config: FrameLimiterConfig,
+
+    /// The virtual window system's configuration
+    #[cfg(headless_platform)]
+    #[serde(rename = "headless")]
+    pub(crate) headless_config: super::HeadlessConfig,
 }
 
 impl Config {
-    /// Reads the config file to fill the Config struct
-    pub(crate) fn init(config_file: Option<&Path>) -> Result<Self, ErrorType> {
-        match config_file {
-            Some(_file_path) => todo!(), // TODO: init config from file
-            None => Ok(Config::default()),
+    /// Fills the Config struct by layering the defaults, the config file,
+    /// the `YARGE_*` environment variables and the command line arguments
+    /// See [super::layers::ConfigLayers]
+    pub(crate) fn init(config_file: Option<&Path>, args: &[String]) -> Result<Self, ErrorType> {
+        let mut layers = ConfigLayers::default();
+        if let Some(file_path) = config_file {
+            layers = layers.file(file_path)?;
         }
+        layers.env(std::env::vars_os()).args(args).build()
+    }
+
+    /// Parses the content of a TOML configuration
+    pub(crate) fn from_toml(content: &str) -> Result<Self, ErrorType> {
+        match toml::from_str::<Config>(content) {
+            Ok(config) => Ok(config),
+            Err(err) => {
+                let (line, column) = match err.span() {
+                    Some(span) => Self::get_line_and_column(content, span.start),
+                    None => (0, 0),
+                };
+                Err(ErrorType::Parsing(
+                    line,
+                    column,
+                    String::from(err.message()),
+                ))
+            }
+        }
+    }
+
+    /// Converts a byte offset in a text into a line and a column
+    /// Both start at 1
+    fn get_line_and_column(content: &str, offset: usize) -> (usize, usize) {
+        let before = &content[..offset.min(content.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = match before.rfind('\n') {
+            Some(line_start) => before[line_start + 1..].chars().count() + 1,
+            None => before.chars().count() + 1,
+        };
+        (line, column)
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////     config tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_file() {
+        let config = Config::from_toml("").unwrap();
+        assert_eq!(config.window_config.title, "NewWindow");
+        assert_eq!(config.application_config.name, "NewApp");
+    }
+
+    #[test]
+    fn partial_file() {
+        let config = Config::from_toml(
+            r#"
+            [window]
+            title = "MyWindow"
+            position = [0.25, 0.5]
+
+            [logger]
+            min_level = "Error"
+
+            [application]
+            version = { major = 2 }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.window_config.title, "MyWindow");
+        assert_eq!(config.window_config.position.x, 0.25);
+        assert_eq!(config.window_config.position.y, 0.5);
+        // Missing keys fall back to the defaults
+        assert_eq!(config.window_config.width, 1.);
+        assert_eq!(config.window_config.border_width, 4);
+        assert!(matches!(
+            config.logger_config.min_level,
+            crate::LogLevel::Error
+        ));
+        assert_eq!(config.application_config.name, "NewApp");
+        assert_eq!(config.application_config.version.major, 2);
+    }
+
+    #[test]
+    fn errors() {
+        let err = Config::from_toml("[window]\ntitle = 3\n").err();
+        assert!(matches!(err, Some(ErrorType::Parsing(2, 9, _))));
+
+        let err = Config::from_toml("[window]\nunknown_key = 3\n").err();
+        assert!(matches!(err, Some(ErrorType::Parsing(2, 1, _))));
     }
 }
@@ -0,0 +1,390 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::{ffi::OsString, path::Path};
+
+use crate::{
+    PlatformLayer, PlatformLayerImpl,
+    config::Config,
+    error::{WarningType, report_warning},
+};
+
+/// The prefix of the environment variables overriding the configuration
+pub(crate) const ENV_PREFIX: &str = "YARGE_";
+/// The separator between the tables and the key of an environment variable
+/// `YARGE_WINDOW__BORDER_WIDTH` overrides `window.border_width`
+pub(crate) const ENV_SEPARATOR: &str = "__";
+/// The prefix of the command line arguments overriding the configuration
+/// `--window.title=MyGame` overrides `window.title`
+/// Only the arguments under a configuration table are used, `--game.level=3` is left to the game
+pub(crate) const ARG_PREFIX: &str = "--";
+/// The command line argument to print the effective configuration
+pub(crate) const ARG_DUMP_CONFIG: &str = "--dump-config";
+/// The deprecated keys still accepted, as their table, their name and their replacement
+const DEPRECATED_KEYS: [(&str, &str, &str); 3] = [
+    (
+        "renderer.vulkan",
+        "prefered_swapchain_formats",
+        "preferred_swapchain_formats",
+    ),
+    (
+        "renderer.vulkan",
+        "prefered_swapchain_present_modes",
+        "preferred_swapchain_present_modes",
+    ),
+    (
+        "renderer.vulkan",
+        "prefered_swapchain_min_image_count",
+        "preferred_swapchain_min_image_count",
+    ),
+];
+
+/// The configuration layers, from the lowest to the highest priority:
+/// defaults -> configuration file -> environment variables -> command line arguments
+/// Each layer only overrides the keys it defines
+#[derive(Default)]
+pub(crate) struct ConfigLayers {
+    /// The merged overrides, the missing keys will use the default values
+    table: toml::Table,
+}
+
+impl ConfigLayers {
+    /// Adds the configuration file layer
+    pub(crate) fn file(mut self, file_path: &Path) -> Result<Self, ErrorType> {
+        let content = match PlatformLayerImpl::read_to_string(file_path) {
+            Ok(content) => content,
+            Err(err) => {
+                log_error!(
+                    "Failed to read the configuration file `{:?}': {:?}",
+                    file_path,
+                    err
+                );
+                return Err(err);
+            }
+        };
+        let mut table = match content.parse::<toml::Table>() {
+            Ok(table) => table,
+            Err(err) => {
+                log_error!(
+                    "Failed to parse the configuration file `{:?}' as a table: {:?}",
+                    file_path,
+                    err
+                );
+                return Err(ErrorType::Parsing(0, 0, String::from(err.message())));
+            }
+        };
+        // Parse the whole config once to report errors with their position in the file
+        // The positions are lost with the deprecated keys, the errors are then reported when building
+        if !Self::rename_deprecated_keys(&mut table)
+            && let Err(err) = Config::from_toml(&content)
+        {
+            log_error!(
+                "Failed to parse the configuration file `{:?}': {:?}",
+                file_path,
+                err
+            );
+            return Err(err);
+        }
+        Self::merge(&mut self.table, table);
+        Ok(self)
+    }
+
+    /// Adds the environment variables layer
+    /// Only the variables starting with [ENV_PREFIX] are used
+    /// The variables which are not valid UTF-8 are skipped
+    pub(crate) fn env<N: Into<OsString>, V: Into<OsString>>(
+        mut self,
+        vars: impl Iterator<Item = (N, V)>,
+    ) -> Self {
+        for (name, value) in vars {
+            let name: OsString = name.into();
+            if !name.to_string_lossy().starts_with(ENV_PREFIX) {
+                continue;
+            }
+            let (name, value) = match (name.into_string(), value.into().into_string()) {
+                (Ok(name), Ok(value)) => (name, value),
+                (name, _) => {
+                    log_warn!(
+                        "Ignoring the environment variable `{:?}' which is not valid UTF-8",
+                        name.unwrap_or_else(|name| name.to_string_lossy().into_owned())
+                    );
+                    continue;
+                }
+            };
+            let Some(path) = name.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            if !path.contains(ENV_SEPARATOR) {
+                continue;
+            }
+            let keys: Vec<String> = path
+                .split(ENV_SEPARATOR)
+                .map(|key| key.to_lowercase())
+                .collect();
+            Self::insert(&mut self.table, &keys, &value);
+        }
+        self
+    }
+
+    /// Adds the command line arguments layer
+    /// Only the arguments looking like `--table.key=value` with a configuration table are used,
+    /// the other ones are left to the game
+    pub(crate) fn args(mut self, args: &[String]) -> Self {
+        let tables = Self::get_config_tables();
+        for arg in args {
+            let Some(arg) = arg.strip_prefix(ARG_PREFIX) else {
+                continue;
+            };
+            let Some((path, value)) = arg.split_once('=') else {
+                continue;
+            };
+            let Some((table, _)) = path.split_once('.') else {
+                continue;
+            };
+            if !tables.iter().any(|name| name == table) {
+                continue;
+            }
+            let keys: Vec<String> = path.split('.').map(String::from).collect();
+            Self::insert(&mut self.table, &keys, value);
+        }
+        self
+    }
+
+    /// Gets the names of the configuration tables, like `window' or `logger'
+    fn get_config_tables() -> Vec<String> {
+        match toml::Table::try_from(Config::default()) {
+            Ok(table) => table.keys().cloned().collect(),
+            Err(err) => {
+                log_error!(
+                    "Failed to get the tables of the default configuration: {:?}",
+                    err
+                );
+                Vec::new()
+            }
+        }
+    }
+
+    /// Creates the configuration from all the layers
+    pub(crate) fn build(mut self) -> Result<Config, ErrorType> {
+        let _ = Self::rename_deprecated_keys(&mut self.table);
+        match self.table.try_into::<Config>() {
+            Ok(config) => Ok(config),
+            Err(err) => {
+                log_error!("Failed to merge the configuration layers: {:?}", err);
+                Err(ErrorType::WrongArgument(String::from(err.message())))
+            }
+        }
+    }
+
+    /// Replaces the deprecated keys of [DEPRECATED_KEYS] and reports them
+    /// The replacement wins when both keys are given
+    /// Returns true if a deprecated key was found
+    fn rename_deprecated_keys(table: &mut toml::Table) -> bool {
+        let mut has_deprecated_keys = false;
+        for (path, deprecated_key, key) in DEPRECATED_KEYS {
+            let mut current = Some(&mut *table);
+            for name in path.split('.') {
+                current = match current.and_then(|current| current.get_mut(name)) {
+                    Some(toml::Value::Table(nested)) => Some(nested),
+                    _ => None,
+                };
+            }
+            let Some(current) = current else {
+                continue;
+            };
+            let Some(value) = current.remove(deprecated_key) else {
+                continue;
+            };
+            has_deprecated_keys = true;
+            report_warning(WarningType::DeprecatedApi(
+                format!("{}.{}", path, deprecated_key),
+                format!("{}.{}", path, key),
+            ));
+            let _ = current.entry(key).or_insert(value);
+        }
+        has_deprecated_keys
+    }
+
+    /// Inserts a raw value at the given keys, creating the missing tables
+    /// The raw value is parsed as a TOML value and falls back to a string
+    fn insert(table: &mut toml::Table, keys: &[String], raw_value: &str) {
+        let value = match format!("value = {}", raw_value).parse::<toml::Table>() {
+            Ok(mut parsed) => parsed
+                .remove("value")
+                .unwrap_or_else(|| toml::Value::String(String::from(raw_value))),
+            Err(_) => toml::Value::String(String::from(raw_value)),
+        };
+        let mut override_table = toml::Table::new();
+        let mut current = &mut override_table;
+        let Some((last, tables)) = keys.split_last() else {
+            return;
+        };
+        for key in tables {
+            current = match current
+                .entry(key.clone())
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            {
+                toml::Value::Table(table) => table,
+                _ => return,
+            };
+        }
+        let _ = current.insert(last.clone(), value);
+        Self::merge(table, override_table);
+    }
+
+    /// Merges the overrides into the base table
+    /// Tables are merged recursively, any other value is replaced
+    fn merge(base: &mut toml::Table, overrides: toml::Table) {
+        for (key, value) in overrides {
+            match (base.get_mut(&key), value) {
+                (Some(toml::Value::Table(base_table)), toml::Value::Table(override_table)) => {
+                    Self::merge(base_table, override_table);
+                }
+                (_, value) => {
+                    let _ = base.insert(key, value);
+                }
+            }
+        }
+    }
+}
+
+impl Config {
+    /// Writes the configuration as a TOML string
+    /// Useful to check the effective configuration after all the layers are merged
+    pub fn dump(&self) -> Result<String, ErrorType> {
+        match toml::to_string_pretty(self) {
+            Ok(dump) => Ok(dump),
+            Err(err) => {
+                log_error!("Failed to dump the configuration: {:?}", err);
+                Err(ErrorType::Unknown)
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////     layers tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env() {
+        let vars = vec![
+            (
+                String::from("YARGE_WINDOW__TITLE"),
+                String::from("EnvTitle"),
+            ),
+            (
+                String::from("YARGE_WINDOW__BORDER_WIDTH"),
+                String::from("2"),
+            ),
+            (String::from("YARGE_IGNORED"), String::from("3")),
+            (String::from("PATH"), String::from("/usr/bin")),
+        ];
+        let config = ConfigLayers::default()
+            .env(vars.into_iter())
+            .build()
+            .unwrap();
+        assert_eq!(config.window_config.title, "EnvTitle");
+        assert_eq!(config.window_config.border_width, 2);
+        assert_eq!(config.window_config.width, 1.);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn env_not_utf8() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let vars = vec![
+            (
+                OsString::from("YARGE_WINDOW__TITLE"),
+                OsString::from_vec(vec![0x45, 0xFF, 0x6E, 0x76]),
+            ),
+            (
+                OsString::from_vec(b"YARGE_WINDOW__\xFF".to_vec()),
+                OsString::from("2"),
+            ),
+            (
+                OsString::from("YARGE_WINDOW__BORDER_WIDTH"),
+                OsString::from("3"),
+            ),
+            (OsString::from_vec(vec![0xFF]), OsString::from("4")),
+        ];
+        let config = ConfigLayers::default()
+            .env(vars.into_iter())
+            .build()
+            .unwrap();
+        assert_eq!(
+            config.window_config.title,
+            Config::default().window_config.title
+        );
+        assert_eq!(config.window_config.border_width, 3);
+    }
+
+    #[test]
+    fn priorities() {
+        let vars = vec![
+            (
+                String::from("YARGE_WINDOW__TITLE"),
+                String::from("EnvTitle"),
+            ),
+            (
+                String::from("YARGE_APPLICATION__NAME"),
+                String::from("EnvApp"),
+            ),
+        ];
+        let args = vec![
+            String::from("--window.title=ArgTitle"),
+            String::from("--window.position=[0.5, 0.25]"),
+            String::from("--level=3"),
+            String::from("--game.level=3"),
+            String::from("positional"),
+        ];
+        let config = ConfigLayers::default()
+            .env(vars.into_iter())
+            .args(&args)
+            .build()
+            .unwrap();
+        assert_eq!(config.window_config.title, "ArgTitle");
+        assert_eq!(config.window_config.position.x, 0.5);
+        assert_eq!(config.window_config.position.y, 0.25);
+        assert_eq!(config.application_config.name, "EnvApp");
+    }
+
+    #[test]
+    fn errors() {
+        let args = vec![String::from("--window.width=wide")];
+        assert!(ConfigLayers::default().args(&args).build().is_err());
+    }
+
+    #[test]
+    fn dump() {
+        let args = vec![String::from("--application.version.major=3")];
+        let config = ConfigLayers::default().args(&args).build().unwrap();
+        let dump = config.dump().unwrap();
+        let config = Config::from_toml(&dump).unwrap();
+        assert_eq!(config.application_config.version.major, 3);
+    }
+
+    #[cfg(vulkan_renderer)]
+    #[test]
+    fn deprecated_keys() {
+        let args = vec![
+            String::from("--renderer.vulkan.prefered_swapchain_min_image_count=4"),
+            String::from("--renderer.vulkan.prefered_swapchain_present_modes=[\"Vsync\"]"),
+            String::from(
+                "--renderer.vulkan.preferred_swapchain_present_modes=[\"TripleBuffering\"]",
+            ),
+        ];
+        let config = ConfigLayers::default().args(&args).build().unwrap();
+        let vulkan = &config.renderer_config.vulkan_parameters;
+        assert_eq!(vulkan.preferred_swapchain_min_image_count, 4);
+        // The replacement wins over the deprecated key
+        assert_eq!(
+            vulkan.preferred_swapchain_present_modes,
+            vec![crate::renderer_types::present::PresentMode::TripleBuffering]
+        );
+    }
+}
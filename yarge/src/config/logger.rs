@@ -1,14 +1,187 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
-use crate::core_layer::logger_system::helpers::{LogLevel, LogTarget};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::core_layer::logger_system::helpers::{LogFilter, LogLevel, LogSink, LogTarget};
 
 /// The configuration for the logger
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct LoggerConfig {
     /// Any logs with weaker level won't be displayed
     pub min_level: LogLevel,
 
     /// Where to log
     pub target: LogTarget,
+
+    /// Per module minimum levels, overriding `min_level`
+    /// When several filters match a module, the most specific one is used
+    /// In a configuration file, the filters are written as `"yarge::rendering_layer=warn,my_game=debug"`
+    #[serde(
+        serialize_with = "serialize_filters",
+        deserialize_with = "deserialize_filters"
+    )]
+    pub filters: Vec<LogFilter>,
+
+    /// Additional outputs, each with its own minimum level
+    /// They can also be added and removed at runtime through user events
+    pub sinks: Vec<LogSink>,
+}
+
+impl LoggerConfig {
+    /// Checks if a log of the given level coming from the given module should be displayed
+    pub fn is_enabled(&self, level: &LogLevel, module_path: &str) -> bool {
+        let min_level = self
+            .filters
+            .iter()
+            .filter(|filter| filter.matches(module_path))
+            .max_by_key(|filter| filter.module.len())
+            .map_or(self.min_level, |filter| filter.min_level);
+        *level >= min_level
+    }
+
+    /// Gets all the targets where a log of the given level coming from the given module should be written
+    pub fn get_targets<'a>(
+        &'a self,
+        level: &LogLevel,
+        module_path: &str,
+    ) -> impl Iterator<Item = &'a LogTarget> + use<'a> {
+        let level = *level;
+        let main_target = if self.is_enabled(&level, module_path) {
+            Some(&self.target)
+        } else {
+            None
+        };
+        main_target.into_iter().chain(
+            self.sinks
+                .iter()
+                .filter(move |sink| level >= sink.min_level)
+                .map(|sink| &sink.target),
+        )
+    }
+
+    /// Adds a sink, replacing the one with the same name if any
+    /// Returns true if a sink was replaced
+    /// Warning: it is called while holding the global logger, so it must not log
+    pub(crate) fn add_sink(&mut self, sink: LogSink) -> bool {
+        match self
+            .sinks
+            .iter_mut()
+            .find(|existing| existing.name == sink.name)
+        {
+            Some(existing) => {
+                *existing = sink;
+                true
+            }
+            None => {
+                self.sinks.push(sink);
+                false
+            }
+        }
+    }
+
+    /// Removes a sink by its name
+    /// Returns false if there was no such sink
+    /// Warning: it is called while holding the global logger, so it must not log
+    pub(crate) fn remove_sink(&mut self, name: &str) -> bool {
+        let nb_sinks = self.sinks.len();
+        self.sinks.retain(|sink| sink.name != name);
+        self.sinks.len() != nb_sinks
+    }
+}
+
+/// Writes the filters as `module=level` pairs separated by commas in a configuration file
+fn serialize_filters<S>(filters: &[LogFilter], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let filters: Vec<String> = filters.iter().map(LogFilter::to_string).collect();
+    filters.join(",").serialize(serializer)
+}
+
+/// Reads the filters written as `module=level` pairs separated by commas in a configuration file
+fn deserialize_filters<'de, D>(deserializer: D) -> Result<Vec<LogFilter>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let filters = String::deserialize(deserializer)?;
+    LogFilter::parse_list(&filters).map_err(|err| serde::de::Error::custom(format!("{:?}", err)))
+}
+
+//////////////////////////////////////////////////////////
+///////////////     logger tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_enabled() {
+        let config = crate::config::Config::from_toml(
+            r#"
+            [logger]
+            min_level = "Info"
+            filters = "yarge=warn,yarge::rendering_layer::vulkan=debug,my_game=error"
+            "#,
+        )
+        .unwrap();
+        let logger = config.logger_config;
+        assert!(!logger.is_enabled(&LogLevel::Debug, "other_crate"));
+        assert!(logger.is_enabled(&LogLevel::Info, "other_crate"));
+        assert!(!logger.is_enabled(&LogLevel::Info, "yarge::config"));
+        assert!(logger.is_enabled(&LogLevel::Warn, "yarge::config"));
+        assert!(logger.is_enabled(&LogLevel::Debug, "yarge::rendering_layer::vulkan::init"));
+        assert!(!logger.is_enabled(&LogLevel::Warn, "my_game"));
+
+        assert!(crate::config::Config::from_toml("[logger]\nfilters = \"yarge\"\n").is_err());
+    }
+
+    #[test]
+    fn file_target() {
+        let config = crate::config::Config::from_toml(
+            r#"
+            [logger.target.File]
+            path = "logs/game.log"
+            max_files = 2
+            "#,
+        )
+        .unwrap();
+        let LogTarget::File(file_config) = config.logger_config.target else {
+            panic!("The log target should be a file");
+        };
+        assert_eq!(file_config.path, std::path::PathBuf::from("logs/game.log"));
+        assert_eq!(file_config.max_files, 2);
+        assert_eq!(file_config.max_size, 10 * 1024 * 1024);
+    }
+
+    #[test]
+    fn sinks() {
+        let config = crate::config::Config::from_toml(
+            r#"
+            [logger]
+            min_level = "Warn"
+
+            [[logger.sinks]]
+            name = "verbose"
+            target = { File = { path = "logs/debug.log" } }
+            "#,
+        )
+        .unwrap();
+        let mut logger = config.logger_config;
+        assert_eq!(logger.sinks[0].min_level, LogLevel::Debug);
+        assert_eq!(logger.get_targets(&LogLevel::Debug, "my_game").count(), 1);
+        assert_eq!(logger.get_targets(&LogLevel::Error, "my_game").count(), 2);
+
+        assert!(logger.add_sink(LogSink::new(
+            "verbose",
+            LogTarget::ErrorConsole,
+            LogLevel::Info
+        )));
+        assert_eq!(logger.get_targets(&LogLevel::Debug, "my_game").count(), 0);
+        assert!(!logger.add_sink(LogSink::new("other", LogTarget::Console, LogLevel::Debug)));
+        assert!(logger.remove_sink("verbose"));
+        assert!(!logger.remove_sink("verbose"));
+        assert_eq!(logger.sinks.len(), 1);
+    }
 }
@@ -14,8 +14,38 @@ pub(crate) use renderer::RendererConfig;
 mod application;
 pub(crate) use application::ApplicationConfig;
 
+mod crash_report;
+pub(crate) use crash_report::CrashReportConfig;
+
+mod warnings;
+pub(crate) use warnings::WarningsConfig;
+
+mod timestep;
+pub(crate) use timestep::TimestepConfig;
+
+mod frame_limiter;
+pub(crate) use frame_limiter::FrameLimiterConfig;
+
+#[cfg(headless_platform)]
+mod headless;
+#[cfg(headless_platform)]
+pub(crate) use headless::HeadlessConfig;
+
 mod init;
-pub(crate) use init::Config;
+pub use init::Config;
+
+mod layers;
+pub(crate) use layers::ARG_DUMP_CONFIG;
+
+mod validation;
+pub use validation::{ConfigReport, ConfigViolation};
+
+mod builder;
+pub use builder::ConfigBuilder;
+
+mod reload;
+pub use reload::ConfigChange;
+pub(crate) use reload::ConfigWatcher;
 
 mod helper;
 pub use helper::Version;
@@ -0,0 +1,336 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::path::{Path, PathBuf};
+
+use crate::{
+    LogFilter, LogLevel, LogSink, LogTarget, PlatformLayer, PlatformLayerImpl, config::Config,
+    maths::Vector2f32,
+};
+
+#[cfg(vulkan_renderer)]
+use crate::renderer_types::present::PresentMode;
+
+/// The minimum time between two checks of the configuration file in milliseconds
+const RELOAD_CHECK_PERIOD: u128 = 500;
+
+/// The keys compared as whole values instead of key by key
+/// The log targets are enums with table variants, a changed field replaces the whole target
+const WHOLE_VALUE_KEYS: [&str; 2] = ["logger.target", "logger.sinks"];
+
+/// A configuration value that changed when reloading the configuration file
+/// Only these values are applied live, the other ones need a restart
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigChange {
+    /// The new minimum level of the displayed logs
+    LogLevel(LogLevel),
+    /// The new log target
+    LogTarget(LogTarget),
+    /// The new per module log filters
+    LogFilters(Vec<LogFilter>),
+    /// The new additional log sinks, replacing the ones added at runtime
+    LogSinks(Vec<LogSink>),
+    /// The new window's title
+    WindowTitle(String),
+    /// The new window's geometry
+    /// The position, width and height are given between 0. and 1.
+    WindowGeometry {
+        position: Vector2f32,
+        width: f32,
+        height: f32,
+    },
+    /// The new preferred swapchain present modes in order of preference
+    #[cfg(vulkan_renderer)]
+    PresentModes(Vec<PresentMode>),
+    /// The new frame rate caps, 0 meaning no limit
+    FrameLimits {
+        target_fps: u32,
+        unfocused_fps: u32,
+        minimized_fps: u32,
+    },
+}
+
+/// Watches the configuration file to reload it when it is modified
+pub(crate) struct ConfigWatcher {
+    /// The watched configuration file
+    file_path: PathBuf,
+    /// The command line arguments, they still override the reloaded file
+    args: Vec<String>,
+    /// The last modification time of the file
+    last_modification: u128,
+    /// The last time the file was checked
+    last_check: u128,
+    /// The current configuration as a table, to find the changed keys
+    table: toml::Table,
+}
+
+impl ConfigWatcher {
+    /// Starts watching a configuration file
+    pub(crate) fn init(
+        file_path: &Path,
+        args: &[String],
+        config: &Config,
+    ) -> Result<Self, ErrorType> {
+        let last_modification = PlatformLayerImpl::get_modification_time(file_path)?;
+        let last_check = PlatformLayerImpl::get_time_since_unix_epoch()?;
+        let table = config.as_table()?;
+        Ok(Self {
+            file_path: file_path.to_path_buf(),
+            args: args.to_vec(),
+            last_modification,
+            last_check,
+            table,
+        })
+    }
+
+    /// Checks if the configuration file was modified since the last check
+    /// Returns the reloaded configuration and its live changes if it was
+    /// An invalid file is reported and ignored, the current configuration is kept
+    pub(crate) fn check(&mut self) -> Result<Option<(Config, Vec<ConfigChange>)>, ErrorType> {
+        let now = PlatformLayerImpl::get_time_since_unix_epoch()?;
+        if now.saturating_sub(self.last_check) < RELOAD_CHECK_PERIOD {
+            return Ok(None);
+        }
+        self.last_check = now;
+
+        let modification = match PlatformLayerImpl::get_modification_time(&self.file_path) {
+            Ok(modification) => modification,
+            Err(err) => {
+                // The file may be replaced by an editor while saving
+                log_debug!(
+                    "Failed to get the modification time of the configuration file: {:?}",
+                    err
+                );
+                return Ok(None);
+            }
+        };
+        if modification == self.last_modification {
+            return Ok(None);
+        }
+        self.last_modification = modification;
+
+        let config = match Config::init(Some(&self.file_path), &self.args) {
+            Ok(config) => config,
+            Err(err) => {
+                log_warn!(
+                    "Failed to reload the configuration file `{:?}', keeping the current configuration: {:?}",
+                    self.file_path,
+                    err
+                );
+                return Ok(None);
+            }
+        };
+        if let Err(err) = config.validate() {
+            log_warn!(
+                "The reloaded configuration file `{:?}' is invalid, keeping the current configuration: {:?}",
+                self.file_path,
+                err
+            );
+            return Ok(None);
+        }
+
+        let table = config.as_table()?;
+        let changes = config.get_changes(&self.table, &table);
+        self.table = table;
+        log_info!(
+            "Configuration file `{:?}' reloaded with {:?} live change(s)",
+            self.file_path,
+            changes.len()
+        );
+        Ok(Some((config, changes)))
+    }
+}
+
+impl Config {
+    /// Converts the configuration into a TOML table
+    fn as_table(&self) -> Result<toml::Table, ErrorType> {
+        match toml::Table::try_from(self) {
+            Ok(table) => Ok(table),
+            Err(err) => {
+                log_error!(
+                    "Failed to convert the configuration into a table: {:?}",
+                    err
+                );
+                Err(ErrorType::Unknown)
+            }
+        }
+    }
+
+    /// Gets the live changes between a previous configuration and this one
+    /// The changed values that can't be applied live are reported
+    fn get_changes(&self, previous: &toml::Table, current: &toml::Table) -> Vec<ConfigChange> {
+        let mut changed_keys = Vec::new();
+        Self::get_changed_keys(previous, current, "", &mut changed_keys);
+
+        let mut changes = Vec::new();
+        for key in changed_keys {
+            let change = match key.as_str() {
+                "logger.min_level" => ConfigChange::LogLevel(self.logger_config.min_level),
+                "logger.target" => ConfigChange::LogTarget(self.logger_config.target.clone()),
+                "logger.filters" => ConfigChange::LogFilters(self.logger_config.filters.clone()),
+                "logger.sinks" => ConfigChange::LogSinks(self.logger_config.sinks.clone()),
+                "window.title" => ConfigChange::WindowTitle(self.window_config.title.clone()),
+                "window.position" | "window.width" | "window.height" => {
+                    ConfigChange::WindowGeometry {
+                        position: self.window_config.position,
+                        width: self.window_config.width,
+                        height: self.window_config.height,
+                    }
+                }
+                #[cfg(vulkan_renderer)]
+                "renderer.vulkan.preferred_swapchain_present_modes" => ConfigChange::PresentModes(
+                    self.renderer_config
+                        .vulkan_parameters
+                        .preferred_swapchain_present_modes
+                        .clone(),
+                ),
+                "frame_limiter.target_fps"
+                | "frame_limiter.unfocused_fps"
+                | "frame_limiter.minimized_fps" => ConfigChange::FrameLimits {
+                    target_fps: self.frame_limiter_config.target_fps,
+                    unfocused_fps: self.frame_limiter_config.unfocused_fps,
+                    minimized_fps: self.frame_limiter_config.minimized_fps,
+                },
+                _ => {
+                    log_warn!(
+                        "The configuration value `{}' changed but can't be reloaded, restart to apply it",
+                        key
+                    );
+                    continue;
+                }
+            };
+            // The geometry and frame limits keys share the same change
+            if !changes.contains(&change) {
+                changes.push(change);
+            }
+        }
+        changes
+    }
+
+    /// Collects the keys that differ between two tables
+    /// Nested keys are joined with dots, for instance `window.title`
+    /// The nested tables are compared key by key, except the ones of [WHOLE_VALUE_KEYS]
+    fn get_changed_keys(
+        previous: &toml::Table,
+        current: &toml::Table,
+        prefix: &str,
+        changed_keys: &mut Vec<String>,
+    ) {
+        let mut keys: Vec<&String> = previous.keys().chain(current.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        for key in keys {
+            let path = format!("{}{}", prefix, key);
+            match (previous.get(key), current.get(key)) {
+                (Some(toml::Value::Table(previous)), Some(toml::Value::Table(current)))
+                    if !WHOLE_VALUE_KEYS.contains(&path.as_str()) =>
+                {
+                    Self::get_changed_keys(previous, current, &format!("{}.", path), changed_keys);
+                }
+                (previous, current) => {
+                    if previous != current {
+                        changed_keys.push(path);
+                    }
+                }
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////     reload tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changes() {
+        let previous = Config::from_toml(
+            r#"
+            [window]
+            title = "Before"
+            border_width = 2
+            "#,
+        )
+        .unwrap();
+        let current = Config::from_toml(
+            r#"
+            [window]
+            title = "After"
+            border_width = 3
+            width = 0.5
+            height = 0.5
+
+            [logger]
+            min_level = "Warn"
+            "#,
+        )
+        .unwrap();
+        let changes =
+            current.get_changes(&previous.as_table().unwrap(), &current.as_table().unwrap());
+        assert_eq!(
+            changes,
+            vec![
+                ConfigChange::LogLevel(LogLevel::Warn),
+                ConfigChange::WindowGeometry {
+                    position: Vector2f32::ZEROS,
+                    width: 0.5,
+                    height: 0.5,
+                },
+                ConfigChange::WindowTitle(String::from("After")),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_changes() {
+        let config = Config::from_toml("[window]\ntitle = \"Same\"\n").unwrap();
+        let table = config.as_table().unwrap();
+        assert!(config.get_changes(&table, &table).is_empty());
+    }
+
+    #[test]
+    fn log_target_changes() {
+        let previous = Config::from_toml(
+            r#"
+            [logger.target.File]
+            path = "a.log"
+            "#,
+        )
+        .unwrap();
+        let current = Config::from_toml(
+            r#"
+            [logger.target.File]
+            path = "b.log"
+            "#,
+        )
+        .unwrap();
+        let changes =
+            current.get_changes(&previous.as_table().unwrap(), &current.as_table().unwrap());
+        assert_eq!(
+            changes,
+            vec![ConfigChange::LogTarget(
+                current.logger_config.target.clone()
+            )]
+        );
+
+        // Switching to another variant replaces the whole target
+        let switched = Config::from_toml(
+            r#"
+            [logger.target.Json]
+            path = "b.json"
+            "#,
+        )
+        .unwrap();
+        let changes =
+            switched.get_changes(&current.as_table().unwrap(), &switched.as_table().unwrap());
+        assert_eq!(
+            changes,
+            vec![ConfigChange::LogTarget(
+                switched.logger_config.target.clone()
+            )]
+        );
+        assert!(matches!(switched.logger_config.target, LogTarget::Json(_)));
+    }
+}
@@ -1,6 +1,8 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Serialize};
+
 use crate::renderer_types::{FinalTransform, RenderingApplicationType};
 
 #[cfg(opengl_renderer)]
@@ -10,13 +12,18 @@ use crate::rendering_layer::rendering_impl::OpenglConfig;
 use crate::rendering_layer::rendering_impl::types::config::VulkanConfig;
 
 /// The configuration for the renderer
-#[derive(Clone)]
+/// Unknown keys are ignored so that a single file can hold
+/// the parameters of every rendering backend
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub(crate) struct RendererConfig {
     #[cfg(opengl_renderer)]
+    #[serde(rename = "opengl")]
     /// The opengl specific parameters
     pub(crate) opengl_parameters: OpenglConfig,
 
     #[cfg(vulkan_renderer)]
+    #[serde(rename = "vulkan")]
     /// The Vulkan specific parameters
     pub(crate) vulkan_parameters: VulkanConfig,
 
@@ -0,0 +1,32 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use serde::{Deserialize, Serialize};
+
+/// The configuration of the fixed timestep simulation
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct TimestepConfig {
+    /// The number of fixed updates per second
+    pub(crate) step_rate: u32,
+
+    /// The maximum number of fixed updates run in a single frame
+    /// The simulation falls behind instead of spiraling when frames are too slow
+    pub(crate) max_catch_up_steps: u32,
+}
+
+impl Default for TimestepConfig {
+    fn default() -> Self {
+        Self {
+            step_rate: 60,
+            max_catch_up_steps: 5,
+        }
+    }
+}
+
+impl TimestepConfig {
+    /// The duration of a fixed update in seconds
+    pub(crate) fn get_step(&self) -> f64 {
+        1. / f64::from(self.step_rate)
+    }
+}
@@ -0,0 +1,414 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use crate::{LogTarget, config::Config};
+
+#[cfg(vulkan_renderer)]
+use crate::{config::Version, renderer_types::present::PresentMode};
+
+/// A single broken invariant of the configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigViolation {
+    /// The key of the faulty value, for instance `window.width`
+    pub key: String,
+    /// Why the value is invalid
+    pub reason: String,
+}
+
+/// All the broken invariants found when validating a configuration
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigReport {
+    /// The violations in the order they were found
+    pub violations: Vec<ConfigViolation>,
+}
+
+impl ConfigReport {
+    /// Adds a violation to the report
+    fn add(&mut self, key: &str, reason: String) {
+        self.violations.push(ConfigViolation {
+            key: String::from(key),
+            reason,
+        });
+    }
+
+    /// Checks that a value lies in [min, max]
+    fn check_range(&mut self, key: &str, value: f32, min: f32, max: f32) {
+        if !(min..=max).contains(&value) {
+            self.add(
+                key,
+                format!("must be between {:?} and {:?}, got {:?}", min, max, value),
+            );
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for violation in &self.violations {
+            writeln!(f, "- `{}': {}", violation.key, violation.reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl Config {
+    /// Checks the configuration invariants
+    /// All the violations are collected and returned together
+    pub fn validate(&self) -> Result<(), ErrorType> {
+        let mut report = ConfigReport::default();
+
+        self.validate_window(&mut report);
+        self.validate_logger(&mut report);
+        self.validate_application(&mut report);
+        self.validate_crash_report(&mut report);
+        self.validate_timestep(&mut report);
+        self.validate_frame_limiter(&mut report);
+        #[cfg(vulkan_renderer)]
+        self.validate_vulkan(&mut report);
+
+        if report.violations.is_empty() {
+            return Ok(());
+        }
+        log_error!(
+            "The configuration has {:?} invalid value(s):\n{}",
+            report.violations.len(),
+            report
+        );
+        Err(ErrorType::InvalidConfig(report))
+    }
+
+    /// Checks the window geometry
+    fn validate_window(&self, report: &mut ConfigReport) {
+        let window = &self.window_config;
+        report.check_range("window.position.x", window.position.x, 0., 1.);
+        report.check_range("window.position.y", window.position.y, 0., 1.);
+        report.check_range("window.width", window.width, 0., 1.);
+        report.check_range("window.height", window.height, 0., 1.);
+
+        if window.width == 0. || window.height == 0. {
+            report.add(
+                "window",
+                format!(
+                    "the window can't be empty, got a {:?}x{:?} size",
+                    window.width, window.height
+                ),
+            );
+        }
+        if window.position.x + window.width > 1. {
+            report.add(
+                "window",
+                format!(
+                    "the window overflows the screen horizontally: position.x ({:?}) + width ({:?}) > 1",
+                    window.position.x, window.width
+                ),
+            );
+        }
+        if window.position.y + window.height > 1. {
+            report.add(
+                "window",
+                format!(
+                    "the window overflows the screen vertically: position.y ({:?}) + height ({:?}) > 1",
+                    window.position.y, window.height
+                ),
+            );
+        }
+    }
+
+    /// Checks the logger's target and sinks
+    fn validate_logger(&self, report: &mut ConfigReport) {
+        Self::validate_log_target(&self.logger_config.target, "logger.target", report);
+
+        let mut names = Vec::new();
+        for sink in &self.logger_config.sinks {
+            if sink.name.is_empty() {
+                report.add("logger.sinks.name", String::from("must not be empty"));
+            } else if names.contains(&&sink.name) {
+                report.add(
+                    "logger.sinks.name",
+                    format!("the name `{}' is used by several sinks", sink.name),
+                );
+            }
+            names.push(&sink.name);
+            Self::validate_log_target(&sink.target, "logger.sinks.target", report);
+        }
+    }
+
+    /// Checks a log target, the key being its location in the configuration
+    fn validate_log_target(target: &LogTarget, key: &str, report: &mut ConfigReport) {
+        let (variant, file_config) = match target {
+            LogTarget::File(file_config) => ("File", file_config),
+            LogTarget::Markdown(file_config) => ("Markdown", file_config),
+            LogTarget::Json(file_config) => ("Json", file_config),
+            LogTarget::Memory { capacity } => {
+                if *capacity == 0 {
+                    report.add(
+                        &format!("{}.Memory.capacity", key),
+                        String::from("must be greater than 0"),
+                    );
+                }
+                return;
+            }
+            #[cfg(tracing_bridge)]
+            LogTarget::Tracing => return,
+            LogTarget::Console | LogTarget::ErrorConsole => return,
+        };
+        if file_config.path.as_os_str().is_empty() {
+            report.add(
+                &format!("{}.{}.path", key, variant),
+                String::from("must not be empty"),
+            );
+        }
+    }
+
+    /// Checks the application description
+    fn validate_application(&self, report: &mut ConfigReport) {
+        if self.application_config.name.is_empty() {
+            report.add("application.name", String::from("must not be empty"));
+        }
+    }
+
+    /// Checks where the crash reports are written
+    fn validate_crash_report(&self, report: &mut ConfigReport) {
+        let crash_report = &self.crash_report_config;
+        if crash_report.enabled && crash_report.directory.as_os_str().is_empty() {
+            report.add(
+                "crash_report.directory",
+                String::from("must not be empty when the crash reports are enabled"),
+            );
+        }
+    }
+
+    /// Checks the fixed timestep
+    fn validate_timestep(&self, report: &mut ConfigReport) {
+        if self.timestep_config.step_rate == 0 {
+            report.add("timestep.step_rate", String::from("must be greater than 0"));
+        }
+        if self.timestep_config.max_catch_up_steps == 0 {
+            report.add(
+                "timestep.max_catch_up_steps",
+                String::from("must be greater than 0"),
+            );
+        }
+    }
+
+    /// Checks the frame rate caps
+    fn validate_frame_limiter(&self, report: &mut ConfigReport) {
+        let frame_limiter = &self.frame_limiter_config;
+        if frame_limiter.target_fps > 0 {
+            let caps = [
+                ("frame_limiter.unfocused_fps", frame_limiter.unfocused_fps),
+                ("frame_limiter.minimized_fps", frame_limiter.minimized_fps),
+            ];
+            for (key, cap) in caps {
+                if cap > frame_limiter.target_fps {
+                    report.add(
+                        key,
+                        format!(
+                            "must not be greater than the target fps ({:?}), got {:?}",
+                            frame_limiter.target_fps, cap
+                        ),
+                    );
+                }
+            }
+        }
+        if frame_limiter.spin_time == 0 {
+            report.add(
+                "frame_limiter.spin_time",
+                String::from("must be greater than 0"),
+            );
+        }
+    }
+
+    /// Checks the Vulkan parameters
+    #[cfg(vulkan_renderer)]
+    fn validate_vulkan(&self, report: &mut ConfigReport) {
+        let vulkan = &self.renderer_config.vulkan_parameters;
+
+        if vulkan.preferred_swapchain_formats.is_empty() {
+            report.add(
+                "renderer.vulkan.preferred_swapchain_formats",
+                String::from("must contain at least one format"),
+            );
+        }
+        if vulkan.preferred_swapchain_present_modes.is_empty() {
+            report.add(
+                "renderer.vulkan.preferred_swapchain_present_modes",
+                String::from("must contain at least one present mode"),
+            );
+        }
+        if vulkan.swapchain_image_usages.is_empty() {
+            report.add(
+                "renderer.vulkan.swapchain_image_usages",
+                String::from("must contain at least one usage"),
+            );
+        }
+        if vulkan.preferred_swapchain_min_image_count == 0 {
+            report.add(
+                "renderer.vulkan.preferred_swapchain_min_image_count",
+                String::from("must be at least 1"),
+            );
+        }
+        if vulkan
+            .preferred_swapchain_present_modes
+            .contains(&PresentMode::TripleBuffering)
+            && vulkan.preferred_swapchain_min_image_count < 3
+        {
+            report.add(
+                "renderer.vulkan.preferred_swapchain_min_image_count",
+                format!(
+                    "the `TripleBuffering' present mode needs at least 3 images, got {:?}",
+                    vulkan.preferred_swapchain_min_image_count
+                ),
+            );
+        }
+
+        if vulkan.version.major != 1 {
+            report.add(
+                "renderer.vulkan.version",
+                format!(
+                    "only Vulkan 1.x is supported, got {}",
+                    vulkan.version.as_string()
+                ),
+            );
+        }
+        let required_features = [
+            (
+                "renderer.vulkan.required_physical_device_features_1_1",
+                vulkan.required_physical_device_features_1_1.is_empty(),
+                1u8,
+            ),
+            (
+                "renderer.vulkan.required_physical_device_features_1_2",
+                vulkan.required_physical_device_features_1_2.is_empty(),
+                2u8,
+            ),
+            (
+                "renderer.vulkan.required_physical_device_features_1_3",
+                vulkan.required_physical_device_features_1_3.is_empty(),
+                3u8,
+            ),
+            (
+                "renderer.vulkan.required_physical_device_features_1_4",
+                vulkan.required_physical_device_features_1_4.is_empty(),
+                4u8,
+            ),
+        ];
+        for (key, is_empty, minor) in required_features {
+            if !is_empty && vulkan.version < Version::default().major(1).minor(minor) {
+                report.add(
+                    key,
+                    format!(
+                        "requires Vulkan 1.{:?} but the configured version is {}",
+                        minor,
+                        vulkan.version.as_string()
+                    ),
+                );
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////   validation tests   /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config() {
+        assert!(Config::default().validate().is_ok());
+    }
+
+    #[test]
+    fn window() {
+        let config = Config::from_toml(
+            r#"
+            [window]
+            position = [0.5, -0.1]
+            width = 0.75
+            height = 0
+            "#,
+        )
+        .unwrap();
+        let Err(ErrorType::InvalidConfig(report)) = config.validate() else {
+            panic!("The window config should be invalid");
+        };
+        let keys: Vec<&str> = report
+            .violations
+            .iter()
+            .map(|violation| violation.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["window.position.y", "window", "window"]);
+    }
+
+    #[test]
+    fn frame_limiter() {
+        let config = Config::from_toml(
+            r#"
+            [frame_limiter]
+            target_fps = 30
+            unfocused_fps = 60
+            minimized_fps = 10
+            spin_time = 0
+            "#,
+        )
+        .unwrap();
+        let Err(ErrorType::InvalidConfig(report)) = config.validate() else {
+            panic!("The frame limiter config should be invalid");
+        };
+        let keys: Vec<&str> = report
+            .violations
+            .iter()
+            .map(|violation| violation.key.as_str())
+            .collect();
+        assert_eq!(
+            keys,
+            vec!["frame_limiter.unfocused_fps", "frame_limiter.spin_time"]
+        );
+
+        // No target cap, the other caps are free
+        let config = Config::from_toml(
+            r#"
+            [frame_limiter]
+            target_fps = 0
+            unfocused_fps = 60
+            minimized_fps = 240
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+    }
+
+    #[cfg(vulkan_renderer)]
+    #[test]
+    fn vulkan() {
+        let config = Config::from_toml(
+            r#"
+            [renderer.vulkan]
+            version = { major = 1, minor = 2 }
+            preferred_swapchain_formats = []
+            preferred_swapchain_min_image_count = 0
+            "#,
+        )
+        .unwrap();
+        let Err(ErrorType::InvalidConfig(report)) = config.validate() else {
+            panic!("The vulkan config should be invalid");
+        };
+        let keys: Vec<&str> = report
+            .violations
+            .iter()
+            .map(|violation| violation.key.as_str())
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "renderer.vulkan.preferred_swapchain_formats",
+                "renderer.vulkan.preferred_swapchain_min_image_count",
+                "renderer.vulkan.preferred_swapchain_min_image_count",
+                "renderer.vulkan.required_physical_device_features_1_3",
+                "renderer.vulkan.required_physical_device_features_1_4",
+            ]
+        );
+    }
+}
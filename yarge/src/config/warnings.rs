@@ -0,0 +1,31 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use serde::{Deserialize, Serialize};
+
+/// The configuration for the warnings collected each frame
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct WarningsConfig {
+    /// The maximum number of distinct warnings kept per frame
+    /// The other ones are only counted, 0 means no limit
+    pub(crate) max_per_frame: usize,
+
+    /// The minimum number of frames between two logs of the same warning
+    /// The warning is still collected and counted in between
+    pub(crate) log_period: u64,
+
+    /// A frame taking longer than this threshold in milliseconds raises a `SlowFrame` warning
+    /// 0 disables the check
+    pub(crate) slow_frame_threshold: u64,
+}
+
+impl Default for WarningsConfig {
+    fn default() -> Self {
+        Self {
+            max_per_frame: 32,
+            log_period: 60,
+            slow_frame_threshold: 100,
+        }
+    }
+}
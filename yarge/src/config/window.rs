@@ -1,6 +1,8 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::maths::Vector2f32;
 
 /// The configuration for the initial window
@@ -9,6 +11,8 @@ use crate::maths::Vector2f32;
 /// [1.,0.] is the top right corner of the monitor
 /// [0.,1.] is the bottom left corner of the monitor
 /// [1.,1.] is the bottom right corner of the monitor
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub(crate) struct WindowConfig {
     /// The window's title
     pub(crate) title: String,
@@ -18,6 +22,11 @@ pub(crate) struct WindowConfig {
     /// `x` is the left of the window
     /// `y` is the top of the window
     /// The positions must be between [0., 0.] (left, top) and [1., 1.] (right, bottom)
+    /// In a configuration file, the position is written as `[x, y]`
+    #[serde(
+        serialize_with = "serialize_position",
+        deserialize_with = "deserialize_position"
+    )]
     pub(crate) position: Vector2f32,
 
     /// The window's width
@@ -31,6 +40,12 @@ pub(crate) struct WindowConfig {
     /// The window's border width
     /// The width is in pixels
     pub(crate) border_width: u16,
+
+    /// Whether to set the `LC_CTYPE' locale from the environment before opening the window
+    /// Rust programs start in the "C" locale, where the dead keys and compose sequences of the
+    /// user's keyboard layout are not available to the text input
+    /// The locale is process-wide, leave it unset if the application manages it
+    pub(crate) use_system_locale: bool,
 }
 
 impl Default for WindowConfig {
@@ -41,6 +56,24 @@ impl Default for WindowConfig {
             width: 1.,
             height: 1.,
             border_width: 4,
+            use_system_locale: false,
         }
     }
 }
+
+/// Writes a position as `[x, y]` in a configuration file
+fn serialize_position<S>(position: &Vector2f32, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    [position.x, position.y].serialize(serializer)
+}
+
+/// Reads a position written as `[x, y]` in a configuration file
+fn deserialize_position<'de, D>(deserializer: D) -> Result<Vector2f32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let [x, y] = <[f32; 2]>::deserialize(deserializer)?;
+    Ok(Vector2f32::new(x, y))
+}
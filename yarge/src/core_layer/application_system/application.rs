@@ -5,13 +5,21 @@ use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
 use std::collections::VecDeque;
 
-use crate::config::Version;
+use crate::error::{Error, Warning, WarningCollector, WarningType, report_warning};
+
+use crate::config::{ConfigChange, Version, WindowConfig};
 use crate::core_layer::application_system::events::user_events::UserEventWrapper;
+use crate::core_layer::logger_system::record::FRAME_INDEX;
 use crate::{
-    ECS, Game, config::Config, platform_layer::event::Event,
+    DisplayMode, ECS, Game, Time,
+    config::Config,
+    platform_layer::{
+        event::Event,
+        window::{MAIN_WINDOW_ID, Window},
+    },
     rendering_layer::types::RendererBeginFrameOutput,
 };
-use crate::{PlatformLayerImpl, RenderingLayer, RenderingLayerImpl};
+use crate::{PlatformLayer, PlatformLayerImpl, RenderingLayer, RenderingLayerImpl};
 
 /// The application system
 pub(crate) struct ApplicationSystem<'a> {
@@ -23,6 +31,9 @@ pub(crate) struct ApplicationSystem<'a> {
     /// The user defined game
     pub(crate) user_game: &'a mut dyn Game,
 
+    /// The configuration, used to create new windows
+    pub(crate) config: Config,
+
     /// The ECS
     pub(crate) ecs: ECS,
 
@@ -33,6 +44,9 @@ pub(crate) struct ApplicationSystem<'a> {
     pub(crate) engine_components: EngineComponents,
     /// The engine level resources
     pub(crate) engine_resources: EngineResources,
+
+    /// When the previous frame started, in nanoseconds of the platform's monotonic clock
+    pub(crate) last_frame_time: u128,
 }
 
 impl<'a> ApplicationSystem<'a> {
@@ -42,31 +56,32 @@ impl<'a> ApplicationSystem<'a> {
         config: &Config,
         platform_layer: &mut PlatformLayerImpl,
         rendering_layer: &mut RenderingLayerImpl<'a>,
-    ) -> Result<Self, ErrorType> {
+    ) -> Result<Self, Error> {
         let name = config.application_config.name.clone();
         let version = config.application_config.version.clone();
 
+        // Starts collecting the warnings of this run
+        WarningCollector::init(&config.warnings_config);
+
         // Inits the ECS system
-        let ecs = match ECS::init() {
+        let mut ecs = match ECS::init() {
             Ok(ecs) => ecs,
             Err(err) => {
-                log_error!(
-                    "Failed to initialize the ECS system when initializing the application: {:?}",
-                    err
-                );
-                return Err(ErrorType::Unknown);
+                return Err(err.context(
+                    "Failed to initialize the ECS system when initializing the application",
+                ));
             }
         };
 
+        ecs.time = Time::new(&config.timestep_config);
+
         let mut user_events = VecDeque::new();
         // Register engine level components
         let engine_components = match EngineComponents::init() {
             Err(err) => {
-                log_error!(
-                    "Failed to initialize the engine level ECS Components when initializing the application: {:?}",
-                    err
-                );
-                return Err(ErrorType::Unknown);
+                return Err(Error::from(err).context(
+                    "Failed to initialize the engine level ECS Components when initializing the application",
+                ));
             }
             Ok((engine_components, mut events)) => {
                 user_events.append(&mut events);
@@ -76,11 +91,9 @@ impl<'a> ApplicationSystem<'a> {
         // Register engine level resources
         let engine_resources = match EngineResources::init() {
             Err(err) => {
-                log_error!(
-                    "Failed to initialize the engine level ECS Resources when initializing the application: {:?}",
-                    err
-                );
-                return Err(ErrorType::Unknown);
+                return Err(Error::from(err).context(
+                    "Failed to initialize the engine level ECS Resources when initializing the application",
+                ));
             }
             Ok((engine_resources, mut events)) => {
                 user_events.append(&mut events);
@@ -89,14 +102,24 @@ impl<'a> ApplicationSystem<'a> {
         };
         // TODO: Register engine level systems
 
+        let last_frame_time = match PlatformLayerImpl::get_monotonic_time() {
+            Ok(time) => time,
+            Err(err) => {
+                return Err(Error::from(err)
+                    .context("Failed to get the start time when initializing the application"));
+            }
+        };
+
         let mut application = Self {
             name,
             version,
             user_game,
+            config: config.clone(),
             ecs,
             user_events,
             engine_components,
             engine_resources,
+            last_frame_time,
         };
         log_info!(
             "Application: {:?}, version: {:?} initialized",
@@ -108,56 +131,46 @@ impl<'a> ApplicationSystem<'a> {
         match application.user_game.on_start() {
             Ok(mut events) => application.user_events.append(&mut events),
             Err(err) => {
-                log_error!("The user game failed to start: {:?}", err);
-                return Err(ErrorType::Unknown);
+                return Err(Error::from(err).context("The user game failed to start"));
             }
         };
         // Must be called in case user generated entities in the 'on_start' event
         if let Err(err) = application.generate_ecs_entities() {
-            log_error!(
-                "Failed to generate entities in the ECS when initializing the application: {:?}",
-                err
-            );
-            return Err(ErrorType::Unknown);
+            return Err(err.context(
+                "Failed to generate entities in the ECS when initializing the application",
+            ));
         }
 
         match application.handle_user_events(platform_layer, rendering_layer) {
             Ok(true) => {
                 log_error!("User asked to quit the app on start");
-                return Err(ErrorType::WrongArgument(String::from(
+                return Err(Error::new(ErrorType::WrongArgument(String::from(
                     "`UserEvent::QuitApp' shouldn't be created inside `on_start()'",
-                )));
+                ))));
             }
             Err(err) => {
-                log_error!(
-                    "Failed to handle user events when initializing the application: {:?}",
-                    err
+                return Err(
+                    err.context("Failed to handle user events when initializing the application")
                 );
-                return Err(ErrorType::Unknown);
             }
             _ => {}
         }
 
         // Run ECS systems
         if let Err(err) = application.run_systems() {
-            log_error!(
-                "Failed to run the ECS systems when initializing the application: {:?}",
-                err
+            return Err(
+                err.context("Failed to run the ECS systems when initializing the application")
             );
-            return Err(ErrorType::Unknown);
         }
 
         Ok(application)
     }
 
     /// Generates ECS entities
-    pub(crate) fn generate_ecs_entities(&mut self) -> Result<(), ErrorType> {
+    pub(crate) fn generate_ecs_entities(&mut self) -> Result<(), Error> {
         if let Err(err) = self.ecs.spawn_real_entities() {
-            log_error!(
-                "Failed to spawn entities in the ECS from the application layer: {:?}",
-                err
-            );
-            return Err(ErrorType::Unknown);
+            return Err(Error::from(err)
+                .context("Failed to spawn entities in the ECS from the application layer"));
         }
         Ok(())
     }
@@ -166,23 +179,29 @@ impl<'a> ApplicationSystem<'a> {
     /// Returns true if the application should quit
     pub(crate) fn update(
         &mut self,
-        event: Event,
+        events: &[(u8, Event)],
         platform_layer: &mut PlatformLayerImpl,
         rendering_layer: &mut RenderingLayerImpl<'_>,
-    ) -> Result<bool, ErrorType> {
+    ) -> Result<bool, Error> {
         // Handle application events
-        match self.handle_event(event) {
-            Ok(mut events) => {
-                self.user_events.append(&mut events);
-            }
-            Err(err) => {
-                log_error!(
-                    "Failed to handle an event in the application layer: {:?}",
-                    err
-                );
-                return Err(ErrorType::Unknown);
+        for (window_id, event) in events {
+            match self.handle_event(*window_id, *event) {
+                Ok(mut events) => {
+                    self.user_events.append(&mut events);
+                }
+                Err(err) => {
+                    return Err(Error::from(err)
+                        .context("Failed to handle an event in the application layer"));
+                }
+            };
+            // Closing a secondary window destroys it, closing the main one quits
+            if *window_id != MAIN_WINDOW_ID
+                && *event == Event::WindowClosed
+                && let Err(err) = self.destroy_window(*window_id, platform_layer, rendering_layer)
+            {
+                return Err(err.context("Failed to destroy a closed window"));
             }
-        };
+        }
 
         // Handle resource loading
         match self.handle_loading_resources(platform_layer, rendering_layer) {
@@ -190,170 +209,335 @@ impl<'a> ApplicationSystem<'a> {
                 self.user_events.append(&mut events);
             }
             Err(err) => {
-                log_error!(
-                    "Failed to handle loading resources in the application layer: {:?}",
-                    err
+                return Err(
+                    err.context("Failed to handle loading resources in the application layer")
                 );
-                return Err(ErrorType::Unknown);
             }
         };
 
-        let delta_time = 0.;
+        if let Err(err) = self.fixed_update() {
+            return Err(err.context("Failed to run the fixed updates in the application layer"));
+        }
+
+        let delta_time = self.ecs.time.delta_time;
         match self.user_game.on_update(delta_time) {
             Ok(mut events) => {
                 self.user_events.append(&mut events);
             }
             Err(err) => {
-                log_error!(
-                    "Failed to update the game in the application layer: {:?}",
-                    err
+                return Err(
+                    Error::from(err).context("Failed to update the game in the application layer")
                 );
-                return Err(ErrorType::Unknown);
             }
         };
 
         // Handle user events
         // Begin by generate new entities if needed
         if let Err(err) = self.generate_ecs_entities() {
-            log_error!(
-                "Failed to generate entities in the ECS when updating the application: {:?}",
-                err
+            return Err(
+                err.context("Failed to generate entities in the ECS when updating the application")
             );
-            return Err(ErrorType::Unknown);
         }
         let should_quit = match self.handle_user_events(platform_layer, rendering_layer) {
             Ok(should_quit) => should_quit,
             Err(err) => {
-                log_error!(
-                    "Failed to handle user events in the application layer: {:?}",
-                    err
-                );
-                return Err(ErrorType::Unknown);
+                return Err(err.context("Failed to handle user events in the application layer"));
             }
         };
 
         // Run ECS systems
         if let Err(err) = self.run_systems() {
-            log_error!(
-                "Failed to run the ECS systems in the application layer: {:?}",
-                err
-            );
-            return Err(ErrorType::Unknown);
+            return Err(err.context("Failed to run the ECS systems in the application layer"));
         }
 
         Ok(should_quit)
     }
 
+    /// Runs the fixed updates consuming the frame's time
+    fn fixed_update(&mut self) -> Result<(), Error> {
+        let nb_steps = self.ecs.time.consume_fixed_steps();
+        let step = self.ecs.time.fixed_step;
+        for _ in 0..nb_steps {
+            match self.user_game.on_fixed_update(step) {
+                Ok(mut events) => {
+                    self.user_events.append(&mut events);
+                }
+                Err(err) => {
+                    return Err(Error::from(err).context("The user game failed a fixed update"));
+                }
+            };
+            if let Err(err) = self.generate_ecs_entities() {
+                return Err(err.context("Failed to generate entities in the ECS in a fixed update"));
+            }
+            if let Err(err) = self.run_fixed_timestep_systems() {
+                return Err(err.context("Failed to run the fixed timestep systems"));
+            }
+        }
+        Ok(())
+    }
+
     /// Renders the application
+    /// Nothing is rendered while the window is minimized
     /// Returns true if the application should quit
     pub(crate) fn render(
         &mut self,
-        event: Event,
         platform_layer: &mut PlatformLayerImpl,
         rendering_layer: &mut RenderingLayerImpl<'_>,
-    ) -> Result<bool, ErrorType> {
-        if event == Event::Expose {
-            // Begin by generate new entities if needed
-            if let Err(err) = self.generate_ecs_entities() {
-                log_error!(
-                    "Failed to generate entities in the ECS when updating the application: {:?}",
-                    err
-                );
-                return Err(ErrorType::Unknown);
+    ) -> Result<bool, Error> {
+        let main_window = match platform_layer.get_window_ref(MAIN_WINDOW_ID) {
+            Ok(window) => window,
+            Err(err) => {
+                return Err(Error::from(err).context("Failed to get the main window to render"));
             }
+        };
+        if main_window.get_properties().display_mode == DisplayMode::Minimized {
+            return Ok(false);
+        }
 
-            // TODO: create rendering packet
-            let delta_time = 0.;
-            match self.user_game.on_render(delta_time) {
-                Ok(mut events) => {
-                    self.user_events.append(&mut events);
-                }
-                Err(err) => {
-                    log_error!(
-                        "Failed to render the game in the application layer: {:?}",
-                        err
-                    );
-                    return Err(ErrorType::Unknown);
-                }
-            };
-            match rendering_layer.begin_frame() {
-                Err(err) => {
-                    log_error!(
-                        "Failed to begin a frame in the application layer: {:?}",
-                        err
-                    );
-                    return Err(ErrorType::Unknown);
-                }
-                Ok(RendererBeginFrameOutput::Success) => {
-                    if let Err(err) = rendering_layer.end_frame(platform_layer) {
-                        log_error!("Failed to end a frame in the application layer: {:?}", err);
-                        return Err(ErrorType::Unknown);
-                    }
-                }
-                _ => {}
-            }
+        // Begin by generate new entities if needed
+        if let Err(err) = self.generate_ecs_entities() {
+            return Err(
+                err.context("Failed to generate entities in the ECS when updating the application")
+            );
+        }
 
-            // Handle user events
-            match self.handle_user_events(platform_layer, rendering_layer) {
-                Ok(should_quit) => return Ok(should_quit),
-                Err(err) => {
-                    log_error!(
-                        "Failed to handle user events in the application layer: {:?}",
-                        err
+        // TODO: create rendering packet
+        let delta_time = self.ecs.time.delta_time;
+        match self.user_game.on_render(delta_time, self.ecs.time.alpha) {
+            Ok(mut events) => {
+                self.user_events.append(&mut events);
+            }
+            Err(err) => {
+                return Err(
+                    Error::from(err).context("Failed to render the game in the application layer")
+                );
+            }
+        };
+        match rendering_layer.begin_frame() {
+            Err(err) => {
+                return Err(
+                    Error::from(err).context("Failed to begin a frame in the application layer")
+                );
+            }
+            Ok(RendererBeginFrameOutput::Success) => {
+                if let Err(err) = rendering_layer.end_frame(platform_layer) {
+                    return Err(
+                        Error::from(err).context("Failed to end a frame in the application layer")
                     );
-                    return Err(ErrorType::Unknown);
                 }
             }
+            _ => {}
+        }
+
+        // Handle user events
+        match self.handle_user_events(platform_layer, rendering_layer) {
+            Ok(should_quit) => Ok(should_quit),
+            Err(err) => Err(err.context("Failed to handle user events in the application layer")),
         }
-        Ok(false)
     }
 
     /// One iteration of the infinite running loop
+    /// Handles all the events polled since the previous frame
     /// Returns true if the application should quit
     pub(crate) fn loop_iteration(
         &mut self,
-        event: Event,
+        events: &[(u8, Event)],
         platform_layer: &mut PlatformLayerImpl,
         rendering_layer: &mut RenderingLayerImpl<'_>,
-    ) -> Result<bool, ErrorType> {
-        match self.update(event, platform_layer, rendering_layer) {
+    ) -> Result<bool, Error> {
+        // Stamps the following logs with the new frame
+        let frame = FRAME_INDEX.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+        #[cfg(tracing_bridge)]
+        let _frame_span = tracing::debug_span!(
+            target: crate::core_layer::logger_system::bridge::TRACING_TARGET,
+            "frame",
+            index = frame
+        )
+        .entered();
+        #[cfg(not(tracing_bridge))]
+        let _ = frame;
+        let frame_start = match PlatformLayerImpl::get_monotonic_time() {
+            Ok(time) => time,
+            Err(err) => {
+                return Err(Error::from(err).context("Failed to get the start time of the frame"));
+            }
+        };
+        self.ecs.time.start_frame(self.last_frame_time, frame_start);
+        self.last_frame_time = frame_start;
+
+        match self.update(events, platform_layer, rendering_layer) {
             Ok(true) => return Ok(true),
             Err(err) => {
-                log_error!("Failed to update the application layer: {:?}", err);
-                return Err(ErrorType::Unknown);
+                return Err(err.context("Failed to update the application layer"));
             }
             _ => {}
         };
 
-        match self.render(event, platform_layer, rendering_layer) {
+        match self.render(platform_layer, rendering_layer) {
             Ok(true) => return Ok(true),
             Err(err) => {
-                log_error!("Failed to render the application layer: {:?}", err);
-                return Err(ErrorType::Unknown);
+                return Err(err.context("Failed to render the application layer"));
             }
             _ => {}
         }
 
+        if let Err(err) = self.end_frame(frame_start) {
+            return Err(err.context("Failed to end the frame in the application layer"));
+        }
+
         Ok(false)
     }
 
+    /// Checks the frame duration and gives the frame's warnings to the user's game
+    fn end_frame(&mut self, frame_start: u128) -> Result<(), Error> {
+        let slow_frame_threshold = WarningCollector::slow_frame_threshold();
+        if slow_frame_threshold > 0 {
+            let frame_end = match PlatformLayerImpl::get_monotonic_time() {
+                Ok(time) => time,
+                Err(err) => {
+                    return Err(Error::from(err).context("Failed to get the end time of the frame"));
+                }
+            };
+            // In milliseconds
+            let duration = frame_end.saturating_sub(frame_start) / 1_000_000;
+            if duration > u128::from(slow_frame_threshold) {
+                report_warning(WarningType::SlowFrame(duration));
+            }
+        }
+
+        let warnings = WarningCollector::end_frame();
+        if warnings.is_empty() {
+            return Ok(());
+        }
+        match self.user_game.on_warnings(&warnings) {
+            Ok(mut events) => {
+                self.user_events.append(&mut events);
+                Ok(())
+            }
+            Err(err) => {
+                Err(Error::from(err).context("The user game failed to handle the warnings"))
+            }
+        }
+    }
+
+    /// Logs all the warnings reported during the run
+    fn log_warnings_summary(warnings: &[Warning]) {
+        if warnings.is_empty() {
+            return;
+        }
+        let total: usize = warnings.iter().map(|warning| warning.count).sum();
+        let mut summary = format!(
+            "{:?} warning(s) reported during the run, {:?} distinct:",
+            total,
+            warnings.len()
+        );
+        for warning in warnings {
+            summary.push_str(&format!(
+                "\n\t- {} ({:?} time(s), first in frame {:?})",
+                warning.kind, warning.count, warning.frame
+            ));
+        }
+        log_warn!("{}", summary);
+    }
+
+    /// Notifies the user's game that the configuration was reloaded
+    /// Returns true if the application should quit
+    pub(crate) fn on_config_reloaded(
+        &mut self,
+        changes: &[ConfigChange],
+        platform_layer: &mut PlatformLayerImpl,
+        rendering_layer: &mut RenderingLayerImpl<'_>,
+    ) -> Result<bool, Error> {
+        match self.user_game.on_config_reloaded(changes) {
+            Ok(mut events) => {
+                self.user_events.append(&mut events);
+            }
+            Err(err) => {
+                return Err(Error::from(err)
+                    .context("The user game failed to handle the configuration reload"));
+            }
+        };
+
+        match self.handle_user_events(platform_layer, rendering_layer) {
+            Ok(should_quit) => Ok(should_quit),
+            Err(err) => {
+                Err(err.context("Failed to handle user events after reloading the configuration"))
+            }
+        }
+    }
+
+    /// Opens a secondary window and prepares the rendering layer to render to it
+    pub(crate) fn create_window(
+        &mut self,
+        window_id: u8,
+        window_config: WindowConfig,
+        platform_layer: &mut PlatformLayerImpl,
+        rendering_layer: &mut RenderingLayerImpl<'_>,
+    ) -> Result<(), Error> {
+        let mut config = self.config.clone();
+        config.window_config = window_config;
+        if let Err(err) = platform_layer.create_window(window_id, &config) {
+            return Err(Error::from(err).context(format!(
+                "Failed to create the window `{:?}' in the platform layer",
+                window_id
+            )));
+        }
+        if let Err(err) = rendering_layer.init_window(&config, platform_layer, window_id) {
+            // The window can't be used without being rendered to
+            if let Err(destroy_err) = platform_layer.destroy_window(window_id) {
+                log_error!(
+                    "Failed to destroy the window `{:?}' after failing to render to it: {:?}",
+                    window_id,
+                    destroy_err
+                );
+            }
+            return Err(Error::from(err).context(format!(
+                "Failed to initialize the window `{:?}' in the rendering layer",
+                window_id
+            )));
+        }
+        log_debug!("Window `{:?}' created", window_id);
+        Ok(())
+    }
+
+    /// Closes a secondary window after the rendering layer stopped rendering to it
+    pub(crate) fn destroy_window(
+        &mut self,
+        window_id: u8,
+        platform_layer: &mut PlatformLayerImpl,
+        rendering_layer: &mut RenderingLayerImpl<'_>,
+    ) -> Result<(), Error> {
+        if let Err(err) = rendering_layer.shutdown_window(window_id) {
+            return Err(Error::from(err).context(format!(
+                "Failed to shutdown the window `{:?}' in the rendering layer",
+                window_id
+            )));
+        }
+        if let Err(err) = platform_layer.destroy_window(window_id) {
+            return Err(Error::from(err).context(format!(
+                "Failed to destroy the window `{:?}' in the platform layer",
+                window_id
+            )));
+        }
+        log_debug!("Window `{:?}' destroyed", window_id);
+        Ok(())
+    }
+
     /// Shuts down the application
-    pub(crate) fn shutdown(&mut self) -> Result<(), ErrorType> {
+    pub(crate) fn shutdown(&mut self) -> Result<(), Error> {
         // Shuts down the ECS system
         if let Err(err) = self.ecs.shutdown() {
-            log_error!(
-                "Failed to shut down the ECS system when shutting down the application: {:?}",
-                err
-            );
-            return Err(ErrorType::Unknown);
+            return Err(err
+                .context("Failed to shut down the ECS system when shutting down the application"));
         }
 
         // Shuts down the user's game
         if let Err(err) = self.user_game.on_shutdown() {
-            log_error!("The user game failed to shutdown: {:?}", err);
-            return Err(ErrorType::Unknown);
+            return Err(Error::from(err).context("The user game failed to shutdown"));
         }
 
+        Self::log_warnings_summary(&WarningCollector::summary());
+
         log_info!("Application system shutted down");
 
         Ok(())
@@ -363,7 +547,7 @@ impl<'a> ApplicationSystem<'a> {
         &mut self,
         _platform_layer: &mut PlatformLayerImpl,
         _rendering_layer: &mut RenderingLayerImpl<'_>,
-    ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+    ) -> Result<VecDeque<UserEventWrapper>, Error> {
         let mut user_events = VecDeque::new();
 
         // TODO: find a workaround to avoid copying
@@ -378,22 +562,17 @@ impl<'a> ApplicationSystem<'a> {
         for (type_id, real_id) in &loading_resources {
             match self.ecs.resource_manager.sys_try_get(real_id, type_id) {
                 Err(err) => {
-                    log_error!(
-                        "Failed to try getting a loading resource in the application: {:?}",
-                        err
-                    );
-                    return Err(ErrorType::Unknown);
+                    return Err(Error::from(err)
+                        .context("Failed to try getting a loading resource in the application"));
                 }
                 Ok(None) => {}
                 Ok(Some(handler)) => {
                     let user_resource_id = match ResourceManager::get_user_id(real_id) {
                         Ok(id) => id,
                         Err(err) => {
-                            log_error!(
-                                "Failed to get the user id when handling a loaded resource in the application: {:?}",
-                                err
-                            );
-                            return Err(ErrorType::Unknown);
+                            return Err(Error::from(err).context(
+                                "Failed to get the user id when handling a loaded resource in the application",
+                            ));
                         }
                     };
                     match self
@@ -401,11 +580,8 @@ impl<'a> ApplicationSystem<'a> {
                         .on_resource_loaded(&user_resource_id, handler)
                     {
                         Err(err) => {
-                            log_error!(
-                                "The user game failed to handle a loaded resource: {:?}",
-                                err
-                            );
-                            return Err(ErrorType::Unknown);
+                            return Err(Error::from(err)
+                                .context("The user game failed to handle a loaded resource"));
                         }
                         Ok(mut events) => {
                             user_events.append(&mut events);
@@ -1,6 +1,8 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use crate::error::Error;
+
 /// A module representing generatioonal indices structures
 /// See https://lucassardois.medium.com/generational-indices-guide-8e3c5f7fd594
 pub(crate) mod generational;
@@ -14,6 +16,8 @@ pub(crate) mod entity;
 pub(crate) mod query;
 pub(crate) mod resource;
 pub(crate) mod system;
+/// A module representing the time of the frame
+pub(crate) mod time;
 
 pub(crate) mod engine;
 
@@ -52,19 +56,20 @@ pub struct ECS {
     pub(crate) resource_manager: resource::ResourceManager,
 
     pub(crate) system_manager: system::SystemManager,
+
+    /// The time of the current frame
+    pub(crate) time: time::Time,
 }
 
 impl ECS {
     /// Initializes the ECS
-    pub(crate) fn init() -> Result<Self, ErrorType> {
+    pub(crate) fn init() -> Result<Self, Error> {
         let component_manager = match component::ComponentManager::init() {
             Ok(manager) => manager,
             Err(err) => {
-                log_error!(
-                    "Failed to initialize the ComponentManager when initializing the ECS system: {:?}",
-                    err
-                );
-                return Err(ErrorType::Unknown);
+                return Err(Error::from(err).context(
+                    "Failed to initialize the ComponentManager when initializing the ECS system",
+                ));
             }
         };
 
@@ -79,11 +84,12 @@ impl ECS {
             component_manager,
             resource_manager,
             system_manager,
+            time: time::Time::default(),
         })
     }
 
     /// Shuts down the ECS
-    pub(crate) fn shutdown(&mut self) -> Result<(), ErrorType> {
+    pub(crate) fn shutdown(&mut self) -> Result<(), Error> {
         match entity::GLOBAL_ENTITY_GENERATOR.write() {
             Ok(mut generator) => {
                 generator.shutdown();
@@ -93,16 +99,14 @@ impl ECS {
                     "Failed to access the global entity generator when shutting down the ECS: {:?}",
                     err
                 );
-                return Err(ErrorType::Unknown);
+                return Err(Error::new(ErrorType::Unknown)
+                    .context("Failed to access the global entity generator"));
             }
         }
 
         if let Err(err) = self.resource_manager.shutdown() {
-            log_error!(
-                "Failed to shut down the resource manager when shutting down the ECS: {:?}",
-                err
-            );
-            return Err(ErrorType::Unknown);
+            return Err(Error::from(err)
+                .context("Failed to shut down the resource manager when shutting down the ECS"));
         }
 
         log_info!("ECS shutted down");
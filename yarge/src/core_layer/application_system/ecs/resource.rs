@@ -1,6 +1,7 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use crate::error::{WarningType, report_warning};
 use crate::platform_layer::platform_impl::PlatformLayerRwLock;
 
 /// A resource type Id
@@ -26,10 +27,17 @@ impl ResourceLoadingBuilder {
         std::sync::Arc::new(move || {
             let loaded_data = match params.load_resource() {
                 Ok(data) => data,
-                Err(err) => {
-                    log_error!("Failed to load the `{:?}' resource: {:?}", params, err);
-                    return Err(ErrorType::Unknown);
-                }
+                Err(err) => match params.get_fallback_resource() {
+                    Some(fallback) => {
+                        log_debug!("Failed to load the `{:?}' resource: {:?}", params, err);
+                        report_warning(WarningType::MissingResource(format!("{:?}", params)));
+                        fallback
+                    }
+                    None => {
+                        log_error!("Failed to load the `{:?}' resource: {:?}", params, err);
+                        return Err(ErrorType::Unknown);
+                    }
+                },
             };
             let handler = std::sync::Arc::new(loaded_data);
             Ok(ResourceHandle(handler))
@@ -97,6 +105,12 @@ pub(crate) trait ResourceLoadingParameters<R: Resource>:
     std::hash::Hash + std::any::Any + Send + Sync + std::fmt::Debug + Clone + 'static
 {
     fn load_resource(&self) -> Result<R, ErrorType>;
+
+    /// The resource used instead when the loading fails
+    /// Default behavior: no fallback, the loading fails
+    fn get_fallback_resource(&self) -> Option<R> {
+        None
+    }
 }
 pub trait Resource: std::any::Any + Send + Sync + 'static {}
 
@@ -107,6 +121,12 @@ pub trait UserResourceLoadingParameters<R: UserResource>:
     std::hash::Hash + std::any::Any + Send + Sync + std::fmt::Debug + Clone + 'static
 {
     fn load_resource(&self) -> Result<R, ErrorType>;
+
+    /// The resource used instead when the loading fails, a missing resource warning is reported
+    /// Default behavior: no fallback, the loading fails
+    fn get_fallback_resource(&self) -> Option<R> {
+        None
+    }
 }
 
 impl<T, R> ResourceLoadingParameters<R> for T
@@ -117,6 +137,10 @@ where
     fn load_resource(&self) -> Result<R, ErrorType> {
         T::load_resource(self)
     }
+
+    fn get_fallback_resource(&self) -> Option<R> {
+        T::get_fallback_resource(self)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -643,3 +667,19 @@ impl ResourceManager {
         }
     }
 }
+
+//////////////////////////////////////////////////////////
+///////////////    resource tests    /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_resource_fallback() {
+        let path = std::path::PathBuf::from("missing_resource_fallback.obj");
+        let loader = ResourceLoadingBuilder::loader::<std::path::PathBuf, crate::ObjFile>(&path);
+        let obj_file = loader().unwrap().get::<crate::ObjFile>().unwrap();
+        assert!(obj_file.meshes.is_empty());
+    }
+}
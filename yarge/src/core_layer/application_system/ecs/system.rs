@@ -2,6 +2,7 @@
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
 use crate::core_layer::application_system::events::user_events::UserEventWrapper;
+use crate::error::Error;
 use std::collections::VecDeque;
 
 pub(crate) type SystemCallbackConditionFunction =
@@ -48,6 +49,9 @@ pub enum SystemSchedule {
     ForXUpdates(usize),
     /// The system will be called once every X updates
     EveryXUpdates(usize),
+    /// The system will be called at every fixed update instead of every update
+    /// See [crate::Game::on_fixed_update]
+    FixedTimestep,
 }
 
 pub trait SystemParam {
@@ -368,6 +372,24 @@ impl SystemParam for &mut super::resource::ResourceManager {
     }
 }
 
+impl SystemParam for &super::time::Time {
+    type State = ();
+
+    type Item<'w, 's> = &'w super::time::Time;
+
+    fn init_state(_game: &dyn crate::Game, _ecs: &crate::ECS) -> Result<Self::State, ErrorType> {
+        Ok(())
+    }
+
+    unsafe fn get_item<'w, 's>(
+        _state: &'s mut Self::State,
+        _game_ptr: &'w crate::UnsafeGameCell,
+        ecs_ptr: &'w crate::UnsafeECSCell,
+    ) -> Result<Self::Item<'w, 's>, ErrorType> {
+        Ok(&unsafe { ecs_ptr.get() }.time)
+    }
+}
+
 /// A wrapper around the real system closure
 pub struct SystemFuncWrapper<Func, Param>
 where
@@ -634,7 +656,7 @@ impl SystemInternal {
                 self.schedule = SystemSchedule::Never;
                 true
             }
-            SystemSchedule::Always => true,
+            SystemSchedule::Always | SystemSchedule::FixedTimestep => true,
             SystemSchedule::ForXUpdates(nb_frames_remaining) => {
                 if nb_frames_remaining == 1 {
                     self.schedule = SystemSchedule::Never;
@@ -717,14 +739,42 @@ impl SystemManager {
         }
     }
 
-    /// Run all the systems and clean the dead ones
+    /// Run all the systems of an update and clean the dead ones
     pub(crate) fn run_all(
         &mut self,
         game: &mut dyn crate::Game,
         ecs_ptr: &crate::UnsafeECSCell,
-    ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+    ) -> Result<VecDeque<UserEventWrapper>, Error> {
+        match self.run_scheduled(game, ecs_ptr, false) {
+            Ok(events) => Ok(events),
+            Err(err) => Err(err.context("Failed to run the ECS systems")),
+        }
+    }
+
+    /// Run all the systems of a fixed update and clean the dead ones
+    pub(crate) fn run_fixed_timestep(
+        &mut self,
+        game: &mut dyn crate::Game,
+        ecs_ptr: &crate::UnsafeECSCell,
+    ) -> Result<VecDeque<UserEventWrapper>, Error> {
+        match self.run_scheduled(game, ecs_ptr, true) {
+            Ok(events) => Ok(events),
+            Err(err) => Err(err.context("Failed to run the ECS fixed timestep systems")),
+        }
+    }
+
+    /// Run either the fixed timestep systems or the other ones
+    fn run_scheduled(
+        &mut self,
+        game: &mut dyn crate::Game,
+        ecs_ptr: &crate::UnsafeECSCell,
+        is_fixed_timestep: bool,
+    ) -> Result<VecDeque<UserEventWrapper>, Error> {
         let mut user_events = VecDeque::new();
         for system in &mut self.systems {
+            if (system.internal.schedule == SystemSchedule::FixedTimestep) != is_fixed_timestep {
+                continue;
+            }
             match (system.internal.condition)(game) {
                 Ok(should_run) => {
                     if should_run && system.should_run_this_update() {
@@ -732,18 +782,15 @@ impl SystemManager {
                         match system.system_trait.run(&game_ptr, ecs_ptr) {
                             Ok(mut events) => user_events.append(&mut events),
                             Err(err) => {
-                                log_error!("Failed to run a system: {:?}", err);
-                                return Err(ErrorType::Unknown);
+                                return Err(Error::from(err).context("Failed to run a system"));
                             }
                         }
                     }
                 }
                 Err(err) => {
-                    log_error!(
-                        "Failed to check the condition when running systems: {:?}",
-                        err
+                    return Err(
+                        Error::from(err).context("Failed to check the condition of a system")
                     );
-                    return Err(ErrorType::Unknown);
                 }
             }
         }
@@ -883,13 +930,20 @@ impl SystemManager {
 }
 
 impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
-    pub(crate) fn run_systems(&mut self) -> Result<(), ErrorType> {
+    pub(crate) fn run_systems(&mut self) -> Result<(), Error> {
         let ecs_ptr = crate::UnsafeECSCell::new(&mut self.ecs);
-        if let Err(err) = self.ecs.system_manager.run_all(self.user_game, &ecs_ptr) {
-            log_error!("Failed to run the systems in the application: {:?}", err);
-            return Err(ErrorType::Unknown);
-        }
-        Ok(())
+        self.ecs
+            .system_manager
+            .run_all(self.user_game, &ecs_ptr)
+            .map(|_| ())
+    }
+
+    pub(crate) fn run_fixed_timestep_systems(&mut self) -> Result<(), Error> {
+        let ecs_ptr = crate::UnsafeECSCell::new(&mut self.ecs);
+        self.ecs
+            .system_manager
+            .run_fixed_timestep(self.user_game, &ecs_ptr)
+            .map(|_| ())
     }
 }
 
@@ -1136,6 +1190,71 @@ mod tests {
         assert_eq!(game.test, 3u32);
     }
 
+    #[test]
+    fn systems_error_context() {
+        let mut game = TestGame { test: 0u32 };
+        let mut ecs = crate::ECS::init().unwrap();
+
+        #[macros::system]
+        fn test_system(_game: &mut TestGame) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+            Err(ErrorType::DoesNotExist)
+        }
+
+        let internal = default_system_internal!();
+        let mut system = test_system.as_system();
+        system.init(&game, &ecs).unwrap();
+        ecs.system_manager.add_system(internal, system).unwrap();
+
+        // The original cause is kept under the ECS context
+        let ecs_ptr = crate::UnsafeECSCell::new(&mut ecs);
+        let Err(err) = ecs.system_manager.run_all(&mut game, &ecs_ptr) else {
+            panic!("The failing system should make the ECS systems fail");
+        };
+        assert!(matches!(err.kind(), ErrorType::DoesNotExist));
+        assert_eq!(
+            err.contexts().collect::<Vec<_>>(),
+            vec!["Failed to run the ECS systems", "Failed to run a system"]
+        );
+    }
+
+    #[test]
+    fn systems_with_fixed_timestep_running() {
+        // Init Game
+        let mut game = TestGame { test: 0u32 };
+        // Init ecs
+        let mut ecs = crate::ECS::init().unwrap();
+        ecs.time.fixed_step = 0.5;
+
+        #[macros::system]
+        fn test_system(
+            game: &mut TestGame,
+            time: &super::super::time::Time,
+        ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+            game.test += (time.fixed_step * 20.) as u32;
+            Ok(VecDeque::new())
+        }
+
+        let internal = SystemInternal::new(
+            SystemSchedule::FixedTimestep,
+            UserSystemConditionBuilder::default_condition(),
+        );
+        let mut system = test_system.as_system();
+        system.init(&game, &ecs).unwrap();
+        ecs.system_manager.add_system(internal, system).unwrap();
+
+        // Not run during the updates
+        let ecs_ptr = crate::UnsafeECSCell::new(&mut ecs);
+        let _ = ecs.system_manager.run_all(&mut game, &ecs_ptr).unwrap();
+        assert_eq!(game.test, 0u32);
+
+        let ecs_ptr = crate::UnsafeECSCell::new(&mut ecs);
+        let _ = ecs
+            .system_manager
+            .run_fixed_timestep(&mut game, &ecs_ptr)
+            .unwrap();
+        assert_eq!(game.test, 10u32);
+    }
+
     #[test]
     fn systems_with_queries_running() {
         // Helpers
@@ -0,0 +1,131 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use crate::config::TimestepConfig;
+
+/// The timing statistics of a frame
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FrameStats {
+    /// The time spent updating and rendering the frame in seconds
+    pub work_time: f64,
+    /// The time spent waiting for the frame rate limiter in seconds
+    pub wait_time: f64,
+    /// The number of frames per second, smoothed over the last frames
+    pub average_fps: f64,
+    /// The frame rate cap applied to the frame, 0 meaning no limit
+    pub frame_cap: u32,
+}
+
+/// The time of the current frame
+/// It can be used as a system parameter to get the delta time
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Time {
+    /// The time ellapsed since the previous frame in seconds
+    pub delta_time: f64,
+    /// The time ellapsed since the application started in seconds
+    pub elapsed_time: f64,
+
+    /// The duration of a fixed update in seconds
+    pub fixed_step: f64,
+    /// How far the frame is between the last two fixed updates, in [0, 1[
+    /// Used to interpolate the states of the fixed updates when rendering
+    pub alpha: f64,
+
+    /// The timing statistics of the previous frame
+    pub frame_stats: FrameStats,
+
+    /// The time not yet consumed by the fixed updates in seconds
+    pub(crate) accumulator: f64,
+    /// The maximum number of fixed updates run in a single frame
+    pub(crate) max_catch_up_steps: u32,
+}
+
+impl Time {
+    /// Creates the time of the application from its fixed timestep configuration
+    pub(crate) fn new(config: &TimestepConfig) -> Self {
+        Self {
+            fixed_step: config.get_step(),
+            max_catch_up_steps: config.max_catch_up_steps,
+            ..Default::default()
+        }
+    }
+
+    /// Starts a new frame at the given time in nanoseconds of the platform's monotonic clock
+    pub(crate) fn start_frame(&mut self, previous_frame_time: u128, frame_time: u128) {
+        self.delta_time = Self::to_seconds(frame_time.saturating_sub(previous_frame_time));
+        self.elapsed_time += self.delta_time;
+    }
+
+    /// Consumes the frame's time by fixed steps
+    /// Returns the number of fixed updates to run this frame
+    pub(crate) fn consume_fixed_steps(&mut self) -> u32 {
+        if self.fixed_step <= 0. {
+            return 0;
+        }
+        self.accumulator += self.delta_time;
+        let mut nb_steps = 0;
+        while self.accumulator >= self.fixed_step {
+            if nb_steps == self.max_catch_up_steps {
+                // Drops the late steps instead of spiraling
+                log_debug!(
+                    "The fixed updates are too late, skipping {:?} step(s)",
+                    (self.accumulator / self.fixed_step) as u64
+                );
+                self.accumulator %= self.fixed_step;
+                break;
+            }
+            self.accumulator -= self.fixed_step;
+            nb_steps += 1;
+        }
+        self.alpha = self.accumulator / self.fixed_step;
+        nb_steps
+    }
+
+    /// Converts nanoseconds to seconds
+    pub(crate) fn to_seconds(nanoseconds: u128) -> f64 {
+        (nanoseconds as f64) / 1_000_000_000.
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////       time tests       ///////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_frame() {
+        let mut time = Time::default();
+        time.start_frame(1_000_000_000, 1_016_000_000);
+        assert_eq!(time.delta_time, 0.016);
+        time.start_frame(1_016_000_000, 1_516_000_000);
+        assert_eq!(time.delta_time, 0.5);
+        assert!((time.elapsed_time - 0.516).abs() < 1e-9);
+
+        // A clock going backward doesn't make the time negative
+        time.start_frame(1_516_000_000, 1_000_000_000);
+        assert_eq!(time.delta_time, 0.);
+    }
+
+    #[test]
+    fn fixed_steps() {
+        let mut time = Time::new(&TimestepConfig {
+            step_rate: 4,
+            max_catch_up_steps: 3,
+        });
+        assert_eq!(time.fixed_step, 0.25);
+
+        time.delta_time = 0.125;
+        assert_eq!(time.consume_fixed_steps(), 0);
+        assert_eq!(time.alpha, 0.5);
+        time.delta_time = 0.5;
+        assert_eq!(time.consume_fixed_steps(), 2);
+        assert_eq!(time.alpha, 0.5);
+
+        // Catches up at most 3 steps and drops the rest
+        time.delta_time = 2.;
+        assert_eq!(time.consume_fixed_steps(), 3);
+        assert_eq!(time.alpha, 0.5);
+    }
+}
@@ -1,8 +1,11 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use std::sync::atomic::{AtomicU8, Ordering};
+
 use crate::{
-    SystemSchedule,
+    DisplayMode, LogLevel, LogSink, LogTarget, SystemSchedule,
+    config::WindowConfig,
     core_layer::application_system::{
         ecs::{
             component::{
@@ -22,8 +25,15 @@ use crate::{
         },
         events::user_events::{UserEvent, UserEventWrapper},
     },
+    maths::Vector2f32,
+    mouse::{CursorGrabMode, CursorImage},
+    platform_layer::window::MAIN_WINDOW_ID,
 };
 
+/// The id of the next secondary window, the main one being 0
+/// The ids are never reused, at most 254 secondary windows can be created during a run
+static NEXT_WINDOW_ID: AtomicU8 = AtomicU8::new(MAIN_WINDOW_ID + 1);
+
 pub struct QuitAppEventBuilder;
 impl QuitAppEventBuilder {
     pub fn build() -> Result<UserEventWrapper, ErrorType> {
@@ -380,3 +390,438 @@ impl RegisterSystemEventBuilder {
         })
     }
 }
+
+#[derive(Default)]
+pub struct AddLogSinkEventBuilder {
+    /// The name of the sink
+    name: Option<String>,
+    /// Where to log
+    target: LogTarget,
+    /// The minimum level of the logs sent to the sink
+    min_level: LogLevel,
+}
+impl AddLogSinkEventBuilder {
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(String::from(name));
+        self
+    }
+    pub fn target(mut self, target: LogTarget) -> Self {
+        self.target = target;
+        self
+    }
+    pub fn min_level(mut self, min_level: LogLevel) -> Self {
+        self.min_level = min_level;
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        let Some(name) = self.name.filter(|name| !name.is_empty()) else {
+            log_error!("Can't build an `AddLogSink' event without a name");
+            return Err(ErrorType::DoesNotExist);
+        };
+        Ok(UserEventWrapper {
+            event: UserEvent::AddLogSink {
+                sink: LogSink::new(&name, self.target, self.min_level),
+            },
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct RemoveLogSinkEventBuilder {
+    /// The name of the sink to remove
+    name: Option<String>,
+}
+impl RemoveLogSinkEventBuilder {
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(String::from(name));
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        let Some(name) = self.name else {
+            log_error!("Can't build a `RemoveLogSink' event without a name");
+            return Err(ErrorType::DoesNotExist);
+        };
+        Ok(UserEventWrapper {
+            event: UserEvent::RemoveLogSink { name },
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct CreateWindowEventBuilder {
+    /// The configuration of the new window
+    window_config: WindowConfig,
+}
+impl CreateWindowEventBuilder {
+    pub fn title(mut self, title: &str) -> Self {
+        self.window_config.title = String::from(title);
+        self
+    }
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.window_config.position = Vector2f32::new(x, y);
+        self
+    }
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.window_config.width = width;
+        self.window_config.height = height;
+        self
+    }
+    pub fn border_width(mut self, border_width: u16) -> Self {
+        self.window_config.border_width = border_width;
+        self
+    }
+    /// Also returns the id of the new window, given with each of its events
+    /// See [crate::Game::on_window_event]
+    pub fn build(self) -> Result<(UserEventWrapper, u8), ErrorType> {
+        let Ok(window_id) =
+            NEXT_WINDOW_ID
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |id| id.checked_add(1))
+        else {
+            log_error!("Can't build a `CreateWindow' event, all the window ids were used");
+            return Err(ErrorType::InvalidIndex);
+        };
+        Ok((
+            UserEventWrapper {
+                event: UserEvent::CreateWindow {
+                    window_id,
+                    window_config: self.window_config,
+                },
+            },
+            window_id,
+        ))
+    }
+}
+
+#[derive(Default)]
+pub struct DestroyWindowEventBuilder {
+    /// The id of the window to close
+    window_id: Option<u8>,
+}
+impl DestroyWindowEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = Some(window_id);
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        let Some(window_id) = self.window_id else {
+            log_error!("Can't build a `DestroyWindow' event without a window id");
+            return Err(ErrorType::DoesNotExist);
+        };
+        if window_id == MAIN_WINDOW_ID {
+            log_error!(
+                "Can't build a `DestroyWindow' event for the main window, quit the app instead"
+            );
+            return Err(ErrorType::BadRequest);
+        }
+        Ok(UserEventWrapper {
+            event: UserEvent::DestroyWindow { window_id },
+        })
+    }
+}
+
+/// Checks that a window position and size lie on the monitor
+fn check_window_geometry(
+    event_name: &str,
+    position: Option<(f32, f32)>,
+    size: Option<(f32, f32)>,
+) -> Result<(), ErrorType> {
+    if let Some((x, y)) = position
+        && !((0. ..=1.).contains(&x) && (0. ..=1.).contains(&y))
+    {
+        log_error!(
+            "Can't build a `{}' event with the position [{:?}, {:?}] outside of [0., 1.]",
+            event_name,
+            x,
+            y
+        );
+        return Err(ErrorType::WrongArgument(String::from(
+            "The window's position must be between 0. and 1.",
+        )));
+    }
+    if let Some((width, height)) = size
+        && !(width > 0. && width <= 1. && height > 0. && height <= 1.)
+    {
+        log_error!(
+            "Can't build a `{}' event with the size {:?}x{:?} outside of ]0., 1.]",
+            event_name,
+            width,
+            height
+        );
+        return Err(ErrorType::WrongArgument(String::from(
+            "The window's width and height must be in ]0., 1.]",
+        )));
+    }
+    Ok(())
+}
+
+/// Targets the main window unless another window id is given
+#[derive(Default)]
+pub struct SetWindowDisplayModeEventBuilder {
+    /// The id of the window
+    window_id: u8,
+    /// The new display mode
+    display_mode: Option<DisplayMode>,
+}
+impl SetWindowDisplayModeEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = window_id;
+        self
+    }
+    pub fn display_mode(mut self, display_mode: DisplayMode) -> Self {
+        self.display_mode = Some(display_mode);
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        let Some(display_mode) = self.display_mode else {
+            log_error!("Can't build a `SetWindowDisplayMode' event without a display mode");
+            return Err(ErrorType::DoesNotExist);
+        };
+        if let DisplayMode::Floating(Some((x, y, width, height))) = display_mode {
+            check_window_geometry("SetWindowDisplayMode", Some((x, y)), Some((width, height)))?;
+        }
+        Ok(UserEventWrapper {
+            event: UserEvent::SetWindowDisplayMode {
+                window_id: self.window_id,
+                display_mode,
+            },
+        })
+    }
+}
+
+/// Targets the main window unless another window id is given
+#[derive(Default)]
+pub struct SetWindowTitleEventBuilder {
+    /// The id of the window
+    window_id: u8,
+    /// The new title
+    title: Option<String>,
+}
+impl SetWindowTitleEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = window_id;
+        self
+    }
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(String::from(title));
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        let Some(title) = self.title else {
+            log_error!("Can't build a `SetWindowTitle' event without a title");
+            return Err(ErrorType::DoesNotExist);
+        };
+        Ok(UserEventWrapper {
+            event: UserEvent::SetWindowTitle {
+                window_id: self.window_id,
+                title,
+            },
+        })
+    }
+}
+
+/// Targets the main window unless another window id is given
+/// The new size is given back to [crate::Game::on_resize] once the window is resized
+#[derive(Default)]
+pub struct ResizeWindowEventBuilder {
+    /// The id of the window
+    window_id: u8,
+    /// The new width and height
+    size: Option<(f32, f32)>,
+}
+impl ResizeWindowEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = window_id;
+        self
+    }
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        if self.size.is_none() {
+            log_error!("Can't build a `ResizeWindow' event without a size");
+            return Err(ErrorType::DoesNotExist);
+        }
+        check_window_geometry("ResizeWindow", None, self.size)?;
+        Ok(UserEventWrapper {
+            event: UserEvent::SetWindowGeometry {
+                window_id: self.window_id,
+                position: None,
+                size: self.size,
+            },
+        })
+    }
+}
+
+/// Targets the main window unless another window id is given
+#[derive(Default)]
+pub struct MoveWindowEventBuilder {
+    /// The id of the window
+    window_id: u8,
+    /// The new position
+    position: Option<(f32, f32)>,
+}
+impl MoveWindowEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = window_id;
+        self
+    }
+    pub fn position(mut self, x: f32, y: f32) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        let Some((x, y)) = self.position else {
+            log_error!("Can't build a `MoveWindow' event without a position");
+            return Err(ErrorType::DoesNotExist);
+        };
+        check_window_geometry("MoveWindow", self.position, None)?;
+        Ok(UserEventWrapper {
+            event: UserEvent::SetWindowGeometry {
+                window_id: self.window_id,
+                position: Some(Vector2f32::new(x, y)),
+                size: None,
+            },
+        })
+    }
+}
+
+/// Targets the main window unless another window id is given
+#[derive(Default)]
+pub struct SetWindowDecorationsEventBuilder {
+    /// The id of the window
+    window_id: u8,
+    /// Whether the window manager should decorate the window
+    has_decorations: Option<bool>,
+    /// The new border width in pixels
+    border_width: Option<u16>,
+}
+impl SetWindowDecorationsEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = window_id;
+        self
+    }
+    pub fn decorations(mut self, has_decorations: bool) -> Self {
+        self.has_decorations = Some(has_decorations);
+        self
+    }
+    pub fn border_width(mut self, border_width: u16) -> Self {
+        self.border_width = Some(border_width);
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        if self.has_decorations.is_none() && self.border_width.is_none() {
+            log_error!(
+                "Can't build a `SetWindowDecorations' event without decorations nor border width"
+            );
+            return Err(ErrorType::DoesNotExist);
+        }
+        Ok(UserEventWrapper {
+            event: UserEvent::SetWindowDecorations {
+                window_id: self.window_id,
+                has_decorations: self.has_decorations,
+                border_width: self.border_width,
+            },
+        })
+    }
+}
+
+/// Targets the main window unless another window id is given
+#[derive(Default)]
+pub struct SetCursorVisibilityEventBuilder {
+    /// The id of the window
+    window_id: u8,
+    /// Whether the cursor should be visible
+    is_visible: Option<bool>,
+}
+impl SetCursorVisibilityEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = window_id;
+        self
+    }
+    pub fn visible(mut self, is_visible: bool) -> Self {
+        self.is_visible = Some(is_visible);
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        let Some(is_visible) = self.is_visible else {
+            log_error!("Can't build a `SetCursorVisibility' event without a visibility");
+            return Err(ErrorType::DoesNotExist);
+        };
+        Ok(UserEventWrapper {
+            event: UserEvent::SetCursorVisibility {
+                window_id: self.window_id,
+                is_visible,
+            },
+        })
+    }
+}
+
+/// Targets the main window unless another window id is given
+/// The relative mode sends the mouse motions to [crate::Game::on_mouse_motion_raw]
+#[derive(Default)]
+pub struct SetCursorGrabEventBuilder {
+    /// The id of the window
+    window_id: u8,
+    /// The new grab mode
+    grab_mode: Option<CursorGrabMode>,
+}
+impl SetCursorGrabEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = window_id;
+        self
+    }
+    pub fn grab_mode(mut self, grab_mode: CursorGrabMode) -> Self {
+        self.grab_mode = Some(grab_mode);
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        let Some(grab_mode) = self.grab_mode else {
+            log_error!("Can't build a `SetCursorGrab' event without a grab mode");
+            return Err(ErrorType::DoesNotExist);
+        };
+        Ok(UserEventWrapper {
+            event: UserEvent::SetCursorGrab {
+                window_id: self.window_id,
+                grab_mode,
+            },
+        })
+    }
+}
+
+/// Targets the main window unless another window id is given
+/// Without an image, the default cursor is restored
+#[derive(Default)]
+pub struct SetCursorImageEventBuilder {
+    /// The id of the window
+    window_id: u8,
+    /// The new image
+    image: Option<CursorImage>,
+}
+impl SetCursorImageEventBuilder {
+    pub fn window_id(mut self, window_id: u8) -> Self {
+        self.window_id = window_id;
+        self
+    }
+    pub fn image(mut self, image: CursorImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+    pub fn build(self) -> Result<UserEventWrapper, ErrorType> {
+        if let Some(image) = &self.image
+            && let Err(err) = image.check()
+        {
+            log_error!(
+                "Can't build a `SetCursorImage' event with an invalid image: {:?}",
+                err
+            );
+            return Err(err);
+        }
+        Ok(UserEventWrapper {
+            event: UserEvent::SetCursorImage {
+                window_id: self.window_id,
+                image: self.image,
+            },
+        })
+    }
+}
@@ -4,18 +4,40 @@ use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 use std::collections::VecDeque;
 
 use crate::{
-    core_layer::application_system::application::ApplicationSystem, platform_layer::event::Event,
+    core_layer::application_system::application::ApplicationSystem,
+    platform_layer::{event::Event, window::MAIN_WINDOW_ID},
 };
 
 impl ApplicationSystem<'_> {
     /// Event handling
+    /// The events of the secondary windows are all given to the same callback
     pub(crate) fn handle_event(
         &mut self,
+        window_id: u8,
         event: Event,
     ) -> Result<VecDeque<super::user_events::UserEventWrapper>, ErrorType> {
+        if window_id != MAIN_WINDOW_ID {
+            return match self.user_game.on_window_event(window_id, event) {
+                Ok(events) => Ok(events),
+                Err(err) => {
+                    log_error!(
+                        "Failed to handle an event of the window `{:?}' in the application layer: {:?}",
+                        window_id,
+                        err
+                    );
+                    Err(ErrorType::Unknown)
+                }
+            };
+        }
+
         match event {
-            Event::KeyboardKeyPressed(keyboard_key) => {
-                match self.user_game.on_keyboard_key_pressed(keyboard_key) {
+            Event::KeyboardKeyPressed(keyboard_key, physical_key, scancode, modifiers) => {
+                match self.user_game.on_keyboard_key_pressed(
+                    keyboard_key,
+                    physical_key,
+                    scancode,
+                    modifiers,
+                ) {
                     Ok(events) => Ok(events),
                     Err(err) => {
                         log_error!(
@@ -26,8 +48,13 @@ impl ApplicationSystem<'_> {
                     }
                 }
             }
-            Event::KeyboardKeyReleased(keyboard_key) => {
-                match self.user_game.on_keyboard_key_released(keyboard_key) {
+            Event::KeyboardKeyReleased(keyboard_key, physical_key, scancode, modifiers) => {
+                match self.user_game.on_keyboard_key_released(
+                    keyboard_key,
+                    physical_key,
+                    scancode,
+                    modifiers,
+                ) {
                     Ok(events) => Ok(events),
                     Err(err) => {
                         log_error!(
@@ -38,6 +65,16 @@ impl ApplicationSystem<'_> {
                     }
                 }
             }
+            Event::TextInput(character) => match self.user_game.on_text_input(character) {
+                Ok(events) => Ok(events),
+                Err(err) => {
+                    log_error!(
+                        "Failed to handle a text input event in the application layer: {:?}",
+                        err
+                    );
+                    Err(ErrorType::Unknown)
+                }
+            },
             Event::MouseButtonPressed(mouse_button) => {
                 match self.user_game.on_mouse_button_pressed(mouse_button) {
                     Ok(events) => Ok(events),
@@ -72,6 +109,18 @@ impl ApplicationSystem<'_> {
                     Err(ErrorType::Unknown)
                 }
             },
+            Event::MouseScrolledHorizontally(delta) => {
+                match self.user_game.on_mouse_scrolled_horizontally(delta) {
+                    Ok(events) => Ok(events),
+                    Err(err) => {
+                        log_error!(
+                            "Failed to handle a mouse scrolled horizontally event in the application layer: {:?}",
+                            err
+                        );
+                        Err(ErrorType::Unknown)
+                    }
+                }
+            }
             Event::MouseMoved(new_x, new_y) => match self.user_game.on_mouse_moved(new_x, new_y) {
                 Ok(events) => Ok(events),
                 Err(err) => {
@@ -97,6 +146,16 @@ impl ApplicationSystem<'_> {
                     }
                 }
             }
+            Event::MouseMotionRaw(dx, dy) => match self.user_game.on_mouse_motion_raw(dx, dy) {
+                Ok(events) => Ok(events),
+                Err(err) => {
+                    log_error!(
+                        "Failed to handle a raw mouse motion event in the application layer: {:?}",
+                        err
+                    );
+                    Err(ErrorType::Unknown)
+                }
+            },
             Event::MouseEnteredWindow(x, y) => match self.user_game.on_mouse_entered_window(x, y) {
                 Ok(events) => Ok(events),
                 Err(err) => {
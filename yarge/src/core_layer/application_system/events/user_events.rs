@@ -1,15 +1,93 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use crate::PlatformLayer;
 use crate::core_layer::application_system::ecs::component::ComponentId;
 use crate::core_layer::application_system::ecs::entity::UserEntity;
 use crate::core_layer::application_system::ecs::resource::{ResourceTypeId, UserResourceId};
+use crate::core_layer::logger_system::logger::LoggerSystemInternal;
+use crate::error::Error;
+use crate::platform_layer::window::Window;
 
 /// An enum representing user fireable events
 pub(crate) enum UserEvent {
     /// To close the application
     QuitApp,
 
+    /// To open a secondary window
+    CreateWindow {
+        /// The id of the new window
+        window_id: u8,
+        /// The configuration of the new window
+        window_config: crate::config::WindowConfig,
+    },
+
+    /// To close a secondary window
+    DestroyWindow {
+        /// The id of the window to close
+        window_id: u8,
+    },
+
+    /// To change how a window is displayed
+    SetWindowDisplayMode {
+        /// The id of the window
+        window_id: u8,
+        /// The new display mode
+        display_mode: crate::DisplayMode,
+    },
+
+    /// To rename a window
+    SetWindowTitle {
+        /// The id of the window
+        window_id: u8,
+        /// The new title
+        title: String,
+    },
+
+    /// To move and resize a window
+    SetWindowGeometry {
+        /// The id of the window
+        window_id: u8,
+        /// The new position, or None to keep the current one
+        position: Option<crate::maths::Vector2f32>,
+        /// The new width and height, or None to keep the current ones
+        size: Option<(f32, f32)>,
+    },
+
+    /// To toggle the decorations and change the border of a window
+    SetWindowDecorations {
+        /// The id of the window
+        window_id: u8,
+        /// Whether the window manager should decorate the window, or None to keep it as is
+        has_decorations: Option<bool>,
+        /// The new border width in pixels, or None to keep the current one
+        border_width: Option<u16>,
+    },
+
+    /// To show or hide the cursor over a window
+    SetCursorVisibility {
+        /// The id of the window
+        window_id: u8,
+        /// Whether the cursor should be visible
+        is_visible: bool,
+    },
+
+    /// To change how the cursor is held by a window
+    SetCursorGrab {
+        /// The id of the window
+        window_id: u8,
+        /// The new grab mode
+        grab_mode: crate::mouse::CursorGrabMode,
+    },
+
+    /// To change the cursor image over a window
+    SetCursorImage {
+        /// The id of the window
+        window_id: u8,
+        /// The new image, or None for the default cursor
+        image: Option<crate::mouse::CursorImage>,
+    },
+
     /// To register a new resource
     RegisterCustomResource {
         user_id: UserResourceId,
@@ -102,6 +180,18 @@ pub(crate) enum UserEvent {
         /// The condition function to run or not this system
         condition: crate::core_layer::application_system::ecs::system::SystemCallbackConditionFunction,
     },
+
+    /// Adds a log sink, replacing the one with the same name if any
+    AddLogSink {
+        /// The sink to add
+        sink: crate::LogSink,
+    },
+
+    /// Removes a log sink
+    RemoveLogSink {
+        /// The name of the sink to remove
+        name: String,
+    },
 }
 
 /// A public Wrapper for UserEvent
@@ -114,32 +204,180 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
     /// Returns true if the application should quit
     pub(crate) fn handle_user_events(
         &mut self,
-        _platform_layer: &mut crate::PlatformLayerImpl,
-        _rendering_layer: &mut crate::RenderingLayerImpl<'_>,
-    ) -> Result<bool, ErrorType> {
+        platform_layer: &mut crate::PlatformLayerImpl,
+        rendering_layer: &mut crate::RenderingLayerImpl<'_>,
+    ) -> Result<bool, Error> {
         let mut should_quit = false;
         while let Some(event_builder) = self.user_events.pop_front() {
             match event_builder.event {
                 UserEvent::QuitApp => {
                     should_quit = true;
                 }
+                UserEvent::CreateWindow {
+                    window_id,
+                    window_config,
+                } => {
+                    if let Err(err) = self.create_window(
+                        window_id,
+                        window_config,
+                        platform_layer,
+                        rendering_layer,
+                    ) {
+                        return Err(err.context(
+                            "Failed to create a window when handling a `CreateWindow' event in the application",
+                        ));
+                    }
+                }
+                UserEvent::DestroyWindow { window_id } => {
+                    if !Self::is_window_opened(platform_layer, window_id, "DestroyWindow") {
+                        continue;
+                    }
+                    if let Err(err) =
+                        self.destroy_window(window_id, platform_layer, rendering_layer)
+                    {
+                        return Err(err.context(
+                            "Failed to destroy a window when handling a `DestroyWindow' event in the application",
+                        ));
+                    }
+                }
+                UserEvent::SetWindowDisplayMode {
+                    window_id,
+                    display_mode,
+                } => {
+                    if !Self::is_window_opened(platform_layer, window_id, "SetWindowDisplayMode") {
+                        continue;
+                    }
+                    if let Err(err) = platform_layer
+                        .get_window(window_id)
+                        .and_then(|window| window.set_display_mode(display_mode))
+                    {
+                        return Err(Error::from(err).context(
+                            "Failed to change the display mode of a window when handling a `SetWindowDisplayMode' event in the application",
+                        ));
+                    }
+                    log_debug!(
+                        "Display mode of the window `{:?}' set to `{:?}'",
+                        window_id,
+                        display_mode
+                    );
+                }
+                UserEvent::SetWindowTitle { window_id, title } => {
+                    if !Self::is_window_opened(platform_layer, window_id, "SetWindowTitle") {
+                        continue;
+                    }
+                    if let Err(err) = platform_layer
+                        .get_window(window_id)
+                        .and_then(|window| window.set_title(&title))
+                    {
+                        return Err(Error::from(err).context(
+                            "Failed to rename a window when handling a `SetWindowTitle' event in the application",
+                        ));
+                    }
+                }
+                UserEvent::SetWindowGeometry {
+                    window_id,
+                    position,
+                    size,
+                } => {
+                    if !Self::is_window_opened(platform_layer, window_id, "SetWindowGeometry") {
+                        continue;
+                    }
+                    if let Err(err) = platform_layer.get_window(window_id).and_then(|window| {
+                        let properties = window.get_properties();
+                        let position = position.unwrap_or(properties.position);
+                        let (width, height) = size.unwrap_or((properties.width, properties.height));
+                        window.set_geometry(position, width, height)
+                    }) {
+                        return Err(Error::from(err).context(
+                            "Failed to move and resize a window when handling a `SetWindowGeometry' event in the application",
+                        ));
+                    }
+                }
+                UserEvent::SetWindowDecorations {
+                    window_id,
+                    has_decorations,
+                    border_width,
+                } => {
+                    if !Self::is_window_opened(platform_layer, window_id, "SetWindowDecorations") {
+                        continue;
+                    }
+                    if let Err(err) = platform_layer.get_window(window_id).and_then(|window| {
+                        if let Some(has_decorations) = has_decorations {
+                            window.set_decorations(has_decorations)?;
+                        }
+                        if let Some(border_width) = border_width {
+                            window.set_border_width(border_width)?;
+                        }
+                        Ok(())
+                    }) {
+                        return Err(Error::from(err).context(
+                            "Failed to update the decorations of a window when handling a `SetWindowDecorations' event in the application",
+                        ));
+                    }
+                }
+                UserEvent::SetCursorVisibility {
+                    window_id,
+                    is_visible,
+                } => {
+                    if !Self::is_window_opened(platform_layer, window_id, "SetCursorVisibility") {
+                        continue;
+                    }
+                    if let Err(err) = platform_layer
+                        .get_window(window_id)
+                        .and_then(|window| window.set_cursor_visible(is_visible))
+                    {
+                        return Err(Error::from(err).context(
+                            "Failed to show or hide the cursor when handling a `SetCursorVisibility' event in the application",
+                        ));
+                    }
+                }
+                UserEvent::SetCursorGrab {
+                    window_id,
+                    grab_mode,
+                } => {
+                    if !Self::is_window_opened(platform_layer, window_id, "SetCursorGrab") {
+                        continue;
+                    }
+                    if let Err(err) = platform_layer
+                        .get_window(window_id)
+                        .and_then(|window| window.set_cursor_grab(grab_mode))
+                    {
+                        return Err(Error::from(err).context(
+                            "Failed to grab the cursor when handling a `SetCursorGrab' event in the application",
+                        ));
+                    }
+                    log_debug!(
+                        "Cursor grab of the window `{:?}' set to `{:?}'",
+                        window_id,
+                        grab_mode
+                    );
+                }
+                UserEvent::SetCursorImage { window_id, image } => {
+                    if !Self::is_window_opened(platform_layer, window_id, "SetCursorImage") {
+                        continue;
+                    }
+                    if let Err(err) = platform_layer
+                        .get_window(window_id)
+                        .and_then(|window| window.set_cursor_image(image.as_ref()))
+                    {
+                        return Err(Error::from(err).context(
+                            "Failed to change the cursor image when handling a `SetCursorImage' event in the application",
+                        ));
+                    }
+                }
                 UserEvent::RemoveEntity { user_entity } => {
                     if let Err(err) = self.ecs.remove_entity(&user_entity) {
-                        log_error!(
-                            "Failed to remove an entity when handling a `RemoveEntity' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to remove an entity when handling a `RemoveEntity' event in the application",
+                        ));
                     }
                     log_debug!("One entity removed");
                 }
                 UserEvent::RemoveEntities { user_entities } => {
                     if let Err(err) = self.ecs.remove_entities(&user_entities) {
-                        log_error!(
-                            "Failed to remove entities when handling a `RemoveEntities' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to remove entities when handling a `RemoveEntities' event in the application",
+                        ));
                     }
                     log_debug!("`{:?}' entities removed", user_entities.len());
                 }
@@ -148,11 +386,9 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
                     register_fct,
                 } => {
                     if let Err(err) = self.ecs.register_component(&component_id, &register_fct) {
-                        log_error!(
-                            "Failed to register a custom component when handling a `RegisterCustomComponent' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to register a custom component when handling a `RegisterCustomComponent' event in the application",
+                        ));
                     }
                     log_debug!("Custom component registered");
                 }
@@ -161,11 +397,9 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
                     remove_fct,
                 } => {
                     if let Err(err) = self.ecs.remove_component(&component_id, &remove_fct) {
-                        log_error!(
-                            "Failed to remove a custom component when handling a `RemoveCustomComponent' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to remove a custom component when handling a `RemoveCustomComponent' event in the application",
+                        ));
                     }
                     log_debug!("Custom component removed");
                 }
@@ -181,11 +415,9 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
                         value,
                         &add_to_entity_fct,
                     ) {
-                        log_error!(
-                            "Failed to add a component to an entity when handling a `AddComponentToEntity' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to add a component to an entity when handling a `AddComponentToEntity' event in the application",
+                        ));
                     }
                     log_debug!("Added component to entity `{:?}'", user_entity);
                 }
@@ -199,11 +431,9 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
                         &user_entity,
                         &remove_from_entity_fct,
                     ) {
-                        log_error!(
-                            "Failed to remove a component from an entity when handling a `RemoveComponentFromEntity' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to remove a component from an entity when handling a `RemoveComponentFromEntity' event in the application",
+                        ));
                     }
                     log_debug!("Removed component to entity `{:?}'", user_entity);
                 }
@@ -219,11 +449,9 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
                         value,
                         &update_for_entity_fct,
                     ) {
-                        log_error!(
-                            "Failed to update a component for an entity when handling a `UpdateComponentValueForEntity' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to update a component for an entity when handling a `UpdateComponentValueForEntity' event in the application",
+                        ));
                     }
                     log_debug!("Updated component for entity `{:?}'", user_entity);
                 }
@@ -236,11 +464,9 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
                         self.ecs
                             .register_system(self.user_game, system, schedule, condition)
                     {
-                        log_error!(
-                            "Failed to register a new system when handling a `RegisterSystem' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to register a new system when handling a `RegisterSystem' event in the application",
+                        ));
                     }
                     log_debug!("Added new system");
                 }
@@ -254,11 +480,9 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
                         &resource_type_id,
                         loading_function,
                     ) {
-                        log_error!(
-                            "Failed to register a new resource when handling a `RegisterCustomResource' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to register a new resource when handling a `RegisterCustomResource' event in the application",
+                        ));
                     }
                 }
                 UserEvent::StartLoadCustomResource {
@@ -269,11 +493,23 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
                         .ecs
                         .try_load_custom_resource(&user_id, &resource_type_id)
                     {
-                        log_error!(
-                            "Failed to start loading a new resource when handling a `StartLoadCustomResource' event in the application: {:?}",
-                            err
-                        );
-                        return Err(ErrorType::Unknown);
+                        return Err(Error::from(err).context(
+                            "Failed to start loading a new resource when handling a `StartLoadCustomResource' event in the application",
+                        ));
+                    }
+                }
+                UserEvent::AddLogSink { sink } => {
+                    if let Err(err) = LoggerSystemInternal::add_sink(sink) {
+                        return Err(Error::from(err).context(
+                            "Failed to add a log sink when handling a `AddLogSink' event in the application",
+                        ));
+                    }
+                }
+                UserEvent::RemoveLogSink { name } => {
+                    if let Err(err) = LoggerSystemInternal::remove_sink(&name) {
+                        return Err(Error::from(err).context(
+                            "Failed to remove a log sink when handling a `RemoveLogSink' event in the application",
+                        ));
                     }
                 }
             }
@@ -281,4 +517,23 @@ impl crate::core_layer::application_system::application::ApplicationSystem<'_> {
 
         Ok(should_quit)
     }
+
+    /// Checks that the window targeted by a user event is opened
+    /// A window can be destroyed before all its events are handled,
+    /// so the events of the unknown windows are ignored with a warning
+    fn is_window_opened(
+        platform_layer: &crate::PlatformLayerImpl,
+        window_id: u8,
+        event_name: &str,
+    ) -> bool {
+        if platform_layer.get_window_ids().contains(&window_id) {
+            return true;
+        }
+        log_warn!(
+            "Ignoring the `{}' event of the window `{:?}' which doesn't exist",
+            event_name,
+            window_id
+        );
+        false
+    }
 }
@@ -4,8 +4,11 @@ use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 use std::collections::VecDeque;
 
 use crate::{
-    core_layer::application_system::events::user_events::UserEventWrapper, gamepad::GamepadButton,
-    keyboard::KeyboardKey, mouse::MouseButton,
+    PlatformEvent,
+    core_layer::application_system::events::user_events::UserEventWrapper,
+    gamepad::GamepadButton,
+    keyboard::{KeyboardKey, KeyboardModifiers},
+    mouse::MouseButton,
 };
 
 pub struct UnsafeGameCell {
@@ -41,9 +44,21 @@ pub trait Game: std::any::Any + 'static {
         Ok(VecDeque::new())
     }
 
+    /// Runs zero or more times per frame, at a fixed rate
+    /// The step is the fixed duration in seconds of an update
+    /// Default behavior: don't do anything
+    fn on_fixed_update(&mut self, _step: f64) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+        Ok(VecDeque::new())
+    }
+
     /// Runs each frame
+    /// The alpha in [0, 1[ tells how far the frame is between the last two fixed updates
     /// Default behavior: don't do anything
-    fn on_render(&self, _delta_time: f64) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+    fn on_render(
+        &self,
+        _delta_time: f64,
+        _alpha: f64,
+    ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
         Ok(VecDeque::new())
     }
 
@@ -64,23 +79,43 @@ pub trait Game: std::any::Any + 'static {
     }
 
     /// Runs when a keyboard key is pressed
+    /// The keyboard key follows the layout while the physical key is the key
+    /// at the same position on a US QWERTY layout, to bind keys by position
+    /// The scancode is the raw code of the key, for the keys without a physical key
     /// Default behavior: don't do anything
     fn on_keyboard_key_pressed(
         &mut self,
         _keyboard_key: KeyboardKey,
+        _physical_key: KeyboardKey,
+        _scancode: u32,
+        _modifiers: KeyboardModifiers,
     ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
         Ok(VecDeque::new())
     }
 
     /// Runs when a keyboard key is released
+    /// See [Game::on_keyboard_key_pressed]
     /// Default behavior: don't do anything
     fn on_keyboard_key_released(
         &mut self,
         _keyboard_key: KeyboardKey,
+        _physical_key: KeyboardKey,
+        _scancode: u32,
+        _modifiers: KeyboardModifiers,
     ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
         Ok(VecDeque::new())
     }
 
+    /// Runs when a key press produces a character, to type text
+    /// Unlike the keyboard keys, the character follows the layout and the dead keys
+    /// The dead keys and compose sequences follow the `LC_CTYPE' locale, which the engine only
+    /// sets from the environment when asked to, see [crate::config::ConfigBuilder::window_use_system_locale]
+    /// and [crate::PlatformEvent::TextInput]
+    /// Default behavior: don't do anything
+    fn on_text_input(&mut self, _character: char) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+        Ok(VecDeque::new())
+    }
+
     /// Runs when a mouse button is pressed
     /// Default behavior: don't do anything
     fn on_mouse_button_pressed(
@@ -89,6 +124,16 @@ pub trait Game: std::any::Any + 'static {
     ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
         Ok(VecDeque::new())
     }
+    /// Runs when the mouse moves while the cursor is in the relative mode
+    /// See [crate::mouse::CursorGrabMode::Relative]
+    /// Default behavior: don't do anything
+    fn on_mouse_motion_raw(
+        &mut self,
+        _dx: f32,
+        _dy: f32,
+    ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+        Ok(VecDeque::new())
+    }
     /// Runs when a mouse button is released
     /// Default behavior: don't do anything
     fn on_mouse_button_released(
@@ -102,6 +147,14 @@ pub trait Game: std::any::Any + 'static {
     fn on_mouse_scrolled(&mut self, _delta: f32) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
         Ok(VecDeque::new())
     }
+    /// Runs when the mouse wheel is tilted
+    /// Default behavior: don't do anything
+    fn on_mouse_scrolled_horizontally(
+        &mut self,
+        _delta: f32,
+    ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+        Ok(VecDeque::new())
+    }
     /// Runs when the mouse moves
     /// Default behavior: don't do anything
     fn on_mouse_moved(
@@ -184,6 +237,18 @@ pub trait Game: std::any::Any + 'static {
         Ok(VecDeque::new())
     }
 
+    /// Runs for every event of a secondary window
+    /// The window is destroyed after handling its `WindowClosed' event
+    /// See [crate::event_builder::CreateWindowEventBuilder]
+    /// Default behavior: don't do anything
+    fn on_window_event(
+        &mut self,
+        _window_id: u8,
+        _event: PlatformEvent,
+    ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+        Ok(VecDeque::new())
+    }
+
     /// Runs when a given resource is done loading
     /// Default behavior: don't do anything
     fn on_resource_loaded(
@@ -193,4 +258,24 @@ pub trait Game: std::any::Any + 'static {
     ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
         Ok(VecDeque::new())
     }
+
+    /// Runs at the end of each frame in which warnings were reported
+    /// The warnings are deduplicated, see [crate::error::report_warning]
+    /// Default behavior: don't do anything
+    fn on_warnings(
+        &mut self,
+        _warnings: &[crate::error::Warning],
+    ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+        Ok(VecDeque::new())
+    }
+
+    /// Runs when the configuration file is reloaded
+    /// The changes were already applied by the engine
+    /// Default behavior: don't do anything
+    fn on_config_reloaded(
+        &mut self,
+        _changes: &[crate::config::ConfigChange],
+    ) -> Result<VecDeque<UserEventWrapper>, ErrorType> {
+        Ok(VecDeque::new())
+    }
 }
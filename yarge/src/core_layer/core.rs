@@ -1,11 +1,18 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use crate::error::Error;
+
 use crate::core_layer::{
-    application_system::application::ApplicationSystem, logger_system::logger::LoggerSystem,
+    application_system::application::ApplicationSystem, frame_limiter::FrameLimiter,
+    logger_system::logger::LoggerSystem,
 };
 
-use crate::{PlatformLayer, PlatformLayerImpl, RenderingLayer, RenderingLayerImpl, config::Config};
+use crate::{
+    PlatformLayer, PlatformLayerImpl, RenderingLayer, RenderingLayerImpl,
+    config::{Config, ConfigChange},
+    platform_layer::window::{MAIN_WINDOW_ID, Window},
+};
 
 /// The core layer
 pub(crate) struct CoreLayer<'a> {
@@ -13,20 +20,17 @@ pub(crate) struct CoreLayer<'a> {
     pub(crate) rendering_layer: RenderingLayerImpl<'a>,
     pub(crate) logger_system: LoggerSystem,
     pub(crate) application_system: ApplicationSystem<'a>,
+    pub(crate) frame_limiter: FrameLimiter,
 }
 
 impl<'a> CoreLayer<'a> {
     /// Initializes the application
-    pub(crate) fn init(
-        user_game: &'a mut dyn crate::Game,
-        config: &Config,
-    ) -> Result<Self, ErrorType> {
+    pub(crate) fn init(user_game: &'a mut dyn crate::Game, config: &Config) -> Result<Self, Error> {
         // Inits the logger system
         let logger_system = match LoggerSystem::init(config) {
             Err(err) => {
-                // TODO: add logging messages
-                eprintln!("Failed to initialize the logger system: {:?}", err);
-                return Err(ErrorType::Unknown);
+                // The error is reported by the caller as the logger is not available
+                return Err(Error::from(err).context("Failed to initialize the logger system"));
             }
             Ok(logger_system) => {
                 log_info!("Logger system initialized");
@@ -41,8 +45,7 @@ impl<'a> CoreLayer<'a> {
                 platform_layer
             }
             Err(err) => {
-                log_error!("Failed to initialize the platform layer: {:?}", err);
-                return Err(ErrorType::Unknown);
+                return Err(Error::from(err).context("Failed to initialize the platform layer"));
             }
         };
 
@@ -53,8 +56,7 @@ impl<'a> CoreLayer<'a> {
                 rendering_layer
             }
             Err(err) => {
-                log_error!("Failed to initialize the rendering layer: {:?}", err);
-                return Err(ErrorType::Unknown);
+                return Err(Error::from(err).context("Failed to initialize the rendering layer"));
             }
         };
 
@@ -66,8 +68,7 @@ impl<'a> CoreLayer<'a> {
             &mut rendering_layer,
         ) {
             Err(err) => {
-                log_error!("Failed to initialize the application system: {:?}", err);
-                return Err(ErrorType::Unknown);
+                return Err(err.context("Failed to initialize the application system"));
             }
             Ok(application_system) => {
                 log_info!("Application system initialized");
@@ -75,39 +76,106 @@ impl<'a> CoreLayer<'a> {
             }
         };
 
+        let frame_limiter = FrameLimiter::init(&config.frame_limiter_config);
+
         Ok(CoreLayer {
             platform_layer,
             rendering_layer,
             logger_system,
             application_system,
+            frame_limiter,
         })
     }
 
+    /// Applies the live changes of a reloaded configuration
+    /// Returns true if the application should quit
+    #[cfg_attr(not(vulkan_renderer), allow(unused_variables))]
+    pub(crate) fn reload_config(
+        &mut self,
+        config: &Config,
+        changes: &[ConfigChange],
+    ) -> Result<bool, Error> {
+        for change in changes {
+            let result = match change {
+                ConfigChange::LogLevel(min_level) => {
+                    self.logger_system.update_min_level(*min_level)
+                }
+                ConfigChange::LogTarget(target) => self.logger_system.update_target(target.clone()),
+                ConfigChange::LogFilters(filters) => {
+                    self.logger_system.update_filters(filters.clone())
+                }
+                ConfigChange::LogSinks(sinks) => self.logger_system.update_sinks(sinks.clone()),
+                ConfigChange::WindowTitle(title) => self
+                    .platform_layer
+                    .get_window(MAIN_WINDOW_ID)
+                    .and_then(|window| window.set_title(title)),
+                ConfigChange::WindowGeometry {
+                    position,
+                    width,
+                    height,
+                } => self
+                    .platform_layer
+                    .get_window(MAIN_WINDOW_ID)
+                    .and_then(|window| window.set_geometry(*position, *width, *height)),
+                #[cfg(vulkan_renderer)]
+                ConfigChange::PresentModes(_) => self
+                    .rendering_layer
+                    .update_present_mode(config, &mut self.platform_layer),
+                ConfigChange::FrameLimits {
+                    target_fps,
+                    unfocused_fps,
+                    minimized_fps,
+                } => {
+                    self.frame_limiter
+                        .update_limits(*target_fps, *unfocused_fps, *minimized_fps);
+                    Ok(())
+                }
+            };
+            if let Err(err) = result {
+                return Err(Error::from(err).context(format!(
+                    "Failed to apply the `{:?}' configuration change",
+                    change
+                )));
+            }
+        }
+
+        // The windows created from now on use the reloaded configuration
+        self.application_system.config = config.clone();
+
+        // Notifies the user's game
+        match self.application_system.on_config_reloaded(
+            changes,
+            &mut self.platform_layer,
+            &mut self.rendering_layer,
+        ) {
+            Ok(should_quit) => Ok(should_quit),
+            Err(err) => {
+                Err(err.context("Failed to notify the application of the configuration reload"))
+            }
+        }
+    }
+
     /// Shuts down the application
-    pub(crate) fn shutdown(&mut self) -> Result<(), ErrorType> {
+    pub(crate) fn shutdown(&mut self) -> Result<(), Error> {
         // Shuts down the rendering layer
         if let Err(err) = self.rendering_layer.shutdown() {
-            log_error!("Failed to shutdown the rendering layer: {:?}", err);
-            return Err(ErrorType::Unknown);
+            return Err(Error::from(err).context("Failed to shutdown the rendering layer"));
         }
 
         // Shuts down the application system
         if let Err(err) = self.application_system.shutdown() {
-            log_error!("Failed to shutdown the application system: {:?}", err);
-            return Err(ErrorType::Unknown);
+            return Err(err.context("Failed to shutdown the application system"));
         }
 
         // Shuts down the platform layer
         if let Err(err) = self.platform_layer.shutdown() {
-            log_error!("Failed to shutdown the platform layer: {:?}", err);
-            return Err(ErrorType::Unknown);
+            return Err(Error::from(err).context("Failed to shutdown the platform layer"));
         }
 
         // Shuts down the logger system
         if let Err(err) = self.logger_system.shutdown() {
-            // TODO: add better logging messages
-            eprintln!("Failed to shutdown the logger system: {:?}", err);
-            return Err(ErrorType::Unknown);
+            // The error is reported by the caller as the logger is not available anymore
+            return Err(Error::from(err).context("Failed to shutdown the logger system"));
         }
 
         println!("Core layer shutted down");
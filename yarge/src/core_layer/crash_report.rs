@@ -0,0 +1,274 @@
+//! The crash reports written when the engine or the game panics
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::{
+    any::Any,
+    fmt::Write,
+    panic::PanicHookInfo,
+    path::{Path, PathBuf},
+    sync::{Mutex, Once, PoisonError, TryLockError, atomic::Ordering},
+};
+
+use crate::{
+    GLOBAL_LOGGER, LogLevel, LogRecord, LogSink, LogTarget, PlatformLayer, PlatformLayerImpl,
+    config::Config,
+    core_layer::logger_system::{logger::LoggerSystemInternal, memory, record::FRAME_INDEX},
+};
+
+/// The name of the memory sink added to keep the recent logs
+const CRASH_REPORT_SINK: &str = "crash_report";
+
+/// What the panic hook needs to write a report
+/// It is only set while the engine is running
+struct CrashContext {
+    /// Where to write the reports
+    directory: PathBuf,
+    /// The maximum number of recent log lines in a report
+    log_lines: usize,
+    /// The effective configuration as a TOML string
+    config_dump: String,
+}
+
+/// The context of the running engine, if any
+static CRASH_CONTEXT: once_cell::sync::Lazy<Mutex<Option<CrashContext>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(None));
+
+/// The panic hook is installed once for the whole process
+static INSTALL_HOOK: Once = Once::new();
+
+/// A report describing a panic
+pub(crate) struct CrashReport {
+    /// The panic message
+    message: String,
+    /// Where the panic happened
+    location: String,
+    /// The name of the thread that panicked, or its id if it is unnamed
+    thread: String,
+    /// The index of the frame during which the panic happened
+    frame: u64,
+    /// When the panic happened, in milliseconds since the Unix epoch
+    timestamp: u128,
+    /// The backtrace of the panic
+    backtrace: String,
+    /// The effective configuration as a TOML string
+    config_dump: String,
+    /// The most recent logs, from the oldest to the most recent
+    logs: Vec<LogRecord>,
+}
+
+impl CrashReport {
+    /// Installs the panic hook and starts writing crash reports
+    /// The previously installed hook is still called after writing a report
+    pub(crate) fn install(config: &Config) {
+        INSTALL_HOOK.call_once(|| {
+            let previous_hook = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                Self::on_panic(info);
+                previous_hook(info);
+            }));
+        });
+        Self::update(config);
+    }
+
+    /// Updates the configuration written in the reports
+    /// Called again when the configuration is reloaded
+    pub(crate) fn update(config: &Config) {
+        let crash_report_config = &config.crash_report_config;
+        let context = if crash_report_config.enabled {
+            // A missing dump should not prevent the report
+            let config_dump = config
+                .dump()
+                .unwrap_or_else(|err| format!("Failed to dump the configuration: {:?}", err));
+            Some(CrashContext {
+                directory: crash_report_config.directory.clone(),
+                log_lines: crash_report_config.log_lines,
+                config_dump,
+            })
+        } else {
+            None
+        };
+        *CRASH_CONTEXT.lock().unwrap_or_else(PoisonError::into_inner) = context;
+    }
+
+    /// Adds a memory sink to keep the recent logs, unless one is already configured
+    /// Must be called once the logger system is initialized and after each reload of the sinks
+    pub(crate) fn keep_recent_logs(config: &Config) -> Result<(), ErrorType> {
+        let crash_report_config = &config.crash_report_config;
+        if !crash_report_config.enabled || crash_report_config.log_lines == 0 {
+            return Ok(());
+        }
+        let logger_config = &config.logger_config;
+        let has_memory_target = std::iter::once(&logger_config.target)
+            .chain(logger_config.sinks.iter().map(|sink| &sink.target))
+            .any(|target| matches!(target, LogTarget::Memory { .. }));
+        if has_memory_target {
+            return Ok(());
+        }
+        if let Err(err) = LoggerSystemInternal::add_sink(LogSink::new(
+            CRASH_REPORT_SINK,
+            LogTarget::Memory {
+                capacity: crash_report_config.log_lines,
+            },
+            LogLevel::Debug,
+        )) {
+            log_error!(
+                "Failed to add the memory sink keeping the logs for the crash reports: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
+
+    /// Stops writing crash reports, the previous hook is still called
+    pub(crate) fn uninstall() {
+        *CRASH_CONTEXT.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Gets the message of a panic from its payload
+    pub(crate) fn get_panic_message(payload: &(dyn Any + Send)) -> String {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            String::from(*message)
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            String::from("Unknown panic payload")
+        }
+    }
+
+    /// The panic hook writing the report and flushing the logs
+    /// The logs are flushed even when the reports are disabled
+    /// The panicking thread may hold the logger, so the hook never waits for it:
+    /// the notices are written to stderr and the logs are only flushed if the logger is free
+    fn on_panic(info: &PanicHookInfo<'_>) {
+        let context = CRASH_CONTEXT.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(context) = context.as_ref() {
+            let report = Self::new(info, context);
+            match report.write(&context.directory) {
+                Ok(path) => eprintln!("The engine panicked, crash report written to {:?}", path),
+                Err(err) => eprintln!(
+                    "The engine panicked but the crash report could not be written to {:?}: {:?}",
+                    context.directory, err
+                ),
+            }
+        }
+        drop(context);
+
+        // A poisoned logger is not held, the log macros report it without blocking
+        let is_logger_free = !matches!(GLOBAL_LOGGER.try_read(), Err(TryLockError::WouldBlock));
+        if !is_logger_free {
+            eprintln!("The logger is in use by the panicking thread, the logs are not flushed");
+        } else if let Err(err) = PlatformLayerImpl::flush_log() {
+            eprintln!("Failed to flush the logs when panicking: {:?}", err);
+        }
+    }
+
+    /// Creates a report from the panic information
+    fn new(info: &PanicHookInfo<'_>, context: &CrashContext) -> Self {
+        // The platform clock can't be used as it logs its own failures
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis());
+        let location = match info.location() {
+            Some(location) => format!("{}:{}", location.file(), location.line()),
+            None => String::from("unknown location"),
+        };
+        let current_thread = std::thread::current();
+        let thread = match current_thread.name() {
+            Some(name) => String::from(name),
+            None => format!("{:?}", current_thread.id()),
+        };
+        // The records are in use if the panic happened while keeping one
+        let mut logs = memory::try_recent(LogLevel::Debug).unwrap_or_default();
+        let nb_skipped_logs = logs.len().saturating_sub(context.log_lines);
+        let logs = logs.split_off(nb_skipped_logs);
+
+        Self {
+            message: Self::get_panic_message(info.payload()),
+            location,
+            thread,
+            frame: FRAME_INDEX.load(Ordering::Relaxed),
+            timestamp,
+            backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+            config_dump: context.config_dump.clone(),
+            logs,
+        }
+    }
+
+    /// Formats the report as plain text
+    fn as_text(&self) -> String {
+        let mut text = String::new();
+        // Writing into a string can't fail
+        let _ = writeln!(text, "# Crash report");
+        let _ = writeln!(text, "Message: {}", self.message);
+        let _ = writeln!(text, "Location: {}", self.location);
+        let _ = writeln!(text, "Thread: {}", self.thread);
+        let _ = writeln!(text, "Frame: {}", self.frame);
+        let _ = writeln!(text, "Timestamp: {}", self.timestamp);
+        let _ = writeln!(text, "\n# Backtrace\n{}", self.backtrace);
+        let _ = writeln!(text, "\n# Configuration\n{}", self.config_dump);
+        let _ = writeln!(text, "# Recent logs");
+        for record in &self.logs {
+            let _ = writeln!(text, "{}", record.as_file_line());
+        }
+        text
+    }
+
+    /// Writes the report in a new file of the given directory
+    /// Returns the path of the written file
+    fn write(&self, directory: &Path) -> Result<PathBuf, ErrorType> {
+        let path = directory.join(format!("crash_{}.txt", self.timestamp));
+        PlatformLayerImpl::write_to_file(&path, self.as_text().as_bytes())?;
+        Ok(path)
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////  crash report tests  /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write() {
+        let directory =
+            std::env::temp_dir().join(format!("yarge_crash_report_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        let report = CrashReport {
+            message: String::from("index out of bounds"),
+            location: String::from("src/main.rs:12"),
+            thread: String::from("main"),
+            frame: 42,
+            timestamp: 1718035200000,
+            backtrace: String::from("disabled backtrace"),
+            config_dump: Config::default().dump().unwrap(),
+            logs: vec![LogRecord::new(
+                LogLevel::Warn,
+                String::from("last words"),
+                "src/main.rs",
+                10,
+            )],
+        };
+
+        let path = report.write(&directory).unwrap();
+        assert_eq!(path, directory.join("crash_1718035200000.txt"));
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("Message: index out of bounds"));
+        assert!(content.contains("Frame: 42"));
+        assert!(content.contains("[crash_report]"));
+        assert!(content.contains("last words"));
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn panic_message() {
+        let payload = std::panic::catch_unwind(|| panic!("formatted {}", 3)).unwrap_err();
+        assert_eq!(
+            CrashReport::get_panic_message(payload.as_ref()),
+            "formatted 3"
+        );
+    }
+}
@@ -1,7 +1,13 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
-use crate::{PlatformLayer, config::Config, core_layer::core::CoreLayer};
+use crate::{
+    PlatformLayer, PlatformLayerImpl,
+    config::{ARG_DUMP_CONFIG, Config, ConfigWatcher},
+    core_layer::{core::CoreLayer, crash_report::CrashReport},
+    error::{Error, ErrorContext},
+    platform_layer::{event::Event, window::MAIN_WINDOW_ID},
+};
 
 /// The entry point of the engine
 pub struct Entry;
@@ -9,23 +15,80 @@ pub struct Entry;
 impl Entry {
     /// The entry point of the engine
     /// Every program using the engine should work simply by calling this function
+    /// The configuration file values can be overriden by `YARGE_TABLE__KEY=value` environment variables
+    /// and by `--table.key=value` command line arguments
+    /// Running with `--dump-config` prints the effective configuration
+    /// The configuration file is watched while running, see [crate::config::ConfigChange]
     pub fn run(
         user_game: &mut dyn crate::Game,
         config_file: Option<&std::path::Path>,
-    ) -> Result<(), ErrorType> {
+    ) -> Result<(), Error> {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+
         // Reads the configuration file
-        let config = match Config::init(config_file) {
+        let config = match Config::init(config_file, &args) {
             Ok(config) => {
                 println!("Configuration initialized");
                 config
             }
             Err(err) => {
+                let err = Error::from(err).context("Failed to initialize the config");
                 // TODO: add better logging messages when the logging system is not available
-                eprintln!("Failed to initialize the config: {:?}", err);
-                return Err(ErrorType::Unknown);
+                eprintln!("{}", err);
+                return Err(err);
             }
         };
 
+        if args.iter().any(|arg| arg == ARG_DUMP_CONFIG) {
+            let dump = config
+                .dump()
+                .context("Failed to dump the effective configuration")?;
+            println!("Effective configuration:\n{}", dump);
+        }
+
+        // Watches the configuration file to reload it at runtime
+        let config_watcher = match config_file {
+            Some(file_path) => match ConfigWatcher::init(file_path, &args, &config) {
+                Ok(config_watcher) => Some(config_watcher),
+                Err(err) => {
+                    // TODO: add better logging messages when the logging system is not available
+                    eprintln!(
+                        "Failed to watch the config file, it won't be reloaded: {:?}",
+                        err
+                    );
+                    None
+                }
+            },
+            None => None,
+        };
+
+        Self::run_internal(user_game, config, config_watcher)
+    }
+
+    /// The entry point of the engine when the configuration is built from code
+    /// See [crate::config::ConfigBuilder]
+    pub fn run_with_config(user_game: &mut dyn crate::Game, config: Config) -> Result<(), Error> {
+        Self::run_internal(user_game, config, None)
+    }
+
+    /// Runs the engine, reloading the configuration when the watched file changes
+    /// A crash report is written if anything panics, see [crate::config::ConfigBuilder::crash_report]
+    fn run_internal(
+        user_game: &mut dyn crate::Game,
+        config: Config,
+        mut config_watcher: Option<ConfigWatcher>,
+    ) -> Result<(), Error> {
+        // Checks the configuration before initializing anything
+        if let Err(err) = config.validate() {
+            let err = Error::from(err).context("Failed to validate the configuration");
+            // TODO: add better logging messages when the logging system is not available
+            eprintln!("{}", err);
+            return Err(err);
+        }
+
+        // Writes a crash report if anything panics from now on
+        CrashReport::install(&config);
+
         // Inits the core layer
         let mut core_layer = match CoreLayer::init(user_game, &config) {
             Ok(core_layer) => {
@@ -33,61 +96,152 @@ impl Entry {
                 core_layer
             }
             Err(err) => {
+                CrashReport::uninstall();
+                let err = err.context("Failed to initialize the core layer");
                 // TODO: add better logging messages when the logging system is not available
-                eprintln!("Failed to initialize the core layer: {:?}", err);
-                return Err(ErrorType::Unknown);
+                eprintln!("{}", err);
+                return Err(err);
             }
         };
+        if let Err(err) = CrashReport::keep_recent_logs(&config) {
+            log_warn!(
+                "Failed to keep the recent logs, the crash reports won't contain them: {:?}",
+                err
+            );
+        }
 
-        let mut has_found_error = false;
         // Runs the application
+        // A panic is caught to still shut down the core layer
+        let found_error = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            Self::run_loop(&mut core_layer, config_watcher.as_mut())
+        })) {
+            Ok(found_error) => found_error,
+            Err(payload) => {
+                let message = CrashReport::get_panic_message(payload.as_ref());
+                let err = Error::new(ErrorType::Panic(message))
+                    .context("The application's loop panicked");
+                log_error!("{}", err);
+                Some(err)
+            }
+        };
+
+        // Shuts down the core layer
+        let shutdown_result = core_layer.shutdown();
+        CrashReport::uninstall();
+        if let Err(err) = shutdown_result {
+            let err = err.context("Failed to shutdown the core layer");
+            // TODO: add better logging messages when the logging system is not available
+            eprintln!("{}", err);
+            return Err(found_error.unwrap_or(err));
+        }
+
+        if let Some(err) = found_error {
+            eprintln!("Failed to run the entry successfully");
+            return Err(err);
+        }
+
+        // Fck VSCode weird integrated terminal
+        println!();
+
+        Ok(())
+    }
+
+    /// Runs the application until it quits
+    /// Returns the first error found, the loop stops on errors
+    fn run_loop(
+        core_layer: &mut CoreLayer<'_>,
+        mut config_watcher: Option<&mut ConfigWatcher>,
+    ) -> Option<Error> {
+        let mut found_error: Option<Error> = None;
         'infinite_loop: loop {
-            // Handle events
-            match core_layer.platform_layer.poll_event() {
-                Ok(event) => {
+            let frame_start = match PlatformLayerImpl::get_monotonic_time() {
+                Ok(time) => time,
+                Err(err) => {
+                    let err = Error::from(err).context("Failed to get the start time of a frame");
+                    log_error!("{}", err);
+                    return Some(err);
+                }
+            };
+
+            // Handle all the pending events without waiting for new ones
+            match core_layer.platform_layer.poll_events() {
+                Ok(events) => {
+                    core_layer.frame_limiter.handle_events(&events);
                     let should_quit = match core_layer.application_system.loop_iteration(
-                        event,
+                        &events,
                         &mut core_layer.platform_layer,
                         &mut core_layer.rendering_layer,
                     ) {
                         Err(err) => {
-                            log_error!(
-                                "Failed to run an iteration of the application's loop when handling an event: {:?}",
-                                err
+                            let err = err.context(
+                                "Failed to run an iteration of the application's loop when handling an event",
                             );
-                            has_found_error = true;
+                            log_error!("{}", err);
+                            found_error = Some(err);
                             true
                         }
                         Ok(should_quit) => should_quit,
                     };
-                    if should_quit || event == crate::platform_layer::event::Event::WindowClosed {
+                    if should_quit || events.contains(&(MAIN_WINDOW_ID, Event::WindowClosed)) {
+                        log_info!("The window is closing");
+                        break 'infinite_loop;
+                    }
+
+                    // Waits for the end of the frame to respect the frame rate cap
+                    match core_layer.frame_limiter.wait(frame_start) {
+                        Ok(frame_stats) => {
+                            core_layer.application_system.ecs.time.frame_stats = frame_stats;
+                        }
+                        Err(err) => {
+                            log_warn!("Failed to limit the frame rate: {:?}", err);
+                        }
+                    }
+
+                    // Reloads the configuration file if it changed
+                    let Some(watcher) = config_watcher.as_mut() else {
+                        continue 'infinite_loop;
+                    };
+                    let should_quit = match watcher.check() {
+                        Ok(Some((new_config, changes))) => {
+                            match core_layer.reload_config(&new_config, &changes) {
+                                Ok(should_quit) => {
+                                    CrashReport::update(&new_config);
+                                    if let Err(err) = CrashReport::keep_recent_logs(&new_config) {
+                                        log_warn!(
+                                            "Failed to keep the recent logs after reloading the configuration: {:?}",
+                                            err
+                                        );
+                                    }
+                                    should_quit
+                                }
+                                Err(err) => {
+                                    let err =
+                                        err.context("Failed to apply the reloaded configuration");
+                                    log_error!("{}", err);
+                                    found_error = Some(err);
+                                    true
+                                }
+                            }
+                        }
+                        Ok(None) => false,
+                        Err(err) => {
+                            log_warn!("Failed to check the configuration file: {:?}", err);
+                            false
+                        }
+                    };
+                    if should_quit {
                         log_info!("The window is closing");
                         break 'infinite_loop;
                     }
                 }
                 Err(err) => {
-                    // TODO: add logging messages
-                    log_error!("Failed to poll an event: {:?}", err);
-                    has_found_error = true;
+                    let err = Error::from(err).context("Failed to poll the events");
+                    log_error!("{}", err);
+                    let _ = found_error.get_or_insert(err);
+                    break 'infinite_loop;
                 }
             };
         }
-
-        // Shuts down the core layer
-        if let Err(err) = core_layer.shutdown() {
-            // TODO: add better logging messages when the logging system is not available
-            eprintln!("Failed to shutdown the core layer: {:?}", err);
-            return Err(ErrorType::Unknown);
-        }
-
-        if has_found_error {
-            eprintln!("Failed to run the entry successfully");
-            return Err(ErrorType::Unknown);
-        }
-
-        // Fck VSCode weird integrated terminal
-        println!();
-
-        Ok(())
+        found_error
     }
 }
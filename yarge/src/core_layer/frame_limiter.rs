@@ -0,0 +1,181 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use crate::{
+    FrameStats, PlatformLayer, PlatformLayerImpl, Time,
+    config::FrameLimiterConfig,
+    platform_layer::{event::Event, window::MAIN_WINDOW_ID},
+};
+
+/// The weight of the last frame in the smoothed frame time
+const AVERAGE_SMOOTHING: f64 = 0.1;
+
+/// Limits the frame rate by waiting at the end of each frame
+/// The cap depends on whether the window is focused or minimized
+pub(crate) struct FrameLimiter {
+    /// The limiter's configuration
+    config: FrameLimiterConfig,
+    /// Whether the window is focused
+    is_focused: bool,
+    /// Whether the window is minimized
+    is_minimized: bool,
+    /// The smoothed duration of the frames in seconds
+    average_frame_time: f64,
+}
+
+impl FrameLimiter {
+    /// Initializes the limiter, the window is considered focused
+    pub(crate) fn init(config: &FrameLimiterConfig) -> Self {
+        Self {
+            config: config.clone(),
+            is_focused: true,
+            is_minimized: false,
+            average_frame_time: 0.,
+        }
+    }
+
+    /// Updates the frame rate caps
+    pub(crate) fn update_limits(
+        &mut self,
+        target_fps: u32,
+        unfocused_fps: u32,
+        minimized_fps: u32,
+    ) {
+        self.config.target_fps = target_fps;
+        self.config.unfocused_fps = unfocused_fps;
+        self.config.minimized_fps = minimized_fps;
+    }
+
+    /// Tracks the focus and the visibility of the main window
+    pub(crate) fn handle_events(&mut self, events: &[(u8, Event)]) {
+        for (window_id, event) in events {
+            if *window_id != MAIN_WINDOW_ID {
+                continue;
+            }
+            match event {
+                Event::WindowFocused => self.is_focused = true,
+                Event::WindowUnfocused => self.is_focused = false,
+                Event::WindowMinimized => self.is_minimized = true,
+                Event::WindowRestored => self.is_minimized = false,
+                _ => {}
+            }
+        }
+    }
+
+    /// Gets the lowest of two caps, 0 meaning no limit
+    fn get_lowest_cap(first: u32, second: u32) -> u32 {
+        match (first, second) {
+            (0, cap) | (cap, 0) => cap,
+            (first, second) => first.min(second),
+        }
+    }
+
+    /// Gets the current frame rate cap, 0 meaning no limit
+    pub(crate) fn get_frame_cap(&self) -> u32 {
+        let mut frame_cap = self.config.target_fps;
+        if !self.is_focused || self.is_minimized {
+            frame_cap = Self::get_lowest_cap(frame_cap, self.config.unfocused_fps);
+        }
+        if self.is_minimized {
+            frame_cap = Self::get_lowest_cap(frame_cap, self.config.minimized_fps);
+        }
+        frame_cap
+    }
+
+    /// Waits until the end of the frame started at the given monotonic time in nanoseconds
+    /// Sleeps first then spins for the last `spin_time` microseconds
+    /// Returns the timing statistics of the frame
+    pub(crate) fn wait(&mut self, frame_start: u128) -> Result<FrameStats, ErrorType> {
+        let frame_cap = self.get_frame_cap();
+        let work_end = PlatformLayerImpl::get_monotonic_time()?;
+        let mut frame_end = work_end;
+
+        if frame_cap > 0 {
+            let deadline = frame_start + 1_000_000_000 / u128::from(frame_cap);
+            let spin_time = u128::from(self.config.spin_time) * 1_000;
+            while frame_end < deadline {
+                let remaining = deadline - frame_end;
+                if remaining > spin_time {
+                    // The remaining time fits in a u64 as it is less than a second
+                    std::thread::sleep(std::time::Duration::from_nanos(
+                        (remaining - spin_time) as u64,
+                    ));
+                } else {
+                    std::hint::spin_loop();
+                }
+                frame_end = PlatformLayerImpl::get_monotonic_time()?;
+            }
+        }
+
+        let frame_time = Time::to_seconds(frame_end.saturating_sub(frame_start));
+        self.average_frame_time = if self.average_frame_time == 0. {
+            frame_time
+        } else {
+            self.average_frame_time + (frame_time - self.average_frame_time) * AVERAGE_SMOOTHING
+        };
+        Ok(FrameStats {
+            work_time: Time::to_seconds(work_end.saturating_sub(frame_start)),
+            wait_time: Time::to_seconds(frame_end - work_end),
+            average_fps: if self.average_frame_time > 0. {
+                1. / self.average_frame_time
+            } else {
+                0.
+            },
+            frame_cap,
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////  frame limiter tests  ////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_cap() {
+        let mut limiter = FrameLimiter::init(&FrameLimiterConfig {
+            target_fps: 144,
+            unfocused_fps: 30,
+            minimized_fps: 0,
+            spin_time: 0,
+        });
+        assert_eq!(limiter.get_frame_cap(), 144);
+        limiter.handle_events(&[(MAIN_WINDOW_ID, Event::WindowUnfocused)]);
+        assert_eq!(limiter.get_frame_cap(), 30);
+        // The unfocused cap still applies without a minimized one
+        limiter.handle_events(&[
+            (MAIN_WINDOW_ID, Event::WindowFocused),
+            (MAIN_WINDOW_ID, Event::WindowMinimized),
+        ]);
+        assert_eq!(limiter.get_frame_cap(), 30);
+        limiter.handle_events(&[(MAIN_WINDOW_ID, Event::WindowRestored)]);
+        assert_eq!(limiter.get_frame_cap(), 144);
+
+        // A higher unfocused cap doesn't raise the target one
+        limiter.update_limits(20, 30, 10);
+        limiter.handle_events(&[(MAIN_WINDOW_ID, Event::WindowUnfocused)]);
+        assert_eq!(limiter.get_frame_cap(), 20);
+        limiter.update_limits(0, 0, 0);
+        assert_eq!(limiter.get_frame_cap(), 0);
+    }
+
+    #[test]
+    fn wait() {
+        let mut limiter = FrameLimiter::init(&FrameLimiterConfig {
+            target_fps: 100,
+            ..Default::default()
+        });
+        let frame_start = PlatformLayerImpl::get_monotonic_time().unwrap();
+        let stats = limiter.wait(frame_start).unwrap();
+        let frame_end = PlatformLayerImpl::get_monotonic_time().unwrap();
+
+        assert!(frame_end - frame_start >= 10_000_000);
+        // The times in seconds are rounded, they can be just below the frame period
+        let epsilon = 1e-9;
+        assert!(stats.work_time + stats.wait_time >= 0.01 - epsilon);
+        assert_eq!(stats.frame_cap, 100);
+        assert!(stats.average_fps > 0. && stats.average_fps <= 100. + epsilon);
+    }
+}
@@ -121,6 +121,10 @@ pub enum Modifier {
     AltLeft,
     /// The right 'AltGr' key
     AltRight,
+    /// The left 'Super' key, often showing the system logo
+    SuperLeft,
+    /// The right 'Super' key, often showing the system logo
+    SuperRight,
 }
 
 /// Other special keyboard keys
@@ -138,6 +142,175 @@ pub enum Special {
     Delete,
     /// The 'Spacebar' key
     Spacebar,
+    /// The 'Caps Lock' key
+    CapsLock,
+    /// The 'Num Lock' key
+    NumLock,
+    /// The 'Scroll Lock' key
+    ScrollLock,
+    /// The 'Print Screen' key
+    PrintScreen,
+    /// The 'Pause' key
+    Pause,
+    /// The 'Menu' key, opening the context menu
+    Menu,
+}
+
+/// The function keyboard keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Function {
+    /// The 'F1' key
+    F1,
+    /// The 'F2' key
+    F2,
+    /// The 'F3' key
+    F3,
+    /// The 'F4' key
+    F4,
+    /// The 'F5' key
+    F5,
+    /// The 'F6' key
+    F6,
+    /// The 'F7' key
+    F7,
+    /// The 'F8' key
+    F8,
+    /// The 'F9' key
+    F9,
+    /// The 'F10' key
+    F10,
+    /// The 'F11' key
+    F11,
+    /// The 'F12' key
+    F12,
+    /// The 'F13' key
+    F13,
+    /// The 'F14' key
+    F14,
+    /// The 'F15' key
+    F15,
+    /// The 'F16' key
+    F16,
+    /// The 'F17' key
+    F17,
+    /// The 'F18' key
+    F18,
+    /// The 'F19' key
+    F19,
+    /// The 'F20' key
+    F20,
+    /// The 'F21' key
+    F21,
+    /// The 'F22' key
+    F22,
+    /// The 'F23' key
+    F23,
+    /// The 'F24' key
+    F24,
+}
+
+/// The numeric keypad keyboard keys
+/// The digits are given whatever the 'Num Lock' state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Numpad {
+    /// The keypad '0' key
+    Zero,
+    /// The keypad '1' key
+    One,
+    /// The keypad '2' key
+    Two,
+    /// The keypad '3' key
+    Three,
+    /// The keypad '4' key
+    Four,
+    /// The keypad '5' key
+    Five,
+    /// The keypad '6' key
+    Six,
+    /// The keypad '7' key
+    Seven,
+    /// The keypad '8' key
+    Eight,
+    /// The keypad '9' key
+    Nine,
+    /// The keypad '.' key
+    Decimal,
+    /// The keypad '+' key
+    Add,
+    /// The keypad '-' key
+    Subtract,
+    /// The keypad '*' key
+    Multiply,
+    /// The keypad '/' key
+    Divide,
+    /// The keypad 'Enter' key
+    Enter,
+    /// The keypad '=' key
+    Equal,
+}
+
+/// The punctuation keyboard keys
+/// They are named after the characters of a US QWERTY layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Punctuation {
+    /// The '-' key
+    Minus,
+    /// The '=' key
+    Equal,
+    /// The '[' key
+    BracketLeft,
+    /// The ']' key
+    BracketRight,
+    /// The '\\' key
+    Backslash,
+    /// The ';' key
+    Semicolon,
+    /// The ''' key
+    Apostrophe,
+    /// The '`' key
+    Grave,
+    /// The ',' key
+    Comma,
+    /// The '.' key
+    Period,
+    /// The '/' key
+    Slash,
+    /// The '<' key, next to the left 'Shift' key on ISO keyboards
+    IntlBackslash,
+}
+
+/// The navigation keyboard keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Navigation {
+    /// The 'Home' key
+    Home,
+    /// The 'End' key
+    End,
+    /// The 'Page Up' key
+    PageUp,
+    /// The 'Page Down' key
+    PageDown,
+    /// The 'Insert' key
+    Insert,
+}
+
+/// The media keyboard keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Media {
+    /// The 'Play/Pause' key
+    PlayPause,
+    /// The 'Stop' key
+    Stop,
+    /// The 'Next Track' key
+    Next,
+    /// The 'Previous Track' key
+    Previous,
+    /// The 'Mute' key
+    Mute,
+    /// The 'Volume Up' key
+    VolumeUp,
+    /// The 'Volume Down' key
+    VolumeDown,
 }
 
 /// All available keys
@@ -157,6 +330,38 @@ pub enum KeyboardKey {
     /// A special key
     /// See 'Special'
     Special(Special),
+    /// A function key
+    /// See 'Function'
+    Function(Function),
+    /// A numeric keypad key
+    /// See 'Numpad'
+    Numpad(Numpad),
+    /// A punctuation key
+    /// See 'Punctuation'
+    Punctuation(Punctuation),
+    /// A navigation key
+    /// See 'Navigation'
+    Navigation(Navigation),
+    /// A media key
+    /// See 'Media'
+    Media(Media),
+}
+
+/// The state of the modifiers when a keyboard event happens
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyboardModifiers {
+    /// Whether a 'Shift' key is held
+    pub is_shift_down: bool,
+    /// Whether a 'Ctrl' key is held
+    pub is_control_down: bool,
+    /// Whether an 'Alt' key is held
+    pub is_alt_down: bool,
+    /// Whether a 'Super' key is held
+    pub is_super_down: bool,
+    /// Whether the 'Caps Lock' is on
+    pub is_caps_lock_on: bool,
+    /// Whether the 'Num Lock' is on
+    pub is_num_lock_on: bool,
 }
 
 /// A structure representing a keyboard
@@ -238,6 +443,8 @@ impl Default for Keyboard {
             Modifier::ControlRight,
             Modifier::AltLeft,
             Modifier::AltRight,
+            Modifier::SuperLeft,
+            Modifier::SuperRight,
         ] {
             if keys
                 .insert(KeyboardKey::Modifier(key), KeyboardKeyState::Released)
@@ -255,6 +462,12 @@ impl Default for Keyboard {
             Special::Tab,
             Special::Delete,
             Special::Spacebar,
+            Special::CapsLock,
+            Special::NumLock,
+            Special::ScrollLock,
+            Special::PrintScreen,
+            Special::Pause,
+            Special::Menu,
         ] {
             if keys
                 .insert(KeyboardKey::Special(key), KeyboardKeyState::Released)
@@ -264,9 +477,162 @@ impl Default for Keyboard {
             }
         }
 
+        // Insert all function keys
+        for key in [
+            Function::F1,
+            Function::F2,
+            Function::F3,
+            Function::F4,
+            Function::F5,
+            Function::F6,
+            Function::F7,
+            Function::F8,
+            Function::F9,
+            Function::F10,
+            Function::F11,
+            Function::F12,
+            Function::F13,
+            Function::F14,
+            Function::F15,
+            Function::F16,
+            Function::F17,
+            Function::F18,
+            Function::F19,
+            Function::F20,
+            Function::F21,
+            Function::F22,
+            Function::F23,
+            Function::F24,
+        ] {
+            if keys
+                .insert(KeyboardKey::Function(key), KeyboardKeyState::Released)
+                .is_some()
+            {
+                log_warn!("Adding the `{:?}' key twice when building a keyboard", key);
+            }
+        }
+
+        // Insert all numeric keypad keys
+        for key in [
+            Numpad::Zero,
+            Numpad::One,
+            Numpad::Two,
+            Numpad::Three,
+            Numpad::Four,
+            Numpad::Five,
+            Numpad::Six,
+            Numpad::Seven,
+            Numpad::Eight,
+            Numpad::Nine,
+            Numpad::Decimal,
+            Numpad::Add,
+            Numpad::Subtract,
+            Numpad::Multiply,
+            Numpad::Divide,
+            Numpad::Enter,
+            Numpad::Equal,
+        ] {
+            if keys
+                .insert(KeyboardKey::Numpad(key), KeyboardKeyState::Released)
+                .is_some()
+            {
+                log_warn!("Adding the `{:?}' key twice when building a keyboard", key);
+            }
+        }
+
+        // Insert all punctuation keys
+        for key in [
+            Punctuation::Minus,
+            Punctuation::Equal,
+            Punctuation::BracketLeft,
+            Punctuation::BracketRight,
+            Punctuation::Backslash,
+            Punctuation::Semicolon,
+            Punctuation::Apostrophe,
+            Punctuation::Grave,
+            Punctuation::Comma,
+            Punctuation::Period,
+            Punctuation::Slash,
+            Punctuation::IntlBackslash,
+        ] {
+            if keys
+                .insert(KeyboardKey::Punctuation(key), KeyboardKeyState::Released)
+                .is_some()
+            {
+                log_warn!("Adding the `{:?}' key twice when building a keyboard", key);
+            }
+        }
+
+        // Insert all navigation keys
+        for key in [
+            Navigation::Home,
+            Navigation::End,
+            Navigation::PageUp,
+            Navigation::PageDown,
+            Navigation::Insert,
+        ] {
+            if keys
+                .insert(KeyboardKey::Navigation(key), KeyboardKeyState::Released)
+                .is_some()
+            {
+                log_warn!("Adding the `{:?}' key twice when building a keyboard", key);
+            }
+        }
+
+        // Insert all media keys
+        for key in [
+            Media::PlayPause,
+            Media::Stop,
+            Media::Next,
+            Media::Previous,
+            Media::Mute,
+            Media::VolumeUp,
+            Media::VolumeDown,
+        ] {
+            if keys
+                .insert(KeyboardKey::Media(key), KeyboardKeyState::Released)
+                .is_some()
+            {
+                log_warn!("Adding the `{:?}' key twice when building a keyboard", key);
+            }
+        }
+
         Self {
             current_key_states: keys.clone(),
             last_key_states: keys,
         }
     }
 }
+
+//////////////////////////////////////////////////////////
+///////////////    keyboard tests    /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_keyboard() {
+        let keyboard = Keyboard::default();
+        // 36 alphanumeric, 4 arrow, 8 modifier, 12 special, 24 function,
+        // 17 keypad, 12 punctuation, 5 navigation and 7 media keys
+        assert_eq!(keyboard.current_key_states.len(), 125);
+        assert!(
+            keyboard
+                .current_key_states
+                .values()
+                .all(|state| *state == KeyboardKeyState::Released)
+        );
+        assert_eq!(
+            keyboard
+                .last_key_states
+                .get(&KeyboardKey::Function(Function::F24)),
+            Some(&KeyboardKeyState::Released)
+        );
+        assert!(
+            !keyboard
+                .current_key_states
+                .contains_key(&KeyboardKey::Unrecognized)
+        );
+    }
+}
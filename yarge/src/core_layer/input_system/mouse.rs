@@ -25,3 +25,91 @@ pub enum MouseButton {
     /// This button is often the mouse wheel
     Middle,
 }
+
+/// How the cursor is held by a window
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CursorGrabMode {
+    /// The cursor moves freely
+    #[default]
+    None,
+    /// The cursor can't leave the window
+    Confined,
+    /// The cursor is confined and hidden, only the relative mouse motions are sent
+    /// This is the mode of first person camera controls
+    /// See [crate::PlatformEvent::MouseMotionRaw]
+    Relative,
+}
+
+/// A custom cursor image
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorImage {
+    /// The image's width in pixels
+    pub width: u16,
+    /// The image's height in pixels
+    pub height: u16,
+    /// The pixel pointing at the cursor's position, from the top left of the image
+    pub hotspot: (u16, u16),
+    /// The RGBA pixels with 8 bits per channel, row by row from the top left of the image
+    pub pixels: Vec<u8>,
+}
+
+impl CursorImage {
+    /// Checks that the pixels and the hotspot match the image's size
+    pub fn check(&self) -> Result<(), ErrorType> {
+        if self.width == 0 || self.height == 0 {
+            log_error!("Can't use an empty cursor image");
+            return Err(ErrorType::WrongArgument(String::from(
+                "The cursor image's size must not be 0",
+            )));
+        }
+        let expected_len = (self.width as usize) * (self.height as usize) * 4;
+        if self.pixels.len() != expected_len {
+            log_error!(
+                "The {:?}x{:?} cursor image has {:?} bytes instead of {:?}",
+                self.width,
+                self.height,
+                self.pixels.len(),
+                expected_len
+            );
+            return Err(ErrorType::WrongArgument(String::from(
+                "The cursor image must have 4 bytes per pixel",
+            )));
+        }
+        if self.hotspot.0 >= self.width || self.hotspot.1 >= self.height {
+            log_error!(
+                "The cursor hotspot {:?} is outside of the {:?}x{:?} image",
+                self.hotspot,
+                self.width,
+                self.height
+            );
+            return Err(ErrorType::WrongArgument(String::from(
+                "The cursor hotspot must be inside the image",
+            )));
+        }
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////      mouse tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_image() {
+        let mut image = CursorImage {
+            width: 2,
+            height: 1,
+            hotspot: (1, 0),
+            pixels: vec![0; 8],
+        };
+        assert!(image.check().is_ok());
+        image.hotspot = (0, 1);
+        assert!(image.check().is_err());
+        image.hotspot = (0, 0);
+        image.pixels.truncate(7);
+        assert!(image.check().is_err());
+    }
+}
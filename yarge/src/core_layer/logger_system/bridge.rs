@@ -0,0 +1,427 @@
+//! Bridges between the yarge loggers and the `log` and `tracing` facades
+//! The records of the facades are sent to the yarge targets,
+//! and the yarge records can be emitted as `tracing` events with the `LogTarget::Tracing` target
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use crate::{GLOBAL_LOGGER, LogLevel, LogRecord, LogTarget, PlatformLayer, PlatformLayerImpl};
+
+/// The `tracing` target of the events and spans emitted by yarge
+/// They are ignored by the bridge to avoid sending the yarge records back to the yarge loggers
+#[cfg(tracing_bridge)]
+pub(crate) const TRACING_TARGET: &str = "yarge";
+
+/// Installs the bridges enabled by the features
+/// A bridge is not installed if the application already set its own `log' logger or `tracing' subscriber
+pub(crate) fn init() {
+    #[cfg(log_bridge)]
+    match log::set_logger(&LOG_BRIDGE) {
+        Ok(()) => {
+            log::set_max_level(log::LevelFilter::Trace);
+            log_debug!("`log' records are now routed to the yarge loggers");
+        }
+        Err(_) => log_debug!("A `log' logger is already installed, the bridge is not installed"),
+    }
+
+    #[cfg(tracing_bridge)]
+    match tracing::subscriber::set_global_default(TracingBridge::default()) {
+        Ok(()) => log_debug!("`tracing' events are now routed to the yarge loggers"),
+        Err(_) => log_debug!(
+            "A `tracing' subscriber is already installed, the yarge logs can still be sent to it with the `Tracing' log target"
+        ),
+    }
+}
+
+/// Checks if a record coming from the given module would be written anywhere
+fn is_enabled(level: &LogLevel, module_path: &str) -> bool {
+    match GLOBAL_LOGGER.read() {
+        Ok(logger) => logger
+            .config
+            .get_targets(level, module_path)
+            .next()
+            .is_some(),
+        Err(_) => true,
+    }
+}
+
+/// Writes a record coming from a facade to the yarge targets
+fn write_record(record: &LogRecord, module_path: &str) {
+    let logger = match GLOBAL_LOGGER.read() {
+        Ok(logger) => logger,
+        Err(err) => {
+            eprintln!(
+                "Logging failure {:?}, failed to get info from the global logger, original message {}",
+                err, record.message
+            );
+            return;
+        }
+    };
+    for target in logger.config.get_targets(&record.level, module_path) {
+        if let Err(err) = PlatformLayerImpl::write_log(record, target) {
+            let message = format!(
+                "Logging failure {:?}, default to error console, original message: {}\n",
+                err, record.message
+            );
+            let record = LogRecord::new(LogLevel::Warn, message, file!(), line!());
+            if let Err(err) = PlatformLayerImpl::write_log(&record, &LogTarget::ErrorConsole) {
+                panic!(
+                    "Critical logging failure: {:?}, original message: {}",
+                    err, record.message
+                );
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////       log bridge       ///////////////////
+//////////////////////////////////////////////////////////
+
+/// The `log' logger routing the records to the yarge loggers
+#[cfg(log_bridge)]
+struct LogBridge;
+
+#[cfg(log_bridge)]
+static LOG_BRIDGE: LogBridge = LogBridge;
+
+#[cfg(log_bridge)]
+impl LogBridge {
+    /// Converts a `log' level, the trace records are considered as debug ones
+    fn convert_level(level: log::Level) -> LogLevel {
+        match level {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug | log::Level::Trace => LogLevel::Debug,
+        }
+    }
+}
+
+#[cfg(log_bridge)]
+impl log::Log for LogBridge {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        is_enabled(&Self::convert_level(metadata.level()), metadata.target())
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        let level = Self::convert_level(record.level());
+        let module_path = record.module_path().unwrap_or(record.target());
+        if !is_enabled(&level, module_path) {
+            return;
+        }
+        let record = LogRecord::new(
+            level,
+            record.args().to_string(),
+            record.file_static().unwrap_or("unknown"),
+            record.line().unwrap_or(0),
+        );
+        write_record(&record, module_path);
+    }
+
+    fn flush(&self) {
+        let _ = PlatformLayerImpl::flush_log();
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////     tracing bridge     ///////////////////
+//////////////////////////////////////////////////////////
+
+/// Emits a yarge record as a `tracing` event, inside the current spans
+#[cfg(tracing_bridge)]
+pub(crate) fn emit_tracing_event(record: &LogRecord) {
+    macro_rules! emit {
+        ($level:expr) => {
+            tracing::event!(
+                target: TRACING_TARGET,
+                $level,
+                timestamp = %record.timestamp,
+                file = record.file,
+                line = record.line,
+                thread = %record.thread,
+                frame = record.frame,
+                "{}",
+                record.message
+            )
+        };
+    }
+    match record.level {
+        LogLevel::Debug => emit!(tracing::Level::DEBUG),
+        LogLevel::Info => emit!(tracing::Level::INFO),
+        LogLevel::Warn => emit!(tracing::Level::WARN),
+        LogLevel::Error => emit!(tracing::Level::ERROR),
+    }
+}
+
+#[cfg(tracing_bridge)]
+thread_local! {
+    /// The spans entered by the current thread, from the outermost to the innermost
+    static ENTERED_SPANS: std::cell::RefCell<Vec<u64>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// A span known by the bridge
+#[cfg(tracing_bridge)]
+struct TracingSpan {
+    /// The name of the span
+    name: &'static str,
+    /// The recorded fields, formatted as `key=value`
+    fields: Vec<String>,
+    /// The number of handles to the span
+    references: usize,
+}
+
+/// The `tracing' subscriber routing the events to the yarge loggers
+/// The events are prefixed with their spans, for instance `load{path="a.obj"}: Parsing the file`
+#[cfg(tracing_bridge)]
+#[derive(Default)]
+struct TracingBridge {
+    /// The next id of a span, the ids must not be 0
+    next_id: std::sync::atomic::AtomicU64,
+    /// The spans that are still referenced
+    spans: std::sync::Mutex<std::collections::HashMap<u64, TracingSpan>>,
+}
+
+/// Collects the fields of an event or a span
+#[cfg(tracing_bridge)]
+#[derive(Default)]
+struct TracingVisitor {
+    /// The message of an event
+    message: String,
+    /// The other fields, formatted as `key=value`
+    fields: Vec<String>,
+}
+
+#[cfg(tracing_bridge)]
+impl tracing::field::Visit for TracingVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        if field.name() == "message" {
+            self.message = String::from(value);
+        } else {
+            self.fields.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+}
+
+#[cfg(tracing_bridge)]
+impl TracingBridge {
+    /// Converts a `tracing' level, the trace events are considered as debug ones
+    fn convert_level(level: &tracing::Level) -> LogLevel {
+        match *level {
+            tracing::Level::ERROR => LogLevel::Error,
+            tracing::Level::WARN => LogLevel::Warn,
+            tracing::Level::INFO => LogLevel::Info,
+            _ => LogLevel::Debug,
+        }
+    }
+
+    /// Formats the spans entered by the current thread
+    fn get_span_context(&self) -> String {
+        let Ok(spans) = self.spans.lock() else {
+            return String::new();
+        };
+        ENTERED_SPANS.with_borrow(|entered| {
+            entered
+                .iter()
+                .filter_map(|id| spans.get(id))
+                .map(|span| {
+                    if span.fields.is_empty() {
+                        String::from(span.name)
+                    } else {
+                        format!("{}{{{}}}", span.name, span.fields.join(","))
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(":")
+        })
+    }
+}
+
+#[cfg(tracing_bridge)]
+impl tracing::Subscriber for TracingBridge {
+    fn register_callsite(
+        &self,
+        metadata: &'static tracing::Metadata<'static>,
+    ) -> tracing::subscriber::Interest {
+        if metadata.target() == TRACING_TARGET {
+            tracing::subscriber::Interest::never()
+        } else {
+            // The yarge filters can change at runtime
+            tracing::subscriber::Interest::sometimes()
+        }
+    }
+
+    fn enabled(&self, metadata: &tracing::Metadata<'_>) -> bool {
+        if metadata.target() == TRACING_TARGET {
+            return false;
+        }
+        // The spans are always needed as a context for the events
+        metadata.is_span() || is_enabled(&Self::convert_level(metadata.level()), metadata.target())
+    }
+
+    fn new_span(&self, attributes: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        let id = self
+            .next_id
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+            + 1;
+        let mut visitor = TracingVisitor::default();
+        attributes.record(&mut visitor);
+        if let Ok(mut spans) = self.spans.lock() {
+            let _ = spans.insert(
+                id,
+                TracingSpan {
+                    name: attributes.metadata().name(),
+                    fields: visitor.fields,
+                    references: 1,
+                },
+            );
+        }
+        tracing::span::Id::from_u64(id)
+    }
+
+    fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+        let mut visitor = TracingVisitor::default();
+        values.record(&mut visitor);
+        if let Ok(mut spans) = self.spans.lock()
+            && let Some(span) = spans.get_mut(&span.into_u64())
+        {
+            span.fields.append(&mut visitor.fields);
+        }
+    }
+
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {
+        // Default behavior: don't do anything
+    }
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let metadata = event.metadata();
+        let level = Self::convert_level(metadata.level());
+        let mut visitor = TracingVisitor::default();
+        event.record(&mut visitor);
+
+        let mut message = self.get_span_context();
+        if !message.is_empty() {
+            message.push_str(": ");
+        }
+        message.push_str(&visitor.message);
+        for field in visitor.fields {
+            message.push(' ');
+            message.push_str(&field);
+        }
+
+        let record = LogRecord::new(
+            level,
+            message,
+            metadata.file().unwrap_or("unknown"),
+            metadata.line().unwrap_or(0),
+        );
+        write_record(&record, metadata.target());
+    }
+
+    fn enter(&self, span: &tracing::span::Id) {
+        ENTERED_SPANS.with_borrow_mut(|entered| entered.push(span.into_u64()));
+    }
+
+    fn exit(&self, span: &tracing::span::Id) {
+        let id = span.into_u64();
+        ENTERED_SPANS.with_borrow_mut(|entered| {
+            if let Some(position) = entered.iter().rposition(|entered_id| *entered_id == id) {
+                let _ = entered.remove(position);
+            }
+        });
+    }
+
+    fn clone_span(&self, span: &tracing::span::Id) -> tracing::span::Id {
+        if let Ok(mut spans) = self.spans.lock()
+            && let Some(span) = spans.get_mut(&span.into_u64())
+        {
+            span.references += 1;
+        }
+        span.clone()
+    }
+
+    fn try_close(&self, span: tracing::span::Id) -> bool {
+        let id = span.into_u64();
+        let Ok(mut spans) = self.spans.lock() else {
+            return false;
+        };
+        let Some(span) = spans.get_mut(&id) else {
+            return false;
+        };
+        span.references -= 1;
+        if span.references == 0 {
+            let _ = spans.remove(&id);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////     bridge tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{LogSink, core_layer::logger_system::logger::LoggerSystemInternal};
+
+    #[cfg(log_bridge)]
+    #[test]
+    fn log_records() {
+        init();
+        LoggerSystemInternal::add_sink(LogSink::new(
+            "log_bridge_test",
+            LogTarget::Memory { capacity: 64 },
+            LogLevel::Debug,
+        ))
+        .unwrap();
+        log::warn!("log_bridge_test {}", 42);
+        LoggerSystemInternal::remove_sink("log_bridge_test").unwrap();
+
+        let records: Vec<LogRecord> = crate::logs::recent(LogLevel::Warn)
+            .into_iter()
+            .filter(|record| record.message == "log_bridge_test 42")
+            .collect();
+        assert_eq!(records.len(), 1);
+        assert!(records[0].file.ends_with("bridge.rs"));
+    }
+
+    #[cfg(tracing_bridge)]
+    #[test]
+    fn tracing_events() {
+        LoggerSystemInternal::add_sink(LogSink::new(
+            "tracing_sink",
+            LogTarget::Memory { capacity: 64 },
+            LogLevel::Debug,
+        ))
+        .unwrap();
+        tracing::subscriber::with_default(TracingBridge::default(), || {
+            let _span = tracing::info_span!("load", path = "a.obj").entered();
+            tracing::info!(vertices = 3, "tracing_bridge_test");
+            // Emitted by yarge, so ignored by the bridge
+            tracing::info!(target: TRACING_TARGET, "tracing_bridge_test");
+        });
+        LoggerSystemInternal::remove_sink("tracing_sink").unwrap();
+
+        let messages: Vec<String> = crate::logs::recent(LogLevel::Info)
+            .into_iter()
+            .map(|record| record.message)
+            .filter(|message| message.contains("tracing_bridge_test"))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![String::from(
+                "load{path=\"a.obj\"}: tracing_bridge_test vertices=3"
+            )]
+        );
+    }
+}
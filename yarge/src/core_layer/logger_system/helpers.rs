@@ -1,30 +1,224 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
 /// The possible log levels
-#[derive(Debug, Default, Clone, Copy)]
+/// The levels are ordered by severity: Debug < Info < Warn < Error
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum LogLevel {
+    /// Only visible on debug mode
+    Debug,
     /// To be used when displaying information
     #[default]
     Info,
-    /// Only visible on debug mode
-    Debug,
     /// Non fatal error messages
     Warn,
     /// Fatal error messages
     Error,
 }
 
+impl std::str::FromStr for LogLevel {
+    type Err = ErrorType;
+
+    /// Parses a level name, ignoring the case
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().as_str() {
+            "debug" => Ok(LogLevel::Debug),
+            "info" => Ok(LogLevel::Info),
+            "warn" => Ok(LogLevel::Warn),
+            "error" => Ok(LogLevel::Error),
+            _ => Err(ErrorType::WrongArgument(format!(
+                "Unknown log level `{}', expected one of `debug', `info', `warn' or `error'",
+                name
+            ))),
+        }
+    }
+}
+
 /// The location of the loggers
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LogTarget {
     /// To log to the console
     #[default]
     Console,
     /// To log to the error console
     ErrorConsole,
-    // /// To log to a markdown file
-    // Markdown(PathBuf),
-    // /// To log to a json file
-    // Json(PathBuf),
+    /// To log to a file, rotated when it gets too big or too old
+    File(LogFileConfig),
+    /// To log to a Markdown file, as a table for human review
+    Markdown(LogFileConfig),
+    /// To log to a JSON-lines file, one JSON object per log
+    /// Each object has the `level', `timestamp', `file', `line', `thread', `frame' and `message' fields
+    Json(LogFileConfig),
+    /// To keep the most recent logs in memory, see [crate::logs::recent]
+    /// All the memory targets share the same records
+    Memory {
+        /// The maximum number of kept records, the oldest ones are dropped
+        capacity: usize,
+    },
+    /// To emit the logs as `tracing` events, inside the current spans
+    #[cfg(tracing_bridge)]
+    Tracing,
+}
+
+/// The configuration of a log file
+/// In a configuration file, it is written as:
+/// ```toml
+/// [logger.target.File]
+/// path = "logs/game.log"
+/// max_size = 1048576
+/// max_files = 3
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogFileConfig {
+    /// The path of the current log file
+    /// The rotated files are named after it: `game.log.1` being the most recent one
+    pub path: PathBuf,
+    /// The maximum size of a log file in bytes before rotating it
+    /// 0 means no size limit
+    pub max_size: u64,
+    /// The maximum age of a log file in seconds before rotating it
+    /// 0 means no age limit
+    pub max_age: u64,
+    /// The maximum number of rotated files to keep, the oldest ones are deleted
+    /// 0 means the log file is simply truncated when rotating it
+    pub max_files: usize,
+}
+
+impl Default for LogFileConfig {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("yarge.log"),
+            max_size: 10 * 1024 * 1024,
+            max_age: 0,
+            max_files: 5,
+        }
+    }
+}
+
+/// An additional log output, receiving the logs alongside the main target
+/// In a configuration file, the sinks are written as:
+/// ```toml
+/// [[logger.sinks]]
+/// name = "verbose"
+/// min_level = "Debug"
+/// target = { File = { path = "logs/debug.log" } }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogSink {
+    /// The name of the sink, used to remove it at runtime
+    pub name: String,
+    /// Any logs with weaker level won't be sent to this sink
+    /// The per module filters of the logger don't apply to the sinks
+    pub min_level: LogLevel,
+    /// Where to log
+    pub target: LogTarget,
+}
+
+impl Default for LogSink {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            min_level: LogLevel::Debug,
+            target: LogTarget::default(),
+        }
+    }
+}
+
+impl LogSink {
+    /// Creates a new sink
+    pub fn new(name: &str, target: LogTarget, min_level: LogLevel) -> Self {
+        Self {
+            name: String::from(name),
+            min_level,
+            target,
+        }
+    }
+}
+
+/// A minimum log level for the logs coming from a module and its sub modules
+/// For instance `yarge::rendering_layer=warn` hides the info and debug logs of the renderer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    /// The module path, for instance `yarge::rendering_layer` or `my_game`
+    pub module: String,
+    /// The minimum level of the displayed logs for this module
+    pub min_level: LogLevel,
+}
+
+impl LogFilter {
+    /// Checks if a module path is the filtered module or one of its sub modules
+    pub fn matches(&self, module_path: &str) -> bool {
+        match module_path.strip_prefix(self.module.as_str()) {
+            Some(rest) => rest.is_empty() || rest.starts_with("::"),
+            None => false,
+        }
+    }
+
+    /// Parses a list of filters such as `yarge::rendering_layer=warn,my_game=debug`
+    pub fn parse_list(filters: &str) -> Result<Vec<Self>, ErrorType> {
+        let mut parsed = Vec::new();
+        for filter in filters.split(',') {
+            let filter = filter.trim();
+            if filter.is_empty() {
+                continue;
+            }
+            let Some((module, level)) = filter.split_once('=') else {
+                return Err(ErrorType::WrongArgument(format!(
+                    "Invalid log filter `{}', expected `module=level'",
+                    filter
+                )));
+            };
+            parsed.push(LogFilter {
+                module: String::from(module.trim()),
+                min_level: level.trim().parse()?,
+            });
+        }
+        Ok(parsed)
+    }
+}
+
+impl std::fmt::Display for LogFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let level = format!("{:?}", self.min_level).to_lowercase();
+        write!(f, "{}={}", self.module, level)
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////     logger tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels() {
+        assert!(LogLevel::Debug < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Warn);
+        assert!(LogLevel::Warn < LogLevel::Error);
+        assert_eq!("WARN".parse::<LogLevel>().ok(), Some(LogLevel::Warn));
+        assert!("verbose".parse::<LogLevel>().is_err());
+    }
+
+    #[test]
+    fn filters() {
+        let filters = LogFilter::parse_list("yarge::rendering_layer=warn, my_game=debug").unwrap();
+        assert_eq!(filters.len(), 2);
+        assert_eq!(filters[0].min_level, LogLevel::Warn);
+        assert_eq!(filters[1].to_string(), "my_game=debug");
+
+        assert!(filters[0].matches("yarge::rendering_layer"));
+        assert!(filters[0].matches("yarge::rendering_layer::rendering_impl"));
+        assert!(!filters[0].matches("yarge::rendering_layer_other"));
+        assert!(!filters[0].matches("yarge"));
+
+        assert!(LogFilter::parse_list("my_game").is_err());
+        assert!(LogFilter::parse_list("my_game=loud").is_err());
+    }
 }
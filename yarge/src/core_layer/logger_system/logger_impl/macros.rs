@@ -1,29 +1,43 @@
 /// Macro for for logging message
 /// This macro should not be used on its own but through other macros like error!, warn!, debug! and info!
+/// The record is written to every target of the given iterator
 #[macro_export]
 macro_rules! log {
-    ($level:expr, $target:expr) => {{
+    ($level:expr, $targets:expr) => {{
         #[allow(unused)]
-        use $crate::{PlatformLayer, PlatformLayerImpl, LogLevel, LogTarget};
-        let message = format!("No message (from {}:{})", file!(), line!());
-        if let Err(err) = PlatformLayerImpl::write_log($level, &message, $target) {
-            let message = format!("Logging failure {:?}, default to error console, original message: {}\n", err, message
-            );
-            if let Err(err) = PlatformLayerImpl::write_log(&LogLevel::Warn, &message, &LogTarget::ErrorConsole) {
-                panic!("Critical logging failure: {:?}, original message: {}", err, message);
+        use $crate::{PlatformLayer, PlatformLayerImpl, LogLevel, LogRecord, LogTarget};
+        let mut targets = $targets.peekable();
+        if targets.peek().is_some() {
+            let record = LogRecord::new(*$level, String::from("No message"), file!(), line!());
+            for target in targets {
+                if let Err(err) = PlatformLayerImpl::write_log(&record, target) {
+                    let message = format!("Logging failure {:?}, default to error console, original message: {}\n", err, record.message
+                    );
+                    let record = LogRecord::new(LogLevel::Warn, message, file!(), line!());
+                    if let Err(err) = PlatformLayerImpl::write_log(&record, &LogTarget::ErrorConsole) {
+                        panic!("Critical logging failure: {:?}, original message: {}", err, record.message);
+                    }
+                }
             }
         }
     }};
-    ($level:expr, $target:expr, $($arg:tt)*) => {{
+    ($level:expr, $targets:expr, $($arg:tt)*) => {{
         #[allow(unused)]
-        use $crate::{PlatformLayer, PlatformLayerImpl, LogLevel, LogTarget};
-        let message = format!("{} (from {}:{})", format!($($arg)*), file!(), line!());
-        if let Err(err) = PlatformLayerImpl::write_log($level, &message, $target) {
-            let message = format!("Logging failure {:?}, default to error console, original message: {}\n",
-                err, message
-            );
-            if let Err(err) = PlatformLayerImpl::write_log(&LogLevel::Warn, &message, &LogTarget::ErrorConsole) {
-                panic!("Critical logging failure: {:?}, original message: {}", err, message);
+        use $crate::{PlatformLayer, PlatformLayerImpl, LogLevel, LogRecord, LogTarget};
+        let mut targets = $targets.peekable();
+        // The message is only formatted once, and only if it will be written somewhere
+        if targets.peek().is_some() {
+            let record = LogRecord::new(*$level, format!($($arg)*), file!(), line!());
+            for target in targets {
+                if let Err(err) = PlatformLayerImpl::write_log(&record, target) {
+                    let message = format!("Logging failure {:?}, default to error console, original message: {}\n",
+                        err, record.message
+                    );
+                    let record = LogRecord::new(LogLevel::Warn, message, file!(), line!());
+                    if let Err(err) = PlatformLayerImpl::write_log(&record, &LogTarget::ErrorConsole) {
+                        panic!("Critical logging failure: {:?}, original message: {}", err, record.message);
+                    }
+                }
             }
         }
     }};
@@ -37,14 +51,14 @@ macro_rules! log_info {
         use $crate::{LogLevel, LogTarget};
         match $crate::GLOBAL_LOGGER.read() {
             Ok(logger) => {
-                let target = &logger.config.target;
-                log!(&LogLevel::Info, target)
+                let targets = logger.config.get_targets(&LogLevel::Info, module_path!());
+                log!(&LogLevel::Info, targets)
             },
             Err(err) => {
                 let message = format!("Logging failure {:?}, failed to get info from the global logger, default to error console\n",
                     err
                 );
-                log!(&LogLevel::Error, &LogTarget::ErrorConsole, "{}", message)
+                log!(&LogLevel::Error, std::iter::once(&LogTarget::ErrorConsole), "{}", message)
             }
         }
     }};
@@ -53,14 +67,14 @@ macro_rules! log_info {
         use $crate::{LogLevel, LogTarget, log};
         match $crate::GLOBAL_LOGGER.read() {
             Ok(logger) => {
-                let target = &logger.config.target;
-                log!(&LogLevel::Info, target, $($arg)*)
+                let targets = logger.config.get_targets(&LogLevel::Info, module_path!());
+                log!(&LogLevel::Info, targets, $($arg)*)
             },
             Err(err) => {
                 let message = format!("Logging failure {:?}, failed to get info from the global logger, default to error console, original message {}\n",
                     err, format!($($arg)*)
                 );
-                log!(&LogLevel::Error, &LogTarget::ErrorConsole, "{}", message)
+                log!(&LogLevel::Error, std::iter::once(&LogTarget::ErrorConsole), "{}", message)
             }
         }
     }};
@@ -74,14 +88,14 @@ macro_rules! log_debug {
         use $crate::{LogLevel, LogTarget, log};
         match $crate::GLOBAL_LOGGER.read() {
             Ok(logger) => {
-                let target = &logger.config.target;
-                log!(&LogLevel::Debug, target)
+                let targets = logger.config.get_targets(&LogLevel::Debug, module_path!());
+                log!(&LogLevel::Debug, targets)
             },
             Err(err) => {
                 let message = format!("Logging failure {:?}, failed to get info from the global logger, default to error console\n",
                     err
                 );
-                log!(&LogLevel::Error, &LogTarget::ErrorConsole, "{}", message)
+                log!(&LogLevel::Error, std::iter::once(&LogTarget::ErrorConsole), "{}", message)
             }
         }
     }};
@@ -90,14 +104,14 @@ macro_rules! log_debug {
         use $crate::{LogLevel, LogTarget, log};
         match $crate::GLOBAL_LOGGER.read() {
             Ok(logger) => {
-                let target = &logger.config.target;
-                log!(&LogLevel::Debug, target, $($arg)*)
+                let targets = logger.config.get_targets(&LogLevel::Debug, module_path!());
+                log!(&LogLevel::Debug, targets, $($arg)*)
             },
             Err(err) => {
                 let message = format!("Logging failure {:?}, failed to get info from the global logger, default to error console, original message {}\n",
                     err, format!($($arg)*)
                 );
-                log!(&LogLevel::Error, &LogTarget::ErrorConsole, "{}", message)
+                log!(&LogLevel::Error, std::iter::once(&LogTarget::ErrorConsole), "{}", message)
             }
         }
     }};
@@ -111,14 +125,14 @@ macro_rules! log_warn {
         use $crate::{LogLevel, LogTarget, log};
         match $crate::GLOBAL_LOGGER.read() {
             Ok(logger) => {
-                let target = &logger.config.target;
-                log!(&LogLevel::Warn, target)
+                let targets = logger.config.get_targets(&LogLevel::Warn, module_path!());
+                log!(&LogLevel::Warn, targets)
             },
             Err(err) => {
                 let message = format!("Logging failure {:?}, failed to get info from the global logger, default to error console\n",
                     err
                 );
-                log!(&LogLevel::Error, &LogTarget::ErrorConsole, "{}", message)
+                log!(&LogLevel::Error, std::iter::once(&LogTarget::ErrorConsole), "{}", message)
             }
         }
     }};
@@ -127,14 +141,14 @@ macro_rules! log_warn {
         use $crate::{LogLevel, LogTarget, log};
         match $crate::GLOBAL_LOGGER.read() {
             Ok(logger) => {
-                let target = &logger.config.target;
-                log!(&LogLevel::Warn, target, $($arg)*)
+                let targets = logger.config.get_targets(&LogLevel::Warn, module_path!());
+                log!(&LogLevel::Warn, targets, $($arg)*)
             },
             Err(err) => {
                 let message = format!("Logging failure {:?}, failed to get info from the global logger, default to error console, original message {}\n",
                     err, format!($($arg)*)
                 );
-                log!(&LogLevel::Error, &LogTarget::ErrorConsole, "{}", message)
+                log!(&LogLevel::Error, std::iter::once(&LogTarget::ErrorConsole), "{}", message)
             }
         }
     }};
@@ -148,15 +162,15 @@ macro_rules! log_error {
         use $crate::{LogLevel, LogTarget, log};
         match $crate::GLOBAL_LOGGER.read() {
             Ok(logger) => {
-                let target = &logger.config.target;
-                $crate::log!(&LogLevel::Error, target)
+                let targets = logger.config.get_targets(&LogLevel::Error, module_path!());
+                $crate::log!(&LogLevel::Error, targets)
             },
             Err(err) => {
                 let message = format!(
                     "Logging failure {:?}, failed to get info from the global logger, default to error console\n",
                     err
                 );
-                $crate::log!(&LogLevel::Error, &LogTarget::ErrorConsole, "{}", message)
+                $crate::log!(&LogLevel::Error, std::iter::once(&LogTarget::ErrorConsole), "{}", message)
             }
         }
     }};
@@ -165,14 +179,14 @@ macro_rules! log_error {
         use $crate::{LogLevel, LogTarget, log};
         match $crate::GLOBAL_LOGGER.read() {
             Ok(logger) => {
-                let target = &logger.config.target;
-                log!(&LogLevel::Error, target, $($arg)*)
+                let targets = logger.config.get_targets(&LogLevel::Error, module_path!());
+                log!(&LogLevel::Error, targets, $($arg)*)
             },
             Err(err) => {
                 let message = format!("Logging failure {:?}, failed to get info from the global logger, default to error console, original message {}\n",
                     err, format!($($arg)*)
                 );
-                log!(&LogLevel::Error, &LogTarget::ErrorConsole, "{}", message)
+                log!(&LogLevel::Error, std::iter::once(&LogTarget::ErrorConsole), "{}", message)
             }
         }
     }};
@@ -3,9 +3,9 @@ use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
 use crate::{PlatformLayer, config::Config};
 
-use super::logger::LoggerSystem;
+use super::logger::{LoggerSystem, LoggerSystemInternal};
 
-use crate::{GLOBAL_LOGGER, LogLevel, LogTarget};
+use crate::{GLOBAL_LOGGER, LogFilter, LogLevel, LogSink, LogTarget};
 
 mod macros;
 
@@ -21,21 +21,84 @@ impl LoggerSystem {
             }
         };
         logger.config = config.logger_config.clone();
+        drop(logger);
+
+        #[cfg(any(log_bridge, tracing_bridge))]
+        crate::core_layer::logger_system::bridge::init();
+
         Ok(LoggerSystem {
             global_logger: &GLOBAL_LOGGER,
         })
     }
 
-    #[allow(unused)]
     /// Updates the the minimum log level
-    pub(crate) fn update_min_level(&mut self, _new_min_level: LogLevel) {
-        todo!("Implement logger level update")
+    pub(crate) fn update_min_level(&mut self, new_min_level: LogLevel) -> Result<(), ErrorType> {
+        match self.global_logger.write() {
+            Ok(mut logger) => logger.config.min_level = new_min_level,
+            Err(err) => {
+                // The logger can't be used while it is poisoned
+                eprintln!(
+                    "Failed to modify the global logger when updating the minimum log level: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        log_info!("Minimum log level updated to `{:?}'", new_min_level);
+        Ok(())
     }
 
-    #[allow(unused)]
     /// Updates the the log target
-    pub(crate) fn update_target(&mut self, _new_target: LogTarget) {
-        todo!("Implement logger target update")
+    pub(crate) fn update_target(&mut self, new_target: LogTarget) -> Result<(), ErrorType> {
+        match self.global_logger.write() {
+            Ok(mut logger) => logger.config.target = new_target.clone(),
+            Err(err) => {
+                // The logger can't be used while it is poisoned
+                eprintln!(
+                    "Failed to modify the global logger when updating the log target: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        log_info!("Log target updated to `{:?}'", new_target);
+        Ok(())
+    }
+
+    /// Updates the per module log filters
+    pub(crate) fn update_filters(&mut self, new_filters: Vec<LogFilter>) -> Result<(), ErrorType> {
+        let nb_filters = new_filters.len();
+        match self.global_logger.write() {
+            Ok(mut logger) => logger.config.filters = new_filters,
+            Err(err) => {
+                // The logger can't be used while it is poisoned
+                eprintln!(
+                    "Failed to modify the global logger when updating the log filters: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        log_info!("Log filters updated with {:?} filter(s)", nb_filters);
+        Ok(())
+    }
+
+    /// Updates the additional sinks, replacing the ones added at runtime
+    pub(crate) fn update_sinks(&mut self, new_sinks: Vec<LogSink>) -> Result<(), ErrorType> {
+        let nb_sinks = new_sinks.len();
+        match self.global_logger.write() {
+            Ok(mut logger) => logger.config.sinks = new_sinks,
+            Err(err) => {
+                // The logger can't be used while it is poisoned
+                eprintln!(
+                    "Failed to modify the global logger when updating the log sinks: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        log_info!("Log sinks updated with {:?} sink(s)", nb_sinks);
+        Ok(())
     }
 
     /// Shuts down the logger
@@ -51,3 +114,48 @@ impl LoggerSystem {
         Ok(())
     }
 }
+
+impl LoggerSystemInternal {
+    /// Adds a sink to the global logger, replacing the one with the same name if any
+    pub(crate) fn add_sink(sink: LogSink) -> Result<(), ErrorType> {
+        let name = sink.name.clone();
+        let replaced = match GLOBAL_LOGGER.write() {
+            Ok(mut logger) => logger.config.add_sink(sink),
+            Err(err) => {
+                // The logger can't be used while it is poisoned
+                eprintln!(
+                    "Failed to modify the global logger when adding a log sink: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        if replaced {
+            log_info!("Log sink `{}' replaced", name);
+        } else {
+            log_info!("Log sink `{}' added", name);
+        }
+        Ok(())
+    }
+
+    /// Removes a sink from the global logger
+    pub(crate) fn remove_sink(name: &str) -> Result<(), ErrorType> {
+        let removed = match GLOBAL_LOGGER.write() {
+            Ok(mut logger) => logger.config.remove_sink(name),
+            Err(err) => {
+                // The logger can't be used while it is poisoned
+                eprintln!(
+                    "Failed to modify the global logger when removing a log sink: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        if removed {
+            log_info!("Log sink `{}' removed", name);
+        } else {
+            log_warn!("Can't remove the log sink `{}', it does not exist", name);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//! The logs kept in memory by the `LogTarget::Memory` targets
+//! Useful for an in-game console or to check the logs in tests
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, PoisonError, TryLockError},
+};
+
+use crate::{LogLevel, LogRecord};
+
+/// The most recent records, shared by all the memory targets
+static MEMORY_LOGS: once_cell::sync::Lazy<Mutex<VecDeque<LogRecord>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(VecDeque::new()));
+
+/// Keeps a record, dropping the oldest ones above the capacity
+/// Warning: it is used by the loggers themselves,
+/// logging from here could deadlock so the errors are only returned
+pub(crate) fn push(record: &LogRecord, capacity: usize) -> Result<(), ErrorType> {
+    let mut logs = match MEMORY_LOGS.lock() {
+        Ok(logs) => logs,
+        Err(_) => return Err(ErrorType::Unknown),
+    };
+    while !logs.is_empty() && logs.len() >= capacity {
+        let _ = logs.pop_front();
+    }
+    if capacity > 0 {
+        logs.push_back(record.clone());
+    }
+    Ok(())
+}
+
+/// Gets the kept records with at least the given level, from the oldest to the most recent
+pub fn recent(min_level: LogLevel) -> Vec<LogRecord> {
+    MEMORY_LOGS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .filter(|record| record.level >= min_level)
+        .cloned()
+        .collect()
+}
+
+/// Gets the kept records like `recent`, without waiting for the records to be free
+/// Returns nothing if they are in use, for example when panicking while keeping a record
+pub(crate) fn try_recent(min_level: LogLevel) -> Option<Vec<LogRecord>> {
+    try_get(&MEMORY_LOGS, min_level)
+}
+
+/// Gets the records with at least the given level if they are not in use
+fn try_get(logs: &Mutex<VecDeque<LogRecord>>, min_level: LogLevel) -> Option<Vec<LogRecord>> {
+    let logs = match logs.try_lock() {
+        Ok(logs) => logs,
+        Err(TryLockError::Poisoned(err)) => err.into_inner(),
+        Err(TryLockError::WouldBlock) => return None,
+    };
+    Some(
+        logs.iter()
+            .filter(|record| record.level >= min_level)
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Drops all the kept records
+pub fn clear() {
+    MEMORY_LOGS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+}
+
+//////////////////////////////////////////////////////////
+///////////////     memory tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{LogSink, LogTarget, core_layer::logger_system::logger::LoggerSystemInternal};
+
+    #[test]
+    fn through_logger() {
+        LoggerSystemInternal::add_sink(LogSink::new(
+            "memory_test",
+            LogTarget::Memory { capacity: 64 },
+            LogLevel::Warn,
+        ))
+        .unwrap();
+        log_info!("memory_test info");
+        log_warn!("memory_test warning");
+        LoggerSystemInternal::remove_sink("memory_test").unwrap();
+
+        // Other tests may log at the same time
+        let messages: Vec<String> = recent(LogLevel::Debug)
+            .into_iter()
+            .map(|record| record.message)
+            .filter(|message| message.starts_with("memory_test"))
+            .collect();
+        assert_eq!(messages, vec![String::from("memory_test warning")]);
+    }
+
+    #[test]
+    fn try_get_in_use() {
+        // A local list as the shared one is used by the other tests
+        let records = Mutex::new(VecDeque::from([
+            LogRecord::new(LogLevel::Debug, String::from("debug"), file!(), line!()),
+            LogRecord::new(LogLevel::Error, String::from("error"), file!(), line!()),
+        ]));
+        let logs = records.lock().unwrap();
+        assert!(try_get(&records, LogLevel::Debug).is_none());
+        drop(logs);
+        let messages: Vec<String> = try_get(&records, LogLevel::Warn)
+            .unwrap()
+            .into_iter()
+            .map(|record| record.message)
+            .collect();
+        assert_eq!(messages, vec![String::from("error")]);
+    }
+}
@@ -1,3 +1,7 @@
+#[cfg(any(log_bridge, tracing_bridge))]
+pub(crate) mod bridge;
 pub(crate) mod helpers;
 pub(crate) mod logger;
 pub(crate) mod logger_impl;
+pub mod memory;
+pub(crate) mod record;
@@ -0,0 +1,163 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::LogLevel;
+
+/// The index of the current frame, stamped on every log record
+pub(crate) static FRAME_INDEX: AtomicU64 = AtomicU64::new(0);
+
+/// The header of a Markdown log file
+pub(crate) const MARKDOWN_HEADER: &str =
+    "| Timestamp | Level | Thread | Frame | Location | Message |\n|---|---|---|---|---|---|";
+
+/// A single log message with its context
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    /// The level of the log
+    pub level: LogLevel,
+    /// When the log was created, in milliseconds since the Unix epoch
+    pub timestamp: u128,
+    /// The source file that created the log
+    pub file: &'static str,
+    /// The line in the source file
+    pub line: u32,
+    /// The name of the thread that created the log, or its id if it is unnamed
+    pub thread: String,
+    /// The index of the frame during which the log was created
+    pub frame: u64,
+    /// The actual message
+    pub message: String,
+}
+
+impl LogRecord {
+    /// Creates a record stamped with the current time, thread and frame
+    /// This should not be used on its own but through the logging macros
+    pub fn new(level: LogLevel, message: String, file: &'static str, line: u32) -> Self {
+        // The platform clock can't be used as it logs its own failures
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis());
+        let current_thread = std::thread::current();
+        let thread = match current_thread.name() {
+            Some(name) => String::from(name),
+            None => format!("{:?}", current_thread.id()),
+        };
+        Self {
+            level,
+            timestamp,
+            file,
+            line,
+            thread,
+            frame: FRAME_INDEX.load(Ordering::Relaxed),
+            message,
+        }
+    }
+
+    /// Formats the record for the consoles
+    pub fn as_console_line(&self) -> String {
+        format!("{} (from {}:{})", self.message, self.file, self.line)
+    }
+
+    /// Formats the record for the plain log files
+    pub fn as_file_line(&self) -> String {
+        format!(
+            "[{}][{:?}]: {} (from {}:{})",
+            self.timestamp, self.level, self.message, self.file, self.line
+        )
+    }
+
+    /// Formats the record as a single line JSON object
+    pub fn as_json_line(&self) -> String {
+        format!(
+            "{{\"level\":\"{:?}\",\"timestamp\":{},\"file\":\"{}\",\"line\":{},\"thread\":\"{}\",\"frame\":{},\"message\":\"{}\"}}",
+            self.level,
+            self.timestamp,
+            Self::escape_json(self.file),
+            self.line,
+            Self::escape_json(&self.thread),
+            self.frame,
+            Self::escape_json(&self.message)
+        )
+    }
+
+    /// Formats the record as a Markdown table row, matching the header written at the top of the log file
+    pub fn as_markdown_row(&self) -> String {
+        format!(
+            "| {} | **{:?}** | {} | {} | `{}:{}` | {} |",
+            self.timestamp,
+            self.level,
+            Self::escape_markdown(&self.thread),
+            self.frame,
+            self.file,
+            self.line,
+            Self::escape_markdown(&self.message)
+        )
+    }
+
+    /// Escapes a string to be written inside a JSON string
+    fn escape_json(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for character in text.chars() {
+            match character {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                character if character.is_control() => {
+                    escaped.push_str(&format!("\\u{:04x}", character as u32))
+                }
+                character => escaped.push(character),
+            }
+        }
+        escaped
+    }
+
+    /// Escapes a string to be written inside a Markdown table cell
+    fn escape_markdown(text: &str) -> String {
+        text.trim_end()
+            .replace('|', "\\|")
+            .replace("\r\n", "<br>")
+            .replace('\n', "<br>")
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////     record tests     /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_record(message: &str) -> LogRecord {
+        LogRecord {
+            level: LogLevel::Warn,
+            timestamp: 42,
+            file: "src/main.rs",
+            line: 7,
+            thread: String::from("main"),
+            frame: 3,
+            message: String::from(message),
+        }
+    }
+
+    #[test]
+    fn json() {
+        let record = get_record("A \"quoted\"\tmessage\n");
+        assert_eq!(
+            record.as_json_line(),
+            r#"{"level":"Warn","timestamp":42,"file":"src/main.rs","line":7,"thread":"main","frame":3,"message":"A \"quoted\"\tmessage\n"}"#
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let record = get_record("a | b\nc");
+        assert_eq!(
+            record.as_markdown_row(),
+            "| 42 | **Warn** | main | 3 | `src/main.rs:7` | a \\| b<br>c |"
+        );
+    }
+}
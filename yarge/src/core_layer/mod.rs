@@ -5,5 +5,9 @@ pub(crate) mod entry;
 pub(crate) mod logger_system;
 
 pub(crate) mod core;
+/// A module writing crash reports when panicking
+pub(crate) mod crash_report;
+/// A module limiting the frame rate
+pub(crate) mod frame_limiter;
 /// A module that handles different input devices
 pub(crate) mod input_system;
@@ -0,0 +1,174 @@
+use std::backtrace::{Backtrace, BacktraceStatus};
+
+use super::ErrorType;
+
+/// An error keeping its original cause and the context added by each layer it went through
+/// For instance: `Failed to update the application layer: Failed to run the ECS systems: the element does not exist`
+/// The backtrace is only captured when the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables are set
+pub struct Error {
+    /// Boxed to keep the results small
+    inner: Box<ErrorInner>,
+}
+
+/// The content of an error
+struct ErrorInner {
+    /// The kind of the error
+    kind: ErrorType,
+    /// The context messages, from the innermost to the outermost one
+    contexts: Vec<String>,
+    /// The underlying error, if it comes from outside the engine
+    source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    /// Where the error was created
+    backtrace: Backtrace,
+}
+
+impl Error {
+    /// Creates a new error
+    pub fn new(kind: ErrorType) -> Self {
+        Self {
+            inner: Box::new(ErrorInner {
+                kind,
+                contexts: Vec::new(),
+                source: None,
+                backtrace: Backtrace::capture(),
+            }),
+        }
+    }
+
+    /// Creates a new error caused by an error from outside the engine
+    pub fn with_source<E>(kind: ErrorType, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let mut error = Self::new(kind);
+        error.inner.source = Some(Box::new(source));
+        error
+    }
+
+    /// Adds a context message describing what was being done when the error happened
+    pub fn context<C: Into<String>>(mut self, context: C) -> Self {
+        self.inner.contexts.push(context.into());
+        self
+    }
+
+    /// Accessor to the kind of the error
+    pub fn kind(&self) -> &ErrorType {
+        &self.inner.kind
+    }
+
+    /// Converts the error into its kind, dropping the context
+    pub fn into_kind(self) -> ErrorType {
+        self.inner.kind
+    }
+
+    /// Iterates over the context messages, from the outermost to the innermost one
+    pub fn contexts(&self) -> impl Iterator<Item = &str> {
+        self.inner.contexts.iter().rev().map(String::as_str)
+    }
+
+    /// Accessor to the backtrace, if it was captured
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match self.inner.backtrace.status() {
+            BacktraceStatus::Captured => Some(&self.inner.backtrace),
+            _ => None,
+        }
+    }
+}
+
+impl From<ErrorType> for Error {
+    fn from(kind: ErrorType) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for context in self.contexts() {
+            write!(f, "{}: ", context)?;
+        }
+        write!(f, "{}", self.inner.kind)?;
+        if let Some(source) = &self.inner.source {
+            write!(f, " (caused by: {})", source)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)?;
+        if let Some(backtrace) = self.backtrace() {
+            write!(f, "\n\nBacktrace:\n{}", backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.inner.source {
+            Some(source) => Some(source.as_ref()),
+            None => None,
+        }
+    }
+}
+
+/// Adds context to the errors of a result
+pub trait ErrorContext<T> {
+    /// Adds a context message to the error, if any
+    fn context<C: Into<String>>(self, context: C) -> Result<T, Error>;
+
+    /// Adds a lazily built context message to the error, if any
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, context: F) -> Result<T, Error>;
+}
+
+impl<T, E: Into<Error>> ErrorContext<T> for Result<T, E> {
+    fn context<C: Into<String>>(self, context: C) -> Result<T, Error> {
+        self.map_err(|err| err.into().context(context))
+    }
+
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, context: F) -> Result<T, Error> {
+        self.map_err(|err| err.into().context(context()))
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////     error tests      /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_chain() {
+        let ecs_result: Result<(), ErrorType> = Err(ErrorType::DoesNotExist);
+        let err = ecs_result
+            .context("Failed to run the ECS systems")
+            .context("Failed to update the application layer")
+            .unwrap_err();
+        assert!(matches!(err.kind(), ErrorType::DoesNotExist));
+        assert_eq!(
+            err.contexts().collect::<Vec<&str>>(),
+            vec![
+                "Failed to update the application layer",
+                "Failed to run the ECS systems"
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "Failed to update the application layer: Failed to run the ECS systems: the element does not exist"
+        );
+        assert!(std::error::Error::source(&err).is_none());
+    }
+
+    #[test]
+    fn source() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing.toml");
+        let err = Error::with_source(ErrorType::IO, io_error).context("Failed to read the config");
+        assert_eq!(
+            err.to_string(),
+            "Failed to read the config: an IO error occured (caused by: missing.toml)"
+        );
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
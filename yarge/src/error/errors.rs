@@ -33,6 +33,14 @@ pub enum ErrorType {
     /// Thrown when an IO error occured
     IO,
 
+    /// Thrown when a file can't be parsed
+    /// Take as parameters the line, the column and the reason of the failure
+    Parsing(usize, usize, String),
+
+    /// Thrown when the configuration breaks some invariants
+    /// Take as parameter the report of all the violations
+    InvalidConfig(crate::config::ConfigReport),
+
     /// Thrown when something is not yet implemented
     NotImplemented,
 
@@ -47,4 +55,33 @@ pub enum ErrorType {
 
     /// Thrown when something is not supported
     NotSupported,
+
+    /// Thrown when the engine or the game panicked
+    /// Take as parameter the panic message
+    Panic(String),
 }
+
+impl std::fmt::Display for ErrorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorType::Unknown => write!(f, "an unknown error occured"),
+            ErrorType::DivisionByZero => write!(f, "division by zero"),
+            ErrorType::WrongArgument(expected) => write!(f, "wrong argument: {}", expected),
+            ErrorType::DoesNotExist => write!(f, "the element does not exist"),
+            ErrorType::VulkanError => write!(f, "a Vulkan error occured"),
+            ErrorType::IO => write!(f, "an IO error occured"),
+            ErrorType::Parsing(line, column, reason) => {
+                write!(f, "parsing error at {}:{}: {}", line, column, reason)
+            }
+            ErrorType::InvalidConfig(report) => write!(f, "invalid configuration:\n{}", report),
+            ErrorType::NotImplemented => write!(f, "not yet implemented"),
+            ErrorType::InvalidIndex => write!(f, "invalid index"),
+            ErrorType::Duplicate => write!(f, "unexpected duplicate"),
+            ErrorType::BadRequest => write!(f, "bad request"),
+            ErrorType::NotSupported => write!(f, "not supported"),
+            ErrorType::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ErrorType {}
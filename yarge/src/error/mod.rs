@@ -3,5 +3,9 @@
 mod errors;
 pub use errors::{ErrorLevel, ErrorType};
 
+mod context;
+pub use context::{Error, ErrorContext};
+
 mod warnings;
-pub use warnings::WarningType;
+pub(crate) use warnings::WarningCollector;
+pub use warnings::{Warning, WarningType, report_warning};
@@ -1,6 +1,260 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::sync::{Mutex, PoisonError, atomic::Ordering};
+
+use crate::{config::WarningsConfig, core_layer::logger_system::record::FRAME_INDEX};
+
 /// The warning types
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum WarningType {
     /// An unrecognized warning type
     Unknown,
+
+    /// Thrown when a deprecated API is used
+    /// Take as parameters the deprecated API and what to use instead
+    DeprecatedApi(String, String),
+
+    /// Thrown when a resource is missing and a fallback is used instead
+    /// Take as parameter the missing resource
+    MissingResource(String),
+
+    /// Thrown when a frame takes longer than the configured threshold
+    /// Take as parameter the duration of the frame in milliseconds
+    SlowFrame(u128),
+
+    /// Thrown when the validation layers of the graphics API report a warning
+    /// Take as parameter the reported message
+    ValidationLayer(String),
+}
+
+impl WarningType {
+    /// Checks if two warnings are duplicates
+    /// The slow frames are all the same warning whatever their duration
+    fn is_duplicate(&self, other: &Self) -> bool {
+        match (self, other) {
+            (WarningType::SlowFrame(_), WarningType::SlowFrame(_)) => true,
+            _ => self == other,
+        }
+    }
+}
+
+impl std::fmt::Display for WarningType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WarningType::Unknown => write!(f, "an unknown warning occured"),
+            WarningType::DeprecatedApi(api, replacement) => {
+                write!(f, "`{}' is deprecated, use `{}' instead", api, replacement)
+            }
+            WarningType::MissingResource(resource) => {
+                write!(
+                    f,
+                    "the resource `{}' is missing, using a fallback",
+                    resource
+                )
+            }
+            WarningType::SlowFrame(duration) => write!(f, "slow frame of {}ms", duration),
+            WarningType::ValidationLayer(message) => {
+                write!(f, "validation layer warning: {}", message)
+            }
+        }
+    }
+}
+
+/// A warning collected by the engine
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    /// The kind of the warning
+    pub kind: WarningType,
+    /// The frame during which it was first reported
+    pub frame: u64,
+    /// How many times it was reported
+    pub count: usize,
+}
+
+/// Collects the warnings of the current frame and of the whole run
+pub(crate) struct WarningCollector {
+    /// The collector configuration
+    config: WarningsConfig,
+    /// The deduplicated warnings of the current frame
+    frame_warnings: Vec<Warning>,
+    /// The number of warnings dropped in the current frame because of `max_per_frame`
+    nb_dropped: usize,
+    /// The last frame each warning was logged
+    last_logged: Vec<(WarningType, u64)>,
+    /// The deduplicated warnings of the whole run
+    totals: Vec<Warning>,
+}
+
+/// The warnings reported from anywhere in the engine
+static WARNING_COLLECTOR: once_cell::sync::Lazy<Mutex<WarningCollector>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(WarningCollector::new(WarningsConfig::default())));
+
+/// Reports a warning during the current frame
+/// It is logged unless the same warning was logged recently,
+/// see [crate::config::ConfigBuilder::max_warnings_per_frame]
+pub fn report_warning(kind: WarningType) {
+    let frame = FRAME_INDEX.load(Ordering::Relaxed);
+    let should_log = WARNING_COLLECTOR
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .add(&kind, frame);
+    if should_log {
+        log_warn!("{}", kind);
+    }
+}
+
+impl WarningCollector {
+    /// Creates an empty collector
+    fn new(config: WarningsConfig) -> Self {
+        Self {
+            config,
+            frame_warnings: Vec::new(),
+            nb_dropped: 0,
+            last_logged: Vec::new(),
+            totals: Vec::new(),
+        }
+    }
+
+    /// Resets the global collector with a new configuration
+    pub(crate) fn init(config: &WarningsConfig) {
+        *WARNING_COLLECTOR
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Self::new(config.clone());
+    }
+
+    /// Takes the warnings of the frame that just ended from the global collector
+    pub(crate) fn end_frame() -> Vec<Warning> {
+        WARNING_COLLECTOR
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take_frame_warnings()
+    }
+
+    /// Gets the warnings of the whole run from the global collector
+    pub(crate) fn summary() -> Vec<Warning> {
+        WARNING_COLLECTOR
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .totals
+            .clone()
+    }
+
+    /// Gets the configured slow frame threshold of the global collector
+    pub(crate) fn slow_frame_threshold() -> u64 {
+        WARNING_COLLECTOR
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .config
+            .slow_frame_threshold
+    }
+
+    /// Collects a warning
+    /// Returns true if it should be logged
+    fn add(&mut self, kind: &WarningType, frame: u64) -> bool {
+        Self::count(&mut self.totals, kind, frame);
+
+        let is_new_in_frame = !self
+            .frame_warnings
+            .iter()
+            .any(|warning| warning.kind.is_duplicate(kind));
+        if is_new_in_frame
+            && self.config.max_per_frame > 0
+            && self.frame_warnings.len() >= self.config.max_per_frame
+        {
+            self.nb_dropped += 1;
+            return false;
+        }
+        Self::count(&mut self.frame_warnings, kind, frame);
+
+        match self
+            .last_logged
+            .iter_mut()
+            .find(|(logged, _)| logged.is_duplicate(kind))
+        {
+            Some((_, last_frame)) => {
+                if frame.saturating_sub(*last_frame) < self.config.log_period {
+                    return false;
+                }
+                *last_frame = frame;
+            }
+            None => self.last_logged.push((kind.clone(), frame)),
+        }
+        true
+    }
+
+    /// Increments the count of a warning, adding it if it is new
+    fn count(warnings: &mut Vec<Warning>, kind: &WarningType, frame: u64) {
+        match warnings
+            .iter_mut()
+            .find(|warning| warning.kind.is_duplicate(kind))
+        {
+            Some(warning) => warning.count += 1,
+            None => warnings.push(Warning {
+                kind: kind.clone(),
+                frame,
+                count: 1,
+            }),
+        }
+    }
+
+    /// Takes the warnings of the current frame and starts a new one
+    fn take_frame_warnings(&mut self) -> Vec<Warning> {
+        if self.nb_dropped > 0 {
+            log_debug!(
+                "{:?} warning(s) dropped this frame, at most {:?} distinct warnings are kept per frame",
+                self.nb_dropped,
+                self.config.max_per_frame
+            );
+            self.nb_dropped = 0;
+        }
+        std::mem::take(&mut self.frame_warnings)
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////    warnings tests    /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deduplication() {
+        let mut collector = WarningCollector::new(WarningsConfig::default());
+        assert!(collector.add(&WarningType::SlowFrame(120), 1));
+        assert!(!collector.add(&WarningType::SlowFrame(150), 1));
+        assert!(collector.add(&WarningType::MissingResource(String::from("cube.obj")), 1));
+
+        let warnings = collector.take_frame_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].kind, WarningType::SlowFrame(120));
+        assert_eq!(warnings[0].count, 2);
+        assert!(collector.take_frame_warnings().is_empty());
+    }
+
+    #[test]
+    fn rate_limit() {
+        let mut collector = WarningCollector::new(WarningsConfig {
+            max_per_frame: 2,
+            log_period: 10,
+            slow_frame_threshold: 0,
+        });
+        let warning = |index: usize| WarningType::ValidationLayer(format!("warning {}", index));
+
+        assert!(collector.add(&warning(0), 1));
+        assert!(collector.add(&warning(1), 1));
+        // Above the per frame limit
+        assert!(!collector.add(&warning(2), 1));
+        assert_eq!(collector.take_frame_warnings().len(), 2);
+
+        // Logged again only after the period
+        assert!(!collector.add(&warning(0), 5));
+        assert!(collector.add(&warning(0), 11));
+
+        let summary = collector.totals;
+        assert_eq!(summary.len(), 3);
+        assert_eq!(summary[0].count, 3);
+        assert_eq!(summary[0].frame, 1);
+    }
 }
@@ -31,6 +31,10 @@
 
 //! The yarge library
 
+// The X11 dependencies are still enabled by the default features when running headless
+#[cfg(all(headless_platform, feature = "x"))]
+use {x11 as _, xcb as _};
+
 pub(crate) mod core_layer;
 pub(crate) mod platform_layer;
 pub(crate) mod rendering_layer;
@@ -46,6 +50,7 @@ pub use core_layer::application_system::ecs::resource::UserResource as Resource;
 pub use core_layer::application_system::ecs::resource::UserResourceId as ResourceId;
 pub use core_layer::application_system::ecs::resource::UserResourceLoadingParameters as ResourceLoadingParameters;
 pub use core_layer::application_system::ecs::resource::{ResourceHandle, ResourceManager};
+pub use core_layer::application_system::ecs::time::{FrameStats, Time};
 pub use core_layer::application_system::ecs::{ECS, UnsafeECSCell};
 
 pub use core_layer::application_system::ecs::component::UserComponent as Component;
@@ -61,9 +66,14 @@ pub use core_layer::application_system::ecs::system::{
 
 pub use core_layer::input_system::{gamepad, keyboard, mouse};
 
-pub use core_layer::logger_system::helpers::{LogLevel, LogTarget};
+pub use core_layer::logger_system::helpers::{
+    LogFileConfig, LogFilter, LogLevel, LogSink, LogTarget,
+};
 pub use core_layer::logger_system::logger::GLOBAL_LOGGER;
+pub use core_layer::logger_system::memory as logs;
+pub use core_layer::logger_system::record::LogRecord;
 
+pub use platform_layer::event::Event as PlatformEvent;
 pub use platform_layer::platform::PlatformLayer;
 pub use platform_layer::platform_impl::{PlatformLayerImpl, PlatformLayerRwLock};
 pub use platform_layer::window::DisplayMode;
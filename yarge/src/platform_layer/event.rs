@@ -1,12 +1,16 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
-use crate::{gamepad::GamepadButton, keyboard::KeyboardKey, mouse::MouseButton};
+use crate::{
+    gamepad::GamepadButton,
+    keyboard::{KeyboardKey, KeyboardModifiers},
+    mouse::MouseButton,
+};
 
 #[allow(unused)]
 /// An enum representing an application event
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-pub(crate) enum Event {
+pub enum Event {
     /// Default unrecognized event
     #[default]
     Unrecognized,
@@ -16,9 +20,26 @@ pub(crate) enum Event {
 
     // Keyboard related events
     /// Event triggered on key press
-    KeyboardKeyPressed(KeyboardKey),
+    /// KeyboardKeyPressed(key, physical_key, scancode, modifiers)
+    /// The key follows the keyboard layout, like the 'Z' key of an AZERTY layout
+    /// The physical key is the key at the same position on a US QWERTY layout, like the 'W' key
+    /// The scancode is the raw code of the key given by the platform, the Linux evdev one on X11,
+    /// it tells apart the keys without a physical key
+    /// The modifiers are the ones held once the key is pressed
+    KeyboardKeyPressed(KeyboardKey, KeyboardKey, u32, KeyboardModifiers),
     /// Event triggered on key release
-    KeyboardKeyReleased(KeyboardKey),
+    /// KeyboardKeyReleased(key, physical_key, scancode, modifiers)
+    /// See KeyboardKeyPressed
+    KeyboardKeyReleased(KeyboardKey, KeyboardKey, u32, KeyboardModifiers),
+    /// Event triggered when a key press produces a character, to type text
+    /// TextInput(character)
+    /// The character follows the keyboard layout, the modifiers and the dead keys
+    /// A composed sequence sends one event per character
+    /// On X11, the dead keys and the compose sequences follow the `LC_CTYPE' locale,
+    /// set by the application or by the `window.use_system_locale' configuration
+    /// The input method server chosen by `XMODIFIERS', like IBus or Fcitx, is used when available,
+    /// with a basic composition: the text is committed without a preedit area
+    TextInput(char),
 
     // Mouse related events
     /// Event triggered on button press
@@ -28,8 +49,12 @@ pub(crate) enum Event {
     /// Event triggered when the mouse wheel is moved
     /// MouseScroll(delta)
     /// The delta can be positive or negative
-    /// The delta is the amount in pixels to scroll
+    /// The delta is the number of wheel notches, positive when scrolling up
     MouseScrolled(f32),
+    /// Event triggered when the mouse wheel is tilted
+    /// MouseScrolledHorizontally(delta)
+    /// The delta is the number of wheel notches, positive when scrolling right
+    MouseScrolledHorizontally(f32),
     /// Event triggered on mouse movement
     /// MouseMove(new_x, new_y)
     /// The position new_x and new_y are in pixel coordinates
@@ -38,6 +63,12 @@ pub(crate) enum Event {
     MouseMoved(u16, u16),
     /// Event triggered on mouse movement when a button is pressed
     MouseMovedAndButton(u16, u16, MouseButton),
+    /// Event triggered on mouse movement in the relative cursor mode
+    /// MouseMotionRaw(dx, dy)
+    /// The deltas are the raw device motions, without the pointer acceleration
+    /// As for MouseMoved, dy is positive when moving up
+    /// See [crate::mouse::CursorGrabMode::Relative]
+    MouseMotionRaw(f32, f32),
     /// Event triggered when the mouse enters the window
     /// MouseEnteredWindow(x, y)
     /// The position x and y correspond to the position at which the mouse entered the window
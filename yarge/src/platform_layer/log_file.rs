@@ -0,0 +1,197 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+use crate::core_layer::logger_system::helpers::LogFileConfig;
+
+/// All the opened log files, indexed by their path
+static LOG_FILES: once_cell::sync::Lazy<Mutex<HashMap<PathBuf, LogFile>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// An opened log file
+/// Warning: it is used by the loggers themselves,
+/// logging from here could deadlock so the errors are only returned
+pub(crate) struct LogFile {
+    /// The buffered file
+    writer: BufWriter<File>,
+    /// The current size of the file in bytes
+    size: u64,
+    /// When the file was created
+    created_at: SystemTime,
+}
+
+impl LogFile {
+    /// Opens a log file, appending to it if it already exists
+    fn open(path: &Path) -> Result<Self, ErrorType> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+            && std::fs::create_dir_all(parent).is_err()
+        {
+            return Err(ErrorType::IO);
+        }
+        let file = match OpenOptions::new().create(true).append(true).open(path) {
+            Ok(file) => file,
+            Err(_) => return Err(ErrorType::IO),
+        };
+        let (size, created_at) = match file.metadata() {
+            Ok(metadata) => (
+                metadata.len(),
+                metadata.created().unwrap_or_else(|_| SystemTime::now()),
+            ),
+            Err(_) => (0, SystemTime::now()),
+        };
+        Ok(Self {
+            writer: BufWriter::new(file),
+            size,
+            created_at,
+        })
+    }
+
+    /// Checks if the file must be rotated before writing a new line
+    fn should_rotate(&self, config: &LogFileConfig, line_size: u64) -> bool {
+        let too_big =
+            config.max_size > 0 && self.size > 0 && self.size + line_size > config.max_size;
+        let too_old = config.max_age > 0
+            && self
+                .created_at
+                .elapsed()
+                .is_ok_and(|age| age.as_secs() >= config.max_age);
+        too_big || too_old
+    }
+
+    /// Shifts the rotated files and starts a new log file
+    /// `game.log` becomes `game.log.1`, `game.log.1` becomes `game.log.2` and so on
+    fn rotate(&mut self, config: &LogFileConfig) -> Result<(), ErrorType> {
+        if self.writer.flush().is_err() {
+            return Err(ErrorType::IO);
+        }
+
+        if config.max_files > 0 {
+            // The oldest file is overwritten by the rename
+            for index in (1..config.max_files).rev() {
+                let from = Self::get_rotated_path(&config.path, index);
+                if from.exists()
+                    && std::fs::rename(&from, Self::get_rotated_path(&config.path, index + 1))
+                        .is_err()
+                {
+                    return Err(ErrorType::IO);
+                }
+            }
+            if std::fs::rename(&config.path, Self::get_rotated_path(&config.path, 1)).is_err() {
+                return Err(ErrorType::IO);
+            }
+        } else if std::fs::remove_file(&config.path).is_err() {
+            return Err(ErrorType::IO);
+        }
+
+        *self = Self::open(&config.path)?;
+        // The creation time may be reused by the file system for the new file
+        self.created_at = SystemTime::now();
+        Ok(())
+    }
+
+    /// Gets the path of a rotated file
+    fn get_rotated_path(path: &Path, index: usize) -> PathBuf {
+        let mut rotated = path.as_os_str().to_owned();
+        rotated.push(format!(".{}", index));
+        PathBuf::from(rotated)
+    }
+
+    /// Writes a line to the log file, rotating it first if needed
+    /// The header is written at the beginning of every new file
+    pub(crate) fn write(
+        config: &LogFileConfig,
+        header: Option<&str>,
+        line: &str,
+    ) -> Result<(), ErrorType> {
+        let mut files = match LOG_FILES.lock() {
+            Ok(files) => files,
+            Err(_) => return Err(ErrorType::Unknown),
+        };
+        let log_file = match files.entry(config.path.clone()) {
+            std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                entry.insert(Self::open(&config.path)?)
+            }
+        };
+
+        let line_size = line.len() as u64 + 1;
+        if log_file.should_rotate(config, line_size) {
+            log_file.rotate(config)?;
+        }
+        if let Some(header) = header
+            && log_file.size == 0
+        {
+            if writeln!(log_file.writer, "{}", header).is_err() {
+                return Err(ErrorType::IO);
+            }
+            log_file.size += header.len() as u64 + 1;
+        }
+        if writeln!(log_file.writer, "{}", line).is_err() {
+            return Err(ErrorType::IO);
+        }
+        log_file.size += line_size;
+        Ok(())
+    }
+
+    /// Flushes all the opened log files
+    pub(crate) fn flush_all() -> Result<(), ErrorType> {
+        let mut files = match LOG_FILES.lock() {
+            Ok(files) => files,
+            Err(_) => return Err(ErrorType::Unknown),
+        };
+        for log_file in files.values_mut() {
+            if log_file.writer.flush().is_err() {
+                return Err(ErrorType::IO);
+            }
+        }
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////    log file tests    /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation() {
+        let directory = std::env::temp_dir().join(format!("yarge_log_file_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        let config = LogFileConfig {
+            path: directory.join("test.log"),
+            max_size: 16,
+            max_age: 0,
+            max_files: 2,
+        };
+
+        for line in ["first line", "second line", "third line", "fourth line"] {
+            LogFile::write(&config, None, line).unwrap();
+        }
+        LogFile::flush_all().unwrap();
+
+        let read = |path: &Path| std::fs::read_to_string(path).unwrap();
+        assert_eq!(read(&config.path), "fourth line\n");
+        assert_eq!(
+            read(&LogFile::get_rotated_path(&config.path, 1)),
+            "third line\n"
+        );
+        assert_eq!(
+            read(&LogFile::get_rotated_path(&config.path, 2)),
+            "second line\n"
+        );
+        assert!(!LogFile::get_rotated_path(&config.path, 3).exists());
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
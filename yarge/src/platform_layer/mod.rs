@@ -12,3 +12,6 @@ pub(crate) mod platform_impl;
 
 /// A module representing an event
 pub(crate) mod event;
+
+/// A module writing and rotating the log files
+pub(crate) mod log_file;
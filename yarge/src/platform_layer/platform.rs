@@ -1,9 +1,29 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
-use crate::{LogLevel, LogTarget, config::Config};
+use crate::{
+    LogLevel, LogRecord, LogTarget,
+    config::Config,
+    core_layer::logger_system::{memory, record::MARKDOWN_HEADER},
+};
 
-use super::{event::Event, window::Window};
+use super::{event::Event, log_file::LogFile, window::Window};
+
+use colored::Colorize;
+
+/// The start of the monotonic clock, set the first time it is read
+static MONOTONIC_CLOCK_START: once_cell::sync::Lazy<std::time::Instant> =
+    once_cell::sync::Lazy::new(std::time::Instant::now);
+
+/// Get the correct ANSI color given the logging level
+fn format_level(level: &LogLevel) -> String {
+    match level {
+        LogLevel::Info => "Info".green().to_string(),
+        LogLevel::Debug => "Debug".yellow().to_string(),
+        LogLevel::Warn => "Warn".truecolor(255, 165, 0).to_string(),
+        LogLevel::Error => "Error".red().to_string(),
+    }
+}
 
 #[allow(private_interfaces)]
 /// Abstract trait for the platform specific code
@@ -19,22 +39,114 @@ pub trait PlatformLayer {
     fn shutdown(&mut self) -> Result<(), ErrorType>;
 
     /// Accessor to a window
-    fn get_window_ref(&self, window_id: u8) -> &impl Window;
+    fn get_window_ref(&self, window_id: u8) -> Result<&impl Window, ErrorType>;
 
     /// Mutable accessor to a window
-    fn get_window(&mut self, window_id: u8) -> &mut impl Window;
+    fn get_window(&mut self, window_id: u8) -> Result<&mut impl Window, ErrorType>;
+
+    /// Creates a new window from the window configuration of `config`
+    /// The id must not be used by another opened window
+    fn create_window(&mut self, window_id: u8, config: &Config) -> Result<(), ErrorType>;
+
+    /// Shuts down and removes a window
+    /// The main window, window 0, can't be destroyed
+    fn destroy_window(&mut self, window_id: u8) -> Result<(), ErrorType>;
 
-    /// Poll the next event
-    fn poll_event(&mut self) -> Result<Event, ErrorType>;
+    /// Gets the ids of the opened windows, starting with the main one
+    fn get_window_ids(&self) -> Vec<u8>;
+
+    /// Poll the next pending event of any window without blocking
+    /// The event is given with the id of the window it comes from
+    /// Returns None when no window has a pending event
+    fn poll_event(&mut self) -> Result<Option<(u8, Event)>, ErrorType>;
+
+    /// Drains all the pending events of every window without blocking
+    fn poll_events(&mut self) -> Result<Vec<(u8, Event)>, ErrorType> {
+        let mut events = Vec::new();
+        loop {
+            match self.poll_event() {
+                Ok(Some((_, Event::Unrecognized))) => {}
+                Ok(Some(event)) => events.push(event),
+                Ok(None) => return Ok(events),
+                Err(err) => {
+                    log_error!("Failed to drain the pending events: {:?}", err);
+                    return Err(ErrorType::Unknown);
+                }
+            }
+        }
+    }
 
     // Static method that are platform dependant
     /// Get the time in milliseconds ellapsed since the Unix epochs
     fn get_time_since_unix_epoch() -> Result<u128, ErrorType>;
 
+    /// Get the time in nanoseconds of a monotonic clock
+    /// It never goes backward and starts at an unspecified point, use it to measure durations
+    fn get_monotonic_time() -> Result<u128, ErrorType> {
+        // Default implementation
+        Ok(MONOTONIC_CLOCK_START.elapsed().as_nanos())
+    }
+
     /// Write a logging message
-    fn write_log(level: &LogLevel, message: &str, target: &LogTarget) -> Result<(), ErrorType>;
+    fn write_log(record: &LogRecord, target: &LogTarget) -> Result<(), ErrorType> {
+        // Default implementation
+        match target {
+            LogTarget::Console => {
+                println!(
+                    "[{}]: {}",
+                    format_level(&record.level),
+                    record.as_console_line()
+                );
+            }
+            LogTarget::ErrorConsole => {
+                eprintln!(
+                    "[{:?}]: {:?}",
+                    format_level(&record.level),
+                    record.as_console_line()
+                );
+            }
+            LogTarget::File(file_config) => {
+                LogFile::write(file_config, None, &record.as_file_line())?;
+            }
+            LogTarget::Markdown(file_config) => {
+                LogFile::write(
+                    file_config,
+                    Some(MARKDOWN_HEADER),
+                    &record.as_markdown_row(),
+                )?;
+            }
+            LogTarget::Json(file_config) => {
+                LogFile::write(file_config, None, &record.as_json_line())?;
+            }
+            LogTarget::Memory { capacity } => {
+                memory::push(record, *capacity)?;
+            }
+            #[cfg(tracing_bridge)]
+            LogTarget::Tracing => {
+                crate::core_layer::logger_system::bridge::emit_tracing_event(record);
+            }
+        };
+        Ok(())
+    }
+
     /// Flush the logging output
-    fn flush_log() -> Result<(), ErrorType>;
+    fn flush_log() -> Result<(), ErrorType> {
+        // Default implementation
+        use std::io::Write;
+        if let Err(err) = std::io::stdout().flush() {
+            log_error!("Failed to flush the stdout: {:?}", err);
+            return Err(ErrorType::Unknown);
+        }
+        if let Err(err) = std::io::stderr().flush() {
+            log_error!("Failed to flush the stderr: {:?}", err);
+            return Err(ErrorType::Unknown);
+        }
+        if let Err(err) = LogFile::flush_all() {
+            eprintln!("Failed to flush the log files: {:?}", err);
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
 
     /// Load a file into a string
     fn read_to_string(path: &std::path::Path) -> Result<String, ErrorType> {
@@ -53,6 +165,34 @@ pub trait PlatformLayer {
         }
     }
 
+    /// Get the last modification time of a file in milliseconds since the Unix epoch
+    fn get_modification_time(path: &std::path::Path) -> Result<u128, ErrorType> {
+        // Default implementation
+        // TODO: add implementation to the platform layer
+        let modified = match std::fs::metadata(path).and_then(|metadata| metadata.modified()) {
+            Ok(modified) => modified,
+            Err(err) => {
+                log_error!(
+                    "Failed to get the modification time of the file `{:?}': {:?}",
+                    path,
+                    err
+                );
+                return Err(ErrorType::IO);
+            }
+        };
+        match modified.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => Ok(duration.as_millis()),
+            Err(err) => {
+                log_error!(
+                    "Failed to convert the modification time of the file `{:?}': {:?}",
+                    path,
+                    err
+                );
+                Err(ErrorType::Unknown)
+            }
+        }
+    }
+
     /// Load a file into a vector of bytes
     fn read_to_bytes(path: &std::path::Path) -> Result<Vec<u8>, ErrorType> {
         // Default implementation
@@ -69,4 +209,21 @@ pub trait PlatformLayer {
             }
         }
     }
+
+    /// Write bytes into a file, creating the file and its missing directories
+    /// Warning: it is used by the panic hook,
+    /// logging from here could deadlock so the errors are only returned
+    fn write_to_file(path: &std::path::Path, content: &[u8]) -> Result<(), ErrorType> {
+        // Default implementation
+        // TODO: add implementation to the platform layer
+        if let Some(directory) = path.parent()
+            && std::fs::create_dir_all(directory).is_err()
+        {
+            return Err(ErrorType::IO);
+        }
+        match std::fs::write(path, content) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(ErrorType::IO),
+        }
+    }
 }
@@ -0,0 +1,9 @@
+#[allow(private_interfaces, private_bounds)]
+/// Implements the virtual window
+pub(crate) mod window;
+
+#[allow(private_interfaces, private_bounds)]
+/// Implements the headless platform layer
+pub(crate) mod platform;
+
+pub use platform::HeadlessPlatformLayer as PlatformLayerImpl;
@@ -0,0 +1,239 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use crate::{
+    PlatformLayer,
+    config::Config,
+    platform_layer::{
+        event::Event,
+        window::{MAIN_WINDOW_ID, Window},
+    },
+};
+
+use super::window::HeadlessWindow;
+
+/// The platform structure without any window system
+/// Everything is rendered offscreen and the events come from the virtual windows
+pub struct HeadlessPlatformLayer {
+    /// The opened windows with their ids, starting with the main one
+    pub(crate) windows: Vec<(u8, HeadlessWindow)>,
+    /// The index of the window to poll the next event from
+    next_polled_window: usize,
+}
+
+impl HeadlessPlatformLayer {
+    /// Gets the index of a window from its id
+    fn get_window_index(&self, window_id: u8) -> Result<usize, ErrorType> {
+        match self.windows.iter().position(|(id, _)| *id == window_id) {
+            Some(index) => Ok(index),
+            None => {
+                log_error!(
+                    "The headless window `{:?}' doesn't exist in the platform layer",
+                    window_id
+                );
+                Err(ErrorType::DoesNotExist)
+            }
+        }
+    }
+}
+
+impl PlatformLayer for HeadlessPlatformLayer {
+    type PlatformLayerType = HeadlessPlatformLayer;
+
+    fn init(config: &Config) -> Result<Self::PlatformLayerType, ErrorType> {
+        let window = match HeadlessWindow::init(config) {
+            Ok(window) => window,
+            Err(err) => {
+                log_error!("Failed to init the headless window: {:?}", err);
+                return Err(ErrorType::Unknown);
+            }
+        };
+        Ok(HeadlessPlatformLayer {
+            windows: vec![(MAIN_WINDOW_ID, window)],
+            next_polled_window: 0,
+        })
+    }
+
+    fn shutdown(&mut self) -> Result<(), ErrorType> {
+        // The secondary windows are shut down before the main one
+        while let Some((_, mut window)) = self.windows.pop() {
+            window.shutdown()?;
+        }
+        log_info!("Platform layer shutted down");
+        Ok(())
+    }
+
+    fn get_window(&mut self, window_id: u8) -> Result<&mut impl Window, ErrorType> {
+        let index = self.get_window_index(window_id)?;
+        Ok(&mut self.windows[index].1)
+    }
+
+    fn get_window_ref(&self, window_id: u8) -> Result<&impl Window, ErrorType> {
+        let index = self.get_window_index(window_id)?;
+        Ok(&self.windows[index].1)
+    }
+
+    fn create_window(&mut self, window_id: u8, config: &Config) -> Result<(), ErrorType> {
+        if self.windows.iter().any(|(id, _)| *id == window_id) {
+            log_error!(
+                "Can't create the headless window `{:?}', the id is already used",
+                window_id
+            );
+            return Err(ErrorType::Duplicate);
+        }
+        // Only the main window paces the frames and replays the script
+        let mut window_config = config.clone();
+        window_config.headless_config.frame_period = 0;
+        window_config.headless_config.max_frames = 0;
+        window_config.headless_config.script.clear();
+        let window = match HeadlessWindow::init(&window_config) {
+            Ok(window) => window,
+            Err(err) => {
+                log_error!(
+                    "Failed to init the headless window `{:?}': {:?}",
+                    window_id,
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        self.windows.push((window_id, window));
+        log_info!("Headless window `{:?}' created", window_id);
+        Ok(())
+    }
+
+    fn destroy_window(&mut self, window_id: u8) -> Result<(), ErrorType> {
+        if window_id == MAIN_WINDOW_ID {
+            log_error!("Can't destroy the main headless window");
+            return Err(ErrorType::BadRequest);
+        }
+        let index = self.get_window_index(window_id)?;
+        let (_, mut window) = self.windows.remove(index);
+        if index < self.next_polled_window {
+            self.next_polled_window -= 1;
+        }
+        if let Err(err) = window.shutdown() {
+            log_error!(
+                "Failed to shutdown the headless window `{:?}': {:?}",
+                window_id,
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+        log_info!("Headless window `{:?}' destroyed", window_id);
+        Ok(())
+    }
+
+    fn get_window_ids(&self) -> Vec<u8> {
+        self.windows.iter().map(|(id, _)| *id).collect()
+    }
+
+    fn poll_event(&mut self) -> Result<Option<(u8, Event)>, ErrorType> {
+        // Each window is drained in turn, None is returned once they all are
+        while let Some((window_id, window)) = self.windows.get_mut(self.next_polled_window) {
+            match window.poll_event() {
+                Ok(Some(event)) => return Ok(Some((*window_id, event))),
+                Ok(None) => self.next_polled_window += 1,
+                Err(err) => {
+                    log_error!(
+                        "Failed to poll an event of the window `{:?}' from the headless platform layer: {:?}",
+                        window_id,
+                        err
+                    );
+                    return Err(ErrorType::Unknown);
+                }
+            }
+        }
+        self.next_polled_window = 0;
+        Ok(None)
+    }
+
+    fn get_time_since_unix_epoch() -> Result<u128, ErrorType> {
+        let start = std::time::SystemTime::now();
+        match start.duration_since(std::time::UNIX_EPOCH) {
+            Err(err) => {
+                log_error!("Failed to get the time {:?}", err);
+                Err(ErrorType::Unknown)
+            }
+            Ok(duration) => Ok(duration.as_millis()),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////  headless platform tests /////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        config::ConfigBuilder,
+        keyboard::{KeyboardKey, KeyboardModifiers},
+        maths::Vector2f32,
+    };
+
+    #[test]
+    fn multiple_windows() {
+        let key_pressed = Event::KeyboardKeyPressed(
+            KeyboardKey::Unrecognized,
+            KeyboardKey::Unrecognized,
+            0,
+            KeyboardModifiers::default(),
+        );
+        let config = ConfigBuilder::default()
+            .headless_frame_period(0)
+            .headless_event(0, key_pressed)
+            .build()
+            .unwrap();
+        let mut platform = HeadlessPlatformLayer::init(&config).unwrap();
+        platform.create_window(1, &config).unwrap();
+        assert!(matches!(
+            platform.create_window(1, &config),
+            Err(ErrorType::Duplicate)
+        ));
+        assert_eq!(platform.get_window_ids(), vec![0, 1]);
+
+        // The events are tagged with their window and only the main one is scripted
+        platform
+            .get_window(1)
+            .unwrap()
+            .set_geometry(Vector2f32::ZEROS, 0.5, 0.5)
+            .unwrap();
+        assert_eq!(
+            platform.poll_events().unwrap(),
+            vec![(0, key_pressed), (1, Event::WindowResized(0.5, 0.5)),]
+        );
+        assert_eq!(platform.poll_events().unwrap(), vec![]);
+
+        assert!(matches!(
+            platform.destroy_window(0),
+            Err(ErrorType::BadRequest)
+        ));
+        platform.destroy_window(1).unwrap();
+        assert!(matches!(
+            platform.destroy_window(1),
+            Err(ErrorType::DoesNotExist)
+        ));
+        assert_eq!(platform.get_window_ids(), vec![0]);
+        platform.shutdown().unwrap();
+    }
+
+    #[test]
+    fn closed_after_max_frames() {
+        let config = ConfigBuilder::default()
+            .headless_frame_period(0)
+            .headless_max_frames(1)
+            .build()
+            .unwrap();
+        let mut platform = HeadlessPlatformLayer::init(&config).unwrap();
+        assert_eq!(platform.poll_events().unwrap(), vec![]);
+        // The closing event is sent once and the drain still ends
+        assert_eq!(
+            platform.poll_events().unwrap(),
+            vec![(MAIN_WINDOW_ID, Event::WindowClosed)]
+        );
+        assert_eq!(platform.poll_events().unwrap(), vec![]);
+        platform.shutdown().unwrap();
+    }
+}
@@ -0,0 +1,446 @@
+#[allow(unused)]
+use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::platform_layer::{
+    event::Event,
+    window::{DisplayMode, Window, WindowCommonProperties},
+};
+#[cfg(vulkan_renderer)]
+use crate::rendering_layer::rendering_impl::types::extensions::VkInstanceExtensions;
+
+use crate::{
+    config::Config,
+    maths::Vector2f32,
+    mouse::{CursorGrabMode, CursorImage},
+};
+
+/// A virtual window without any window system
+/// It starts a frame every `frame_period` and replays the scripted events of each frame
+pub(crate) struct HeadlessWindow {
+    /// Common window properties
+    pub(crate) properties: WindowCommonProperties,
+    /// The window's title
+    title: String,
+    /// The virtual screen's width in pixels
+    screen_width: u16,
+    /// The virtual screen's height in pixels
+    screen_height: u16,
+    /// The display mode to go back to when the window is restored
+    restored_display_mode: DisplayMode,
+    /// The position, width and height to go back to when leaving the fullscreen mode
+    floating_geometry: (Vector2f32, f32, f32),
+    /// Whether the window would have decorations
+    has_decorations: bool,
+    /// The window's border width in pixels
+    border_width: u16,
+    /// Whether the cursor would be visible over the window
+    is_cursor_visible: bool,
+    /// How the cursor would be held by the window
+    cursor_grab_mode: CursorGrabMode,
+    /// The custom cursor image, None for the default one
+    cursor_image: Option<CursorImage>,
+
+    /// The time between two frames
+    frame_period: Duration,
+    /// When the next frame should start
+    next_frame_time: Instant,
+    /// Whether the events of the current frame are being polled
+    is_frame_started: bool,
+    /// The number of ended frames
+    nb_frames: u64,
+    /// The number of frames before closing the window, 0 to never close it
+    max_frames: u64,
+    /// Whether the closing event was sent after `max_frames`
+    is_closed: bool,
+
+    /// The scripted events sorted by the frame they are sent at
+    script: VecDeque<(u64, Event)>,
+    /// The events raised by the window itself, sent before the scripted ones
+    pending_events: VecDeque<Event>,
+}
+
+impl HeadlessWindow {
+    /// Updates the window's properties from an event before sending it
+    fn apply_event(&mut self, event: Event) -> Event {
+        match event {
+            Event::WindowResized(width, height) => {
+                self.properties.width = width;
+                self.properties.height = height;
+            }
+            Event::WindowMinimized if self.properties.display_mode != DisplayMode::Minimized => {
+                self.restored_display_mode = self.properties.display_mode;
+                self.properties.display_mode = DisplayMode::Minimized;
+            }
+            Event::WindowRestored if self.properties.display_mode == DisplayMode::Minimized => {
+                self.properties.display_mode = self.restored_display_mode;
+            }
+            _ => {}
+        }
+        event
+    }
+
+    /// Accessor to the window's title
+    pub(crate) fn get_title(&self) -> &str {
+        &self.title
+    }
+}
+
+impl Window for HeadlessWindow {
+    type WindowType = HeadlessWindow;
+
+    fn init(config: &Config) -> Result<Self::WindowType, ErrorType> {
+        let window_config = &config.window_config;
+        let headless_config = &config.headless_config;
+        if headless_config.screen_width == 0 || headless_config.screen_height == 0 {
+            log_error!(
+                "Can't initialize the headless window with an empty {:?}x{:?} virtual screen",
+                headless_config.screen_width,
+                headless_config.screen_height
+            );
+            return Err(ErrorType::WrongArgument(String::from(
+                "The virtual screen's size must not be 0",
+            )));
+        }
+
+        log_info!(
+            "Headless window `{}' initialized on a {:?}x{:?} virtual screen",
+            window_config.title,
+            headless_config.screen_width,
+            headless_config.screen_height
+        );
+        Ok(HeadlessWindow {
+            properties: WindowCommonProperties {
+                position: window_config.position,
+                width: window_config.width,
+                height: window_config.height,
+                display_mode: DisplayMode::Floating(None),
+            },
+            title: window_config.title.clone(),
+            screen_width: headless_config.screen_width,
+            screen_height: headless_config.screen_height,
+            restored_display_mode: DisplayMode::Floating(None),
+            floating_geometry: (
+                window_config.position,
+                window_config.width,
+                window_config.height,
+            ),
+            has_decorations: true,
+            border_width: window_config.border_width,
+            is_cursor_visible: true,
+            cursor_grab_mode: CursorGrabMode::None,
+            cursor_image: None,
+            frame_period: Duration::from_millis(headless_config.frame_period),
+            next_frame_time: Instant::now(),
+            is_frame_started: false,
+            nb_frames: 0,
+            max_frames: headless_config.max_frames,
+            is_closed: false,
+            script: headless_config.script.iter().copied().collect(),
+            pending_events: VecDeque::new(),
+        })
+    }
+
+    fn shutdown(&mut self) -> Result<(), ErrorType> {
+        log_info!(
+            "Headless window shutted down after {:?} frame(s)",
+            self.nb_frames
+        );
+        Ok(())
+    }
+
+    fn get_properties(&self) -> WindowCommonProperties {
+        self.properties
+    }
+
+    fn poll_event(&mut self) -> Result<Option<Event>, ErrorType> {
+        if !self.is_frame_started {
+            // Waits for the next frame
+            let now = Instant::now();
+            if self.next_frame_time > now {
+                std::thread::sleep(self.next_frame_time - now);
+            }
+            self.next_frame_time = self.next_frame_time.max(now) + self.frame_period;
+            self.is_frame_started = true;
+        }
+
+        if let Some(event) = self.pending_events.pop_front() {
+            return Ok(Some(self.apply_event(event)));
+        }
+        if let Some((frame, _)) = self.script.front()
+            && *frame <= self.nb_frames
+            && let Some((_, event)) = self.script.pop_front()
+        {
+            return Ok(Some(self.apply_event(event)));
+        }
+        if self.max_frames > 0 && self.nb_frames >= self.max_frames && !self.is_closed {
+            // The window is closed only once, the next frames are empty
+            self.is_closed = true;
+            return Ok(Some(Event::WindowClosed));
+        }
+
+        // All the events of the frame were polled
+        self.is_frame_started = false;
+        self.nb_frames += 1;
+        Ok(None)
+    }
+
+    fn set_title(&mut self, title: &str) -> Result<(), ErrorType> {
+        self.title = String::from(title);
+        Ok(())
+    }
+
+    fn set_geometry(
+        &mut self,
+        position: Vector2f32,
+        width: f32,
+        height: f32,
+    ) -> Result<(), ErrorType> {
+        self.properties.position = position;
+        // The size is updated when sending the resize event, as with a real window system
+        if width != self.properties.width || height != self.properties.height {
+            self.pending_events
+                .push_back(Event::WindowResized(width, height));
+        }
+        Ok(())
+    }
+
+    fn set_display_mode(&mut self, display_mode: DisplayMode) -> Result<(), ErrorType> {
+        // The minimized state is updated when sending the corresponding events
+        let is_minimized = self.properties.display_mode == DisplayMode::Minimized;
+        if display_mode == DisplayMode::Minimized {
+            if !is_minimized {
+                self.pending_events.push_back(Event::WindowMinimized);
+            }
+            return Ok(());
+        }
+        let previous_mode = if is_minimized {
+            self.pending_events.push_back(Event::WindowRestored);
+            self.restored_display_mode
+        } else {
+            self.properties.display_mode
+        };
+        self.restored_display_mode = display_mode;
+        if !is_minimized {
+            self.properties.display_mode = display_mode;
+        }
+
+        // The fullscreen window covers the whole virtual screen
+        match display_mode {
+            DisplayMode::Fullscreen => {
+                if previous_mode != DisplayMode::Fullscreen {
+                    self.floating_geometry = (
+                        self.properties.position,
+                        self.properties.width,
+                        self.properties.height,
+                    );
+                }
+                self.set_geometry(Vector2f32::ZEROS, 1., 1.)
+            }
+            DisplayMode::Floating(Some((x, y, width, height))) => {
+                self.set_geometry(Vector2f32::new(x, y), width, height)
+            }
+            DisplayMode::Floating(None) if previous_mode == DisplayMode::Fullscreen => {
+                let (position, width, height) = self.floating_geometry;
+                self.set_geometry(position, width, height)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn set_decorations(&mut self, has_decorations: bool) -> Result<(), ErrorType> {
+        self.has_decorations = has_decorations;
+        Ok(())
+    }
+
+    fn set_border_width(&mut self, border_width: u16) -> Result<(), ErrorType> {
+        self.border_width = border_width;
+        Ok(())
+    }
+
+    fn set_cursor_visible(&mut self, is_visible: bool) -> Result<(), ErrorType> {
+        self.is_cursor_visible = is_visible;
+        Ok(())
+    }
+
+    fn set_cursor_grab(&mut self, grab_mode: CursorGrabMode) -> Result<(), ErrorType> {
+        self.cursor_grab_mode = grab_mode;
+        Ok(())
+    }
+
+    fn set_cursor_image(&mut self, image: Option<&CursorImage>) -> Result<(), ErrorType> {
+        if let Some(image) = image {
+            image.check()?;
+        }
+        self.cursor_image = image.cloned();
+        Ok(())
+    }
+
+    fn get_framebuffer_width(&self) -> u16 {
+        (self.properties.width * (self.screen_width as f32)) as u16
+    }
+
+    fn get_framebuffer_height(&self) -> u16 {
+        (self.properties.height * (self.screen_height as f32)) as u16
+    }
+
+    #[cfg(opengl_renderer)]
+    fn opengl_swap_buffers(&mut self) -> Result<(), ErrorType> {
+        log_error!("OpenGL is not supported by the headless platform");
+        Err(ErrorType::NotSupported)
+    }
+
+    #[cfg(opengl_renderer)]
+    fn opengl_make_context_current(&mut self) -> Result<(), ErrorType> {
+        log_error!("OpenGL is not supported by the headless platform");
+        Err(ErrorType::NotSupported)
+    }
+
+    #[cfg(opengl_renderer)]
+    fn opengl_load_functions(&mut self) -> Result<(), ErrorType> {
+        log_error!("OpenGL is not supported by the headless platform");
+        Err(ErrorType::NotSupported)
+    }
+
+    #[cfg(vulkan_renderer)]
+    fn vulkan_get_required_instance_extensions(
+        &self,
+    ) -> Result<Vec<VkInstanceExtensions>, ErrorType> {
+        let required_extensions = vec![
+            VkInstanceExtensions::KhrSurface,
+            VkInstanceExtensions::ExtHeadlessSurface,
+        ];
+        Ok(required_extensions)
+    }
+
+    #[cfg(vulkan_renderer)]
+    fn vulkan_get_surface(
+        &self,
+        vk_entry: &ash::Entry,
+        vk_instance: &ash::Instance,
+        allocator: Option<&ash::vk::AllocationCallbacks<'_>>,
+    ) -> Result<ash::vk::SurfaceKHR, ErrorType> {
+        let create_info = ash::vk::HeadlessSurfaceCreateInfoEXT::default();
+        let surface_instance = ash::ext::headless_surface::Instance::new(vk_entry, vk_instance);
+        match unsafe { surface_instance.create_headless_surface(&create_info, allocator) } {
+            Ok(surface) => Ok(surface),
+            Err(err) => {
+                log_error!(
+                    "Failed to create the headless surface when trying to get the Vulkan Surface on the headless platform: {:?}",
+                    err
+                );
+                Err(ErrorType::VulkanError)
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////  headless window tests ///////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        config::ConfigBuilder,
+        keyboard::{AlphaNumeric, KeyboardKey, KeyboardModifiers},
+    };
+
+    #[test]
+    fn scripted_events() {
+        // The 'W' physical key of an AZERTY layout
+        let key_pressed = Event::KeyboardKeyPressed(
+            KeyboardKey::AlphaNumeric(AlphaNumeric::Z),
+            KeyboardKey::AlphaNumeric(AlphaNumeric::W),
+            17,
+            KeyboardModifiers::default(),
+        );
+        let config = ConfigBuilder::default()
+            .window_size(0.5, 0.25)
+            .headless_screen_size(800, 600)
+            .headless_frame_period(0)
+            .headless_max_frames(2)
+            .headless_event(1, key_pressed)
+            .headless_event(0, Event::WindowFocused)
+            .build()
+            .unwrap();
+        let mut window = HeadlessWindow::init(&config).unwrap();
+        assert_eq!(window.get_framebuffer_width(), 400);
+        assert_eq!(window.get_framebuffer_height(), 150);
+
+        let events: Vec<Option<Event>> = (0..7).map(|_| window.poll_event().unwrap()).collect();
+        assert_eq!(
+            events,
+            vec![
+                Some(Event::WindowFocused),
+                None,
+                Some(key_pressed),
+                None,
+                Some(Event::WindowClosed),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn resize() {
+        let config = ConfigBuilder::default()
+            .headless_screen_size(100, 100)
+            .headless_frame_period(0)
+            .build()
+            .unwrap();
+        let mut window = HeadlessWindow::init(&config).unwrap();
+        window
+            .set_geometry(Vector2f32::new(0.5, 0.5), 0.5, 0.5)
+            .unwrap();
+        assert_eq!(window.get_framebuffer_width(), 100);
+        assert_eq!(
+            window.poll_event().unwrap(),
+            Some(Event::WindowResized(0.5, 0.5))
+        );
+        assert_eq!(window.get_framebuffer_width(), 50);
+        assert_eq!(window.poll_event().unwrap(), None);
+    }
+
+    #[test]
+    fn display_mode() {
+        let config = ConfigBuilder::default()
+            .window_size(0.5, 0.5)
+            .headless_frame_period(0)
+            .build()
+            .unwrap();
+        let mut window = HeadlessWindow::init(&config).unwrap();
+        window.set_display_mode(DisplayMode::Fullscreen).unwrap();
+        assert_eq!(
+            window.poll_event().unwrap(),
+            Some(Event::WindowResized(1., 1.))
+        );
+        assert_eq!(
+            window.get_properties().display_mode,
+            DisplayMode::Fullscreen
+        );
+
+        // The window is restored to its previous mode
+        window.set_display_mode(DisplayMode::Minimized).unwrap();
+        assert_eq!(window.poll_event().unwrap(), Some(Event::WindowMinimized));
+        window
+            .set_display_mode(DisplayMode::Floating(None))
+            .unwrap();
+        assert_eq!(window.poll_event().unwrap(), Some(Event::WindowRestored));
+        assert_eq!(
+            window.poll_event().unwrap(),
+            Some(Event::WindowResized(0.5, 0.5))
+        );
+        assert_eq!(
+            window.get_properties().display_mode,
+            DisplayMode::Floating(None)
+        );
+        assert_eq!(window.poll_event().unwrap(), None);
+    }
+}
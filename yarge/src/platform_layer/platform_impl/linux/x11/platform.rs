@@ -2,18 +2,38 @@
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
 use crate::{
-    LogLevel, LogTarget, PlatformLayer,
+    PlatformLayer,
     config::Config,
-    platform_layer::{event::Event, window::Window},
+    platform_layer::{
+        event::Event,
+        window::{MAIN_WINDOW_ID, Window},
+    },
 };
 
-use colored::Colorize;
-
 use super::window::LinuxX11Window;
 
 /// The platform structure for Linux X11
 pub struct LinuxX11PlatformLayer {
-    pub(crate) window: LinuxX11Window,
+    /// The opened windows with their ids, starting with the main one
+    pub(crate) windows: Vec<(u8, LinuxX11Window)>,
+    /// The index of the window to poll the next event from
+    next_polled_window: usize,
+}
+
+impl LinuxX11PlatformLayer {
+    /// Gets the index of a window from its id
+    fn get_window_index(&self, window_id: u8) -> Result<usize, ErrorType> {
+        match self.windows.iter().position(|(id, _)| *id == window_id) {
+            Some(index) => Ok(index),
+            None => {
+                log_error!(
+                    "The X11 linux window `{:?}' doesn't exist in the platform layer",
+                    window_id
+                );
+                Err(ErrorType::DoesNotExist)
+            }
+        }
+    }
 }
 
 impl PlatformLayer for LinuxX11PlatformLayer {
@@ -27,89 +47,109 @@ impl PlatformLayer for LinuxX11PlatformLayer {
                 return Err(ErrorType::Unknown);
             }
         };
-        Ok(LinuxX11PlatformLayer { window })
+        Ok(LinuxX11PlatformLayer {
+            windows: vec![(MAIN_WINDOW_ID, window)],
+            next_polled_window: 0,
+        })
     }
 
     fn shutdown(&mut self) -> Result<(), ErrorType> {
-        self.window.shutdown()?;
+        // The secondary windows are shut down before the main one
+        while let Some((_, mut window)) = self.windows.pop() {
+            window.shutdown()?;
+        }
         log_info!("Platform layer shutted down");
         Ok(())
     }
 
-    fn get_window(&mut self, _window_id: u8) -> &mut impl Window {
-        &mut self.window
+    fn get_window(&mut self, window_id: u8) -> Result<&mut impl Window, ErrorType> {
+        let index = self.get_window_index(window_id)?;
+        Ok(&mut self.windows[index].1)
     }
 
-    fn get_window_ref(&self, _window_id: u8) -> &impl Window {
-        &self.window
+    fn get_window_ref(&self, window_id: u8) -> Result<&impl Window, ErrorType> {
+        let index = self.get_window_index(window_id)?;
+        Ok(&self.windows[index].1)
     }
 
-    fn poll_event(&mut self) -> Result<Event, ErrorType> {
-        match self.window.poll_event() {
-            Ok(event) => Ok(event),
+    fn create_window(&mut self, window_id: u8, config: &Config) -> Result<(), ErrorType> {
+        if self.windows.iter().any(|(id, _)| *id == window_id) {
+            log_error!(
+                "Can't create the X11 linux window `{:?}', the id is already used",
+                window_id
+            );
+            return Err(ErrorType::Duplicate);
+        }
+        let window = match LinuxX11Window::init(config) {
+            Ok(window) => window,
             Err(err) => {
                 log_error!(
-                    "Failed to poll an event from the X11 linux platform layer: {:?}",
+                    "Failed to init the X11 linux window `{:?}': {:?}",
+                    window_id,
                     err
                 );
-                Err(ErrorType::Unknown)
-            }
-        }
-    }
-
-    fn get_time_since_unix_epoch() -> Result<u128, ErrorType> {
-        let start = std::time::SystemTime::now();
-        match start.duration_since(std::time::UNIX_EPOCH) {
-            Err(err) => {
-                log_error!("Failed to get the linux time {:?}", err);
-                Err(ErrorType::Unknown)
-            }
-            Ok(duration) => Ok(duration.as_millis()),
-        }
-    }
-
-    fn write_log(level: &LogLevel, message: &str, target: &LogTarget) -> Result<(), ErrorType> {
-        match target {
-            LogTarget::Console => {
-                println!("[{}]: {}", Self::format_level(level), message);
-            }
-            LogTarget::ErrorConsole => {
-                eprintln!("[{:?}]: {:?}", Self::format_level(level), message);
+                return Err(ErrorType::Unknown);
             }
         };
+        self.windows.push((window_id, window));
+        log_info!("X11 linux window `{:?}' created", window_id);
         Ok(())
     }
 
-    fn flush_log() -> Result<(), ErrorType> {
-        use std::io::Write;
-        if let Err(err) = std::io::stdout().flush() {
-            log_error!(
-                "Failed to flush the stdout in the Linux X11 Platform: {:?}",
-                err
-            );
-            eprintln!("failure 1");
-            return Err(ErrorType::Unknown);
+    fn destroy_window(&mut self, window_id: u8) -> Result<(), ErrorType> {
+        if window_id == MAIN_WINDOW_ID {
+            log_error!("Can't destroy the main X11 linux window");
+            return Err(ErrorType::BadRequest);
+        }
+        let index = self.get_window_index(window_id)?;
+        let (_, mut window) = self.windows.remove(index);
+        if index < self.next_polled_window {
+            self.next_polled_window -= 1;
         }
-        if let Err(err) = std::io::stderr().flush() {
+        if let Err(err) = window.shutdown() {
             log_error!(
-                "Failed to flush the stderr in the Linux X11 Platform: {:?}",
+                "Failed to shutdown the X11 linux window `{:?}': {:?}",
+                window_id,
                 err
             );
-            eprintln!("failure 2");
             return Err(ErrorType::Unknown);
         }
+        log_info!("X11 linux window `{:?}' destroyed", window_id);
         Ok(())
     }
-}
 
-impl LinuxX11PlatformLayer {
-    /// Get the correct ANSI color given the logging level
-    fn format_level(level: &LogLevel) -> String {
-        match level {
-            LogLevel::Info => "Info".green().to_string(),
-            LogLevel::Debug => "Debug".yellow().to_string(),
-            LogLevel::Warn => "Warn".truecolor(255, 165, 0).to_string(),
-            LogLevel::Error => "Error".red().to_string(),
+    fn get_window_ids(&self) -> Vec<u8> {
+        self.windows.iter().map(|(id, _)| *id).collect()
+    }
+
+    fn poll_event(&mut self) -> Result<Option<(u8, Event)>, ErrorType> {
+        // Each window is drained in turn, None is returned once they all are
+        while let Some((window_id, window)) = self.windows.get_mut(self.next_polled_window) {
+            match window.poll_event() {
+                Ok(Some(event)) => return Ok(Some((*window_id, event))),
+                Ok(None) => self.next_polled_window += 1,
+                Err(err) => {
+                    log_error!(
+                        "Failed to poll an event of the window `{:?}' from the X11 linux platform layer: {:?}",
+                        window_id,
+                        err
+                    );
+                    return Err(ErrorType::Unknown);
+                }
+            }
+        }
+        self.next_polled_window = 0;
+        Ok(None)
+    }
+
+    fn get_time_since_unix_epoch() -> Result<u128, ErrorType> {
+        let start = std::time::SystemTime::now();
+        match start.duration_since(std::time::UNIX_EPOCH) {
+            Err(err) => {
+                log_error!("Failed to get the linux time {:?}", err);
+                Err(ErrorType::Unknown)
+            }
+            Ok(duration) => Ok(duration.as_millis()),
         }
     }
 }
@@ -1,7 +1,7 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 use crate::platform_layer::{
     event::Event,
@@ -12,9 +12,28 @@ use crate::rendering_layer::rendering_impl::types::extensions::VkInstanceExtensi
 #[cfg(opengl_renderer)]
 use crate::rendering_layer::types::formats::ImageFormat;
 
-use crate::{config::Config, keyboard::KeyboardKey};
+use crate::{
+    config::Config,
+    keyboard::{
+        AlphaNumeric, Arrow, Function, KeyboardKey, KeyboardModifiers, Media, Modifier, Navigation,
+        Numpad, Punctuation, Special,
+    },
+    maths::Vector2f32,
+    mouse::{CursorGrabMode, CursorImage, MouseButton},
+};
+
+use xcb::{Xid, render, x, xinput};
 
-use xcb::{Xid, x};
+/// The `_NET_WM_STATE' action removing a state
+const EWMH_STATE_REMOVE: u32 = 0;
+/// The `_NET_WM_STATE' action adding a state
+const EWMH_STATE_ADD: u32 = 1;
+/// Tells the window manager that the request comes from a normal application
+const EWMH_SOURCE_APPLICATION: u32 = 1;
+/// The `WM_CHANGE_STATE' state of a minimized window
+const ICCCM_ICONIC_STATE: u32 = 3;
+/// The `_MOTIF_WM_HINTS' flag telling that the decorations field is set
+const MOTIF_HINTS_DECORATIONS: u32 = 1 << 1;
 
 /// Handled atoms
 pub(crate) struct LinuxX11Atoms {
@@ -31,6 +50,16 @@ pub(crate) struct LinuxX11Atoms {
     pub(crate) state_maximized_horz: x::Atom,
     /// Window minimized
     pub(crate) state_hidden: x::Atom,
+    /// Window covering the whole screen without decorations
+    pub(crate) state_fullscreen: x::Atom,
+    /// Asks the window manager to change the window's state, used to minimize it
+    pub(crate) change_state: x::Atom,
+    /// Motif hints, used to toggle the window's decorations
+    pub(crate) motif_wm_hints: x::Atom,
+    /// The window's title encoded in UTF-8
+    pub(crate) net_wm_name: x::Atom,
+    /// The UTF-8 string type
+    pub(crate) utf8_string: x::Atom,
 }
 
 /// Properties of the X11 screen the window is attached to
@@ -39,6 +68,8 @@ pub(crate) struct LinuxX11ScreenProperties {
     pub(crate) width: u16,
     /// The total screen height in pixels
     pub(crate) height: u16,
+    /// The root window, receiving the requests to the window manager
+    pub(crate) root: x::Window,
 }
 
 /// Properties to handle OpenGL with Linux X11
@@ -407,6 +438,283 @@ impl LinuxX11OpenglWindow {
     }
 }
 
+/// Properties to translate the key presses into text with the X input method
+/// The input method server chosen by `XMODIFIERS' is used when available,
+/// otherwise the local one handles the dead keys and compose sequences
+pub(crate) struct LinuxX11TextInput {
+    /// The xlib display
+    display: *mut x11::xlib::Display,
+
+    /// The input method, null if it couldn't be opened
+    input_method: x11::xlib::XIM,
+
+    /// The input context of the window, null without input method
+    input_context: x11::xlib::XIC,
+}
+
+impl LinuxX11TextInput {
+    /// Only checks the events already in the Xlib queue, without reading the connection
+    const XLIB_QUEUED_ALREADY: std::os::raw::c_int = 0;
+
+    /// Opens the input method and creates the input context of the window
+    /// The text falls back to Latin-1 without input method
+    /// The compose sequences follow the `LC_CTYPE' locale, which is only set from the environment
+    /// when `use_system_locale' is enabled, otherwise it is left to the application
+    /// The Xlib event queue must be owned by Xlib, the input method servers answer through it
+    pub(crate) fn init(
+        display: *mut x11::xlib::Display,
+        window: x::Window,
+        use_system_locale: bool,
+    ) -> Self {
+        let mut text_input = Self {
+            display,
+            input_method: std::ptr::null_mut(),
+            input_context: std::ptr::null_mut(),
+        };
+        if use_system_locale && unsafe { libc::setlocale(libc::LC_CTYPE, c"".as_ptr()) }.is_null() {
+            log_warn!(
+                "Failed to set the locale from the environment for the X11 linux input method"
+            );
+        }
+        if unsafe { x11::xlib::XSupportsLocale() } == 0 {
+            log_warn!("The X11 linux input method doesn't support the current locale");
+            return text_input;
+        }
+
+        // The input method server set in `XMODIFIERS' is tried first,
+        // the local one handling the dead keys and the compose sequences is the fallback
+        for modifiers in [c"", c"@im=none"] {
+            unsafe {
+                let _ = x11::xlib::XSetLocaleModifiers(modifiers.as_ptr());
+                text_input.input_method = x11::xlib::XOpenIM(
+                    display,
+                    std::ptr::null_mut(),
+                    std::ptr::null_mut(),
+                    std::ptr::null_mut(),
+                );
+            }
+            if !text_input.input_method.is_null() {
+                break;
+            }
+        }
+        if text_input.input_method.is_null() {
+            log_warn!("Failed to open the X11 linux input method, the dead keys are ignored");
+            return text_input;
+        }
+
+        let window = window.resource_id() as std::os::raw::c_ulong;
+        text_input.input_context = unsafe {
+            x11::xlib::XCreateIC(
+                text_input.input_method,
+                x11::xlib::XNInputStyle_0.as_ptr(),
+                (x11::xlib::XIMPreeditNothing | x11::xlib::XIMStatusNothing)
+                    as std::os::raw::c_long,
+                x11::xlib::XNClientWindow_0.as_ptr(),
+                window,
+                x11::xlib::XNFocusWindow_0.as_ptr(),
+                window,
+                std::ptr::null_mut::<std::os::raw::c_void>(),
+            )
+        };
+        if text_input.input_context.is_null() {
+            log_warn!("Failed to create the X11 linux input context, the dead keys are ignored");
+            unsafe {
+                let _ = x11::xlib::XCloseIM(text_input.input_method);
+            }
+            text_input.input_method = std::ptr::null_mut();
+        }
+        text_input
+    }
+
+    /// Destroys the input context and closes the input method
+    pub(crate) fn shutdown(&mut self) {
+        if !self.input_context.is_null() {
+            unsafe { x11::xlib::XDestroyIC(self.input_context) };
+            self.input_context = std::ptr::null_mut();
+        }
+        if !self.input_method.is_null() {
+            unsafe {
+                let _ = x11::xlib::XCloseIM(self.input_method);
+            }
+            self.input_method = std::ptr::null_mut();
+        }
+    }
+
+    /// Tells the input method whether the window has the keyboard focus
+    pub(crate) fn set_focused(&self, is_focused: bool) {
+        if self.input_context.is_null() {
+            return;
+        }
+        if is_focused {
+            unsafe { x11::xlib::XSetICFocus(self.input_context) };
+        } else {
+            unsafe { x11::xlib::XUnsetICFocus(self.input_context) };
+        }
+    }
+
+    /// Tells Xlib that the keyboard mapping changed
+    /// The events are read through xcb so Xlib never refreshes its own keymap
+    pub(crate) fn refresh_keyboard_mapping(&self, event: &x::MappingNotifyEvent) {
+        let mut xevent = x11::xlib::XMappingEvent {
+            type_: x11::xlib::MappingNotify,
+            serial: 0,
+            send_event: 0,
+            display: self.display,
+            event: 0,
+            request: event.request() as std::os::raw::c_int,
+            first_keycode: event.first_keycode() as std::os::raw::c_int,
+            count: event.count() as std::os::raw::c_int,
+        };
+        unsafe {
+            let _ = x11::xlib::XRefreshKeyboardMapping(&mut xevent);
+        }
+    }
+
+    /// Gets the characters typed by a key press
+    /// A dead key gives no character, the composed ones are given by the key ending the sequence
+    pub(crate) fn get_text(&self, event: &x::KeyPressEvent, window: x::Window) -> Vec<char> {
+        let window = window.resource_id() as std::os::raw::c_ulong;
+        let mut xevent = x11::xlib::XEvent {
+            key: x11::xlib::XKeyEvent {
+                type_: x11::xlib::KeyPress,
+                serial: 0,
+                send_event: 0,
+                display: self.display,
+                window,
+                root: event.root().resource_id() as std::os::raw::c_ulong,
+                subwindow: event.child().resource_id() as std::os::raw::c_ulong,
+                time: event.time() as std::os::raw::c_ulong,
+                x: event.event_x() as std::os::raw::c_int,
+                y: event.event_y() as std::os::raw::c_int,
+                x_root: event.root_x() as std::os::raw::c_int,
+                y_root: event.root_y() as std::os::raw::c_int,
+                state: event.state().bits(),
+                keycode: event.detail() as std::os::raw::c_uint,
+                same_screen: event.same_screen() as std::os::raw::c_int,
+            },
+        };
+        if self.input_context.is_null() {
+            return self.lookup_text(&mut xevent);
+        }
+        if unsafe { x11::xlib::XFilterEvent(&mut xevent, window) } == 0 {
+            return self.lookup_text(&mut xevent);
+        }
+
+        self.read_queued_text()
+    }
+
+    /// Passes a message sent to the input method's own windows, such as a text committed by an
+    /// input method server, and gets the characters it typed
+    pub(crate) fn filter_client_message(&self, event: &x::ClientMessageEvent) -> Vec<char> {
+        if self.input_context.is_null() {
+            return Vec::new();
+        }
+        let mut client_message = x11::xlib::XClientMessageEvent {
+            type_: x11::xlib::ClientMessage,
+            serial: 0,
+            send_event: 1,
+            display: self.display,
+            window: event.window().resource_id() as std::os::raw::c_ulong,
+            message_type: event.r#type().resource_id() as std::os::raw::c_ulong,
+            format: event.format() as std::os::raw::c_int,
+            data: x11::xlib::ClientMessageData::new(),
+        };
+        match event.data() {
+            x::ClientMessageData::Data8(data) => {
+                for (i, byte) in data.into_iter().enumerate() {
+                    client_message
+                        .data
+                        .set_byte(i, byte as std::os::raw::c_char);
+                }
+            }
+            x::ClientMessageData::Data16(data) => {
+                for (i, short) in data.into_iter().enumerate() {
+                    client_message
+                        .data
+                        .set_short(i, short as std::os::raw::c_short);
+                }
+            }
+            x::ClientMessageData::Data32(data) => {
+                for (i, long) in data.into_iter().enumerate() {
+                    client_message
+                        .data
+                        .set_long(i, long as std::os::raw::c_long);
+                }
+            }
+        }
+        let mut xevent = x11::xlib::XEvent { client_message };
+        if unsafe { x11::xlib::XFilterEvent(&mut xevent, 0) } == 0 {
+            return Vec::new();
+        }
+        self.read_queued_text()
+    }
+
+    /// Reads the characters of the key presses put back in the Xlib queue by the input method
+    fn read_queued_text(&self) -> Vec<char> {
+        let mut text = Vec::new();
+        while unsafe { x11::xlib::XEventsQueued(self.display, Self::XLIB_QUEUED_ALREADY) } > 0 {
+            let mut xevent = x11::xlib::XEvent { pad: [0; 24] };
+            unsafe {
+                let _ = x11::xlib::XNextEvent(self.display, &mut xevent);
+            }
+            if xevent.get_type() == x11::xlib::KeyPress
+                && unsafe { x11::xlib::XFilterEvent(&mut xevent, 0) } == 0
+            {
+                text.extend(self.lookup_text(&mut xevent));
+            }
+        }
+        text
+    }
+
+    /// Translates a key press into characters, ignoring the control ones
+    fn lookup_text(&self, xevent: &mut x11::xlib::XEvent) -> Vec<char> {
+        let key_event = unsafe { &mut xevent.key };
+        let mut buffer = vec![0u8; 32];
+        let mut keysym: std::os::raw::c_ulong = 0;
+
+        let text = if self.input_context.is_null() {
+            let length = unsafe {
+                x11::xlib::XLookupString(
+                    key_event,
+                    buffer.as_mut_ptr() as *mut std::os::raw::c_char,
+                    buffer.len() as std::os::raw::c_int,
+                    &mut keysym,
+                    std::ptr::null_mut(),
+                )
+            };
+            // Without input method, the text is encoded in Latin-1
+            buffer[..length.max(0) as usize]
+                .iter()
+                .map(|&byte| char::from(byte))
+                .collect::<String>()
+        } else {
+            let mut status: std::os::raw::c_int = 0;
+            let mut lookup = |buffer: &mut Vec<u8>, status: &mut std::os::raw::c_int| unsafe {
+                x11::xlib::Xutf8LookupString(
+                    self.input_context,
+                    key_event,
+                    buffer.as_mut_ptr() as *mut std::os::raw::c_char,
+                    buffer.len() as std::os::raw::c_int,
+                    &mut keysym,
+                    status,
+                )
+            };
+            let mut length = lookup(&mut buffer, &mut status);
+            if status == x11::xlib::XBufferOverflow {
+                buffer.resize(length.max(0) as usize, 0);
+                length = lookup(&mut buffer, &mut status);
+            }
+            if status != x11::xlib::XLookupChars && status != x11::xlib::XLookupBoth {
+                return Vec::new();
+            }
+            String::from_utf8_lossy(&buffer[..length.max(0) as usize]).into_owned()
+        };
+        text.chars()
+            .filter(|character| !character.is_control())
+            .collect()
+    }
+}
+
 /// The required elements to manage a window in Linux X11
 pub(crate) struct LinuxX11Window {
     /// Common window properties
@@ -424,6 +732,25 @@ pub(crate) struct LinuxX11Window {
     /// The xcb screen properties
     screen: LinuxX11ScreenProperties,
 
+    /// Whether the window has the keyboard focus
+    is_focused: bool,
+    /// Whether the X server can send the raw mouse motions
+    has_raw_motion: bool,
+    /// Whether the cursor is visible over the window
+    is_cursor_visible: bool,
+    /// How the cursor is held by the window
+    cursor_grab_mode: CursorGrabMode,
+    /// The custom cursor, `CURSOR_NONE' for the default one
+    custom_cursor: x::Cursor,
+    /// The invisible cursor shown when the cursor is hidden
+    hidden_cursor: x::Cursor,
+    /// The display mode to go back to when the window is restored
+    restored_display_mode: DisplayMode,
+    /// Translates the key presses into text
+    text_input: LinuxX11TextInput,
+    /// The events waiting to be polled, like the text typed by a key press
+    pending_events: VecDeque<Event>,
+
     #[cfg(opengl_renderer)]
     /// OpenGL specific window information
     opengl_window: LinuxX11OpenglWindow,
@@ -441,7 +768,14 @@ impl Window for LinuxX11Window {
         }
 
         // Connect to the X server
-        let connection = unsafe { xcb::Connection::from_xlib_display(display) };
+        // XInput 2 sends the raw mouse motions and XRender creates the colored cursors
+        let connection = unsafe {
+            xcb::Connection::from_xlib_display_and_extensions(
+                display,
+                &[],
+                &[xcb::Extension::Input, xcb::Extension::Render],
+            )
+        };
         if let Err(err) = connection.has_error() {
             log_error!(
                 "Failed to create an xcb connection when initializing the X11 linux window: {:?}",
@@ -452,6 +786,9 @@ impl Window for LinuxX11Window {
             };
             return Err(ErrorType::Unknown);
         }
+        // The input method reads the composed key presses back from the Xlib queue,
+        // the other events are only read through xcb
+        connection.set_event_queue_owner(xcb::EventQueueOwner::Xcb);
 
         let default_screen_number = match xcb::Connection::connect(None) {
             Ok((_, default_screen_number)) => default_screen_number,
@@ -531,7 +868,8 @@ impl Window for LinuxX11Window {
             | x::EventMask::ENTER_WINDOW
             | x::EventMask::LEAVE_WINDOW
             | x::EventMask::STRUCTURE_NOTIFY
-            | x::EventMask::FOCUS_CHANGE;
+            | x::EventMask::FOCUS_CHANGE
+            | x::EventMask::PROPERTY_CHANGE;
 
         // Warning, the list must be sorted in the same order as in
         // https://docs.rs/xcb/1.5.0/xcb/x/enum.Cw.html
@@ -567,28 +905,24 @@ impl Window for LinuxX11Window {
             return Err(ErrorType::Unknown);
         }
 
-        // Update window's title
-        let cookie = connection.send_request_checked(&x::ChangeProperty {
-            mode: x::PropMode::Replace,
-            window,
-            property: x::ATOM_WM_NAME,
-            r#type: x::ATOM_STRING,
-            data: config.window_config.title.as_bytes(),
-        });
-        if let Err(err) = connection.check_request(cookie) {
-            log_error!(
-                "Failed to update the window title when initializing the X11 linux window: {:?}",
-                err
-            );
-            return Err(ErrorType::Unknown);
-        };
-
         // Map the window
         let _cookie = connection.send_request(&x::MapWindow { window });
 
         // Get necessary atoms
         // An atom is an id replacement for a string
-        let (wm_protocols, wm_del_window, wm_state, wm_max_vert, wm_max_horz, wm_hidden) = {
+        let (
+            wm_protocols,
+            wm_del_window,
+            wm_state,
+            wm_max_vert,
+            wm_max_horz,
+            wm_hidden,
+            wm_fullscreen,
+            wm_change_state,
+            motif_wm_hints,
+            net_wm_name,
+            utf8_string,
+        ) = {
             let cookies = (
                 connection.send_request(&x::InternAtom {
                     only_if_exists: true,
@@ -608,12 +942,33 @@ impl Window for LinuxX11Window {
                 }),
                 connection.send_request(&x::InternAtom {
                     only_if_exists: true,
-                    name: b"_NET_WM_STATE_MINIMIZED_VERT",
+                    name: b"_NET_WM_STATE_MAXIMIZED_HORZ",
                 }),
                 connection.send_request(&x::InternAtom {
                     only_if_exists: true,
                     name: b"_NET_WM_STATE_HIDDEN",
                 }),
+                // The atoms only sent by the window are created if needed
+                connection.send_request(&x::InternAtom {
+                    only_if_exists: false,
+                    name: b"_NET_WM_STATE_FULLSCREEN",
+                }),
+                connection.send_request(&x::InternAtom {
+                    only_if_exists: false,
+                    name: b"WM_CHANGE_STATE",
+                }),
+                connection.send_request(&x::InternAtom {
+                    only_if_exists: false,
+                    name: b"_MOTIF_WM_HINTS",
+                }),
+                connection.send_request(&x::InternAtom {
+                    only_if_exists: false,
+                    name: b"_NET_WM_NAME",
+                }),
+                connection.send_request(&x::InternAtom {
+                    only_if_exists: false,
+                    name: b"UTF8_STRING",
+                }),
             );
             (
                 match connection.wait_for_reply(cookies.0) {
@@ -676,6 +1031,56 @@ impl Window for LinuxX11Window {
                         return Err(ErrorType::Unknown);
                     }
                 },
+                match connection.wait_for_reply(cookies.6) {
+                    Ok(reply) => reply.atom(),
+                    Err(err) => {
+                        log_error!(
+                            "Failed to fetch back an atom when initializing the X11 linux window: {:?}",
+                            err
+                        );
+                        return Err(ErrorType::Unknown);
+                    }
+                },
+                match connection.wait_for_reply(cookies.7) {
+                    Ok(reply) => reply.atom(),
+                    Err(err) => {
+                        log_error!(
+                            "Failed to fetch back an atom when initializing the X11 linux window: {:?}",
+                            err
+                        );
+                        return Err(ErrorType::Unknown);
+                    }
+                },
+                match connection.wait_for_reply(cookies.8) {
+                    Ok(reply) => reply.atom(),
+                    Err(err) => {
+                        log_error!(
+                            "Failed to fetch back an atom when initializing the X11 linux window: {:?}",
+                            err
+                        );
+                        return Err(ErrorType::Unknown);
+                    }
+                },
+                match connection.wait_for_reply(cookies.9) {
+                    Ok(reply) => reply.atom(),
+                    Err(err) => {
+                        log_error!(
+                            "Failed to fetch back an atom when initializing the X11 linux window: {:?}",
+                            err
+                        );
+                        return Err(ErrorType::Unknown);
+                    }
+                },
+                match connection.wait_for_reply(cookies.10) {
+                    Ok(reply) => reply.atom(),
+                    Err(err) => {
+                        log_error!(
+                            "Failed to fetch back an atom when initializing the X11 linux window: {:?}",
+                            err
+                        );
+                        return Err(ErrorType::Unknown);
+                    }
+                },
             )
         };
 
@@ -697,14 +1102,8 @@ impl Window for LinuxX11Window {
         };
 
         // Cache the keymap
-        let min_keycode = setup.min_keycode();
-        let max_keycode = setup.max_keycode();
-        let cookie = connection.send_request(&x::GetKeyboardMapping {
-            first_keycode: min_keycode,
-            count: (max_keycode - min_keycode + 1u8),
-        });
-        let keymap_reply = match connection.wait_for_reply(cookie) {
-            Ok(reply) => reply,
+        let keymap = match Self::get_keymap(&connection) {
+            Ok(keymap) => keymap,
             Err(err) => {
                 log_error!(
                     "Failed to fetch the keymap when initializing the X11 linux window: {:?}",
@@ -713,20 +1112,6 @@ impl Window for LinuxX11Window {
                 return Err(ErrorType::Unknown);
             }
         };
-        let mut keymap = HashMap::new();
-        let keysyms = keymap_reply.keysyms();
-        let keysyms_per_keycode = keymap_reply.keysyms_per_keycode() as usize;
-        for (i, chunk) in keysyms.chunks(keysyms_per_keycode).enumerate() {
-            let keycode = min_keycode + (i as x::Keycode);
-            if let Some(&keysym) = chunk.first()
-                && keymap.insert(keycode, keysym).is_some()
-            {
-                log_warn!(
-                    "Adding the `{:?}' keycode twice to the Linux X11 keymap",
-                    keycode
-                );
-            }
-        }
 
         let properties = WindowCommonProperties {
             position: config.window_config.position,
@@ -742,11 +1127,28 @@ impl Window for LinuxX11Window {
             state_maximized_vert: wm_max_vert,
             state_maximized_horz: wm_max_horz,
             state_hidden: wm_hidden,
+            state_fullscreen: wm_fullscreen,
+            change_state: wm_change_state,
+            motif_wm_hints,
+            net_wm_name,
+            utf8_string,
+        };
+
+        // Update window's title
+        if let Err(err) =
+            Self::change_title(&connection, window, &atoms, &config.window_config.title)
+        {
+            log_error!(
+                "Failed to update the window title when initializing the X11 linux window: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
         };
 
         let screen = LinuxX11ScreenProperties {
             width: screen.width_in_pixels(),
             height: screen.height_in_pixels(),
+            root: screen.root(),
         };
 
         // Inits the opengl drawable windows
@@ -759,6 +1161,24 @@ impl Window for LinuxX11Window {
             return Err(ErrorType::Unknown);
         };
 
+        let has_raw_motion = Self::has_xinput2(&connection);
+        let hidden_cursor = match Self::init_hidden_cursor(&connection, window) {
+            Ok(cursor) => cursor,
+            Err(err) => {
+                log_error!(
+                    "Failed to create the hidden cursor when initializing the X11 linux window: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+
+        // The input method servers answer through the Xlib queue while they are being opened
+        connection.set_event_queue_owner(xcb::EventQueueOwner::Xlib);
+        let text_input =
+            LinuxX11TextInput::init(display, window, config.window_config.use_system_locale);
+        connection.set_event_queue_owner(xcb::EventQueueOwner::Xcb);
+
         Ok(LinuxX11Window {
             properties,
             keymap,
@@ -766,12 +1186,35 @@ impl Window for LinuxX11Window {
             connection,
             window,
             screen,
+            is_focused: false,
+            has_raw_motion,
+            is_cursor_visible: true,
+            cursor_grab_mode: CursorGrabMode::None,
+            custom_cursor: x::CURSOR_NONE,
+            hidden_cursor,
+            restored_display_mode: DisplayMode::Floating(None),
+            text_input,
+            pending_events: VecDeque::new(),
             #[cfg(opengl_renderer)]
             opengl_window,
         })
     }
 
     fn shutdown(&mut self) -> Result<(), ErrorType> {
+        // The input method servers answer through the Xlib queue while they are being closed
+        self.connection
+            .set_event_queue_owner(xcb::EventQueueOwner::Xlib);
+        self.text_input.shutdown();
+        self.connection
+            .set_event_queue_owner(xcb::EventQueueOwner::Xcb);
+        if self.custom_cursor != x::CURSOR_NONE {
+            let _cookie = self.connection.send_request(&x::FreeCursor {
+                cursor: self.custom_cursor,
+            });
+        }
+        let _cookie = self.connection.send_request(&x::FreeCursor {
+            cursor: self.hidden_cursor,
+        });
         #[cfg(opengl_renderer)]
         if let Err(err) = self.opengl_window.shutdown() {
             log_error!(
@@ -787,133 +1230,286 @@ impl Window for LinuxX11Window {
         self.properties
     }
 
-    fn poll_event(&mut self) -> Result<Event, ErrorType> {
-        match self.connection.wait_for_event() {
+    fn poll_event(&mut self) -> Result<Option<Event>, ErrorType> {
+        if let Some(event) = self.pending_events.pop_front() {
+            return Ok(Some(event));
+        }
+        match self.connection.poll_for_event() {
             Err(err) => {
-                log_error!("Failed to wait for an event on the X11 linux: {:?}", err);
+                log_error!("Failed to poll for an event on the X11 linux: {:?}", err);
                 Err(ErrorType::Unknown)
             }
-            Ok(event) => match event {
-                // Keyboard events
-                xcb::Event::X(x::Event::KeyPress(event)) => {
-                    match self.get_key_from_keysym(event.detail()) {
-                        Some(key) => Ok(Event::KeyboardKeyPressed(key)),
-                        None => Ok(Event::KeyboardKeyPressed(KeyboardKey::Unrecognized)),
-                    }
-                }
-                xcb::Event::X(x::Event::KeyRelease(event)) => {
-                    match self.get_key_from_keysym(event.detail()) {
-                        Some(key) => Ok(Event::KeyboardKeyReleased(key)),
-                        None => Ok(Event::KeyboardKeyReleased(KeyboardKey::Unrecognized)),
-                    }
-                }
-                // Client message events
-                xcb::Event::X(x::Event::ClientMessage(event)) => {
-                    if event.r#type() == self.atoms.state {
-                        if let x::ClientMessageData::Data32(
-                            [_, first_property, second_property, ..],
-                        ) = event.data()
-                        {
-                            // Window maximized
-                            if first_property == self.atoms.state_maximized_horz.resource_id()
-                                || first_property == self.atoms.state_maximized_vert.resource_id()
-                                || second_property == self.atoms.state_maximized_horz.resource_id()
-                                || second_property == self.atoms.state_maximized_vert.resource_id()
-                            {
-                                return Ok(Event::WindowRestored);
-                            }
-                            // Window minimized
-                            else if first_property == self.atoms.state_hidden.resource_id() {
-                                return Ok(Event::WindowMinimized);
-                            }
-                        }
-                    } else if event.r#type() == self.atoms.protocols
-                        && let x::ClientMessageData::Data32([atom, ..]) = event.data()
-                    {
-                        // Window closed
-                        if atom == self.atoms.delete_window.resource_id() {
-                            return Ok(Event::WindowClosed);
-                        }
-                    }
-                    log_debug!("Unknown X11 linux client message event");
-                    Ok(Event::Unrecognized)
-                }
-                // Configure notify events
-                xcb::Event::X(x::Event::ConfigureNotify(event)) => {
-                    let width = (event.width() as f32) / (self.screen.width as f32);
-                    let height = (event.height() as f32) / (self.screen.height as f32);
-
-                    // Detect if the size has changed and trigger the corresponding event
-                    if width != self.properties.width || height != self.properties.height {
-                        // Update the window's properties
-                        self.properties.width = width;
-                        self.properties.height = height;
-
-                        return Ok(Event::WindowResized(width, height));
-                    }
-                    Ok(Event::Unrecognized)
-                }
-                xcb::Event::X(x::Event::Expose(_)) => Ok(Event::Expose),
-
-                // TODO: other events
-                _ => {
-                    // log_debug!("Unknown X11 linux window event");
-                    Ok(Event::Unrecognized)
+            Ok(None) => Ok(None),
+            Ok(Some(event)) => match self.convert_event(event) {
+                Ok(event) => Ok(Some(event)),
+                Err(err) => {
+                    log_error!("Failed to convert an event on the X11 linux: {:?}", err);
+                    Err(ErrorType::Unknown)
                 }
             },
         }
     }
 
-    fn get_framebuffer_width(&self) -> u16 {
-        (self.properties.width * (self.screen.width as f32)) as u16
-    }
-
-    fn get_framebuffer_height(&self) -> u16 {
-        (self.properties.height * (self.screen.height as f32)) as u16
-    }
-
-    #[cfg(opengl_renderer)]
-    fn opengl_swap_buffers(&mut self) -> Result<(), ErrorType> {
-        unsafe {
-            x11::glx::glXSwapBuffers(self.opengl_window.display, self.opengl_window.drawable)
+    fn set_title(&mut self, title: &str) -> Result<(), ErrorType> {
+        if let Err(err) = Self::change_title(&self.connection, self.window, &self.atoms, title) {
+            log_error!("Failed to update the X11 linux window title: {:?}", err);
+            return Err(ErrorType::Unknown);
         };
         Ok(())
     }
 
-    #[cfg(opengl_renderer)]
-    fn opengl_make_context_current(&mut self) -> Result<(), ErrorType> {
-        if unsafe {
-            x11::glx::glXMakeContextCurrent(
-                self.opengl_window.display,
-                self.opengl_window.drawable,
-                self.opengl_window.drawable,
-                self.opengl_window.context,
-            )
-        } == 0
-        {
-            log_error!("Failed to make the OpenGL context current on the Linux X11 plateform");
+    fn set_geometry(
+        &mut self,
+        position: Vector2f32,
+        width: f32,
+        height: f32,
+    ) -> Result<(), ErrorType> {
+        let x = (position.x * (self.screen.width as f32)) as i32;
+        let y = (position.y * (self.screen.height as f32)) as i32;
+        let pixel_width = (width * (self.screen.width as f32)) as u32;
+        let pixel_height = (height * (self.screen.height as f32)) as u32;
+
+        // Warning, the list must be sorted in the same order as in
+        // https://docs.rs/xcb/1.5.0/xcb/x/enum.ConfigWindow.html
+        let cookie = self.connection.send_request_checked(&x::ConfigureWindow {
+            window: self.window,
+            value_list: &[
+                x::ConfigWindow::X(x),
+                x::ConfigWindow::Y(y),
+                x::ConfigWindow::Width(pixel_width),
+                x::ConfigWindow::Height(pixel_height),
+            ],
+        });
+        if let Err(err) = self.connection.check_request(cookie) {
+            log_error!("Failed to move and resize the X11 linux window: {:?}", err);
             return Err(ErrorType::Unknown);
         };
 
+        // The size is updated when receiving the `ConfigureNotify' event
+        // to trigger the corresponding resize event
+        self.properties.position = position;
         Ok(())
     }
 
-    #[cfg(opengl_renderer)]
-    fn opengl_load_functions(&mut self) -> Result<(), ErrorType> {
-        gl::load_with(|name| {
-            let cname = std::ffi::CString::new(name).unwrap(); // should never fail for valid GL names
-            unsafe {
-                x11::glx::glXGetProcAddress(cname.as_ptr() as *const std::os::raw::c_uchar).unwrap()
-                    as *const std::os::raw::c_void
+    fn set_display_mode(&mut self, display_mode: DisplayMode) -> Result<(), ErrorType> {
+        // The minimized state is updated when receiving the `UnmapNotify' event
+        if display_mode == DisplayMode::Minimized {
+            if let Err(err) = self
+                .send_to_window_manager(self.atoms.change_state, [ICCCM_ICONIC_STATE, 0, 0, 0, 0])
+            {
+                log_error!("Failed to minimize the X11 linux window: {:?}", err);
+                return Err(ErrorType::Unknown);
             }
-        });
-
-        // Check if OpenGL functions are loaded
-        if !gl::Clear::is_loaded() {
-            log_error!("Failed to load the OpenGL `Clear' function");
-            return Err(ErrorType::Unknown);
+            return Ok(());
         }
-        if !gl::ClearColor::is_loaded() {
+
+        // A minimized window is restored to the new mode when receiving the `MapNotify' event
+        if self.properties.display_mode == DisplayMode::Minimized {
+            let cookie = self.connection.send_request_checked(&x::MapWindow {
+                window: self.window,
+            });
+            if let Err(err) = self.connection.check_request(cookie) {
+                log_error!("Failed to restore the X11 linux window: {:?}", err);
+                return Err(ErrorType::Unknown);
+            }
+            self.restored_display_mode = display_mode;
+        } else {
+            self.properties.display_mode = display_mode;
+        }
+
+        // The new size is sent back by the window manager with a `ConfigureNotify' event
+        let action = if display_mode == DisplayMode::Fullscreen {
+            EWMH_STATE_ADD
+        } else {
+            EWMH_STATE_REMOVE
+        };
+        if let Err(err) = self.send_to_window_manager(
+            self.atoms.state,
+            [
+                action,
+                self.atoms.state_fullscreen.resource_id(),
+                0,
+                EWMH_SOURCE_APPLICATION,
+                0,
+            ],
+        ) {
+            log_error!(
+                "Failed to update the fullscreen state of the X11 linux window: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+
+        if let DisplayMode::Floating(Some((x, y, width, height))) = display_mode
+            && let Err(err) = self.set_geometry(Vector2f32::new(x, y), width, height)
+        {
+            log_error!(
+                "Failed to move and resize the X11 linux window when making it float: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
+
+    fn set_decorations(&mut self, has_decorations: bool) -> Result<(), ErrorType> {
+        // The hints are flags, functions, decorations, input mode and status
+        let hints = [MOTIF_HINTS_DECORATIONS, 0, has_decorations as u32, 0, 0];
+        let cookie = self.connection.send_request_checked(&x::ChangeProperty {
+            mode: x::PropMode::Replace,
+            window: self.window,
+            property: self.atoms.motif_wm_hints,
+            r#type: self.atoms.motif_wm_hints,
+            data: &hints,
+        });
+        if let Err(err) = self.connection.check_request(cookie) {
+            log_error!(
+                "Failed to update the X11 linux window decorations: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        };
+        Ok(())
+    }
+
+    fn set_border_width(&mut self, border_width: u16) -> Result<(), ErrorType> {
+        let cookie = self.connection.send_request_checked(&x::ConfigureWindow {
+            window: self.window,
+            value_list: &[x::ConfigWindow::BorderWidth(border_width as u32)],
+        });
+        if let Err(err) = self.connection.check_request(cookie) {
+            log_error!(
+                "Failed to update the X11 linux window border width: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        };
+        Ok(())
+    }
+
+    fn set_cursor_visible(&mut self, is_visible: bool) -> Result<(), ErrorType> {
+        self.is_cursor_visible = is_visible;
+        if let Err(err) = self.apply_cursor() {
+            log_error!(
+                "Failed to update the X11 linux cursor visibility: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
+
+    fn set_cursor_grab(&mut self, grab_mode: CursorGrabMode) -> Result<(), ErrorType> {
+        let is_relative = grab_mode == CursorGrabMode::Relative;
+        if is_relative && !self.has_raw_motion {
+            log_error!("The X11 linux relative cursor mode needs the XInput 2 extension");
+            return Err(ErrorType::NotSupported);
+        }
+        let was_relative = self.cursor_grab_mode == CursorGrabMode::Relative;
+        self.cursor_grab_mode = grab_mode;
+
+        // The relative mode hides the cursor and listens to the raw motions
+        if was_relative != is_relative {
+            if let Err(err) = self.select_raw_motion(is_relative) {
+                log_error!(
+                    "Failed to toggle the X11 linux raw mouse motions: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+            if let Err(err) = self.apply_cursor() {
+                log_error!(
+                    "Failed to update the X11 linux cursor for the relative mode: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        }
+        if let Err(err) = self.apply_cursor_grab() {
+            log_error!("Failed to grab the X11 linux cursor: {:?}", err);
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
+
+    fn set_cursor_image(&mut self, image: Option<&CursorImage>) -> Result<(), ErrorType> {
+        let cursor = match image {
+            None => x::CURSOR_NONE,
+            Some(image) => match self.init_cursor(image) {
+                Ok(cursor) => cursor,
+                Err(err) => {
+                    log_error!(
+                        "Failed to create the X11 linux cursor from an image: {:?}",
+                        err
+                    );
+                    return Err(ErrorType::Unknown);
+                }
+            },
+        };
+        // The X server keeps the previous cursor alive until the window stops using it
+        let previous_cursor = std::mem::replace(&mut self.custom_cursor, cursor);
+        if previous_cursor != x::CURSOR_NONE {
+            let _cookie = self.connection.send_request(&x::FreeCursor {
+                cursor: previous_cursor,
+            });
+        }
+        if let Err(err) = self.apply_cursor() {
+            log_error!("Failed to update the X11 linux cursor image: {:?}", err);
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
+
+    fn get_framebuffer_width(&self) -> u16 {
+        (self.properties.width * (self.screen.width as f32)) as u16
+    }
+
+    fn get_framebuffer_height(&self) -> u16 {
+        (self.properties.height * (self.screen.height as f32)) as u16
+    }
+
+    #[cfg(opengl_renderer)]
+    fn opengl_swap_buffers(&mut self) -> Result<(), ErrorType> {
+        unsafe {
+            x11::glx::glXSwapBuffers(self.opengl_window.display, self.opengl_window.drawable)
+        };
+        Ok(())
+    }
+
+    #[cfg(opengl_renderer)]
+    fn opengl_make_context_current(&mut self) -> Result<(), ErrorType> {
+        if unsafe {
+            x11::glx::glXMakeContextCurrent(
+                self.opengl_window.display,
+                self.opengl_window.drawable,
+                self.opengl_window.drawable,
+                self.opengl_window.context,
+            )
+        } == 0
+        {
+            log_error!("Failed to make the OpenGL context current on the Linux X11 plateform");
+            return Err(ErrorType::Unknown);
+        };
+
+        Ok(())
+    }
+
+    #[cfg(opengl_renderer)]
+    fn opengl_load_functions(&mut self) -> Result<(), ErrorType> {
+        gl::load_with(|name| {
+            let cname = std::ffi::CString::new(name).unwrap(); // should never fail for valid GL names
+            unsafe {
+                x11::glx::glXGetProcAddress(cname.as_ptr() as *const std::os::raw::c_uchar).unwrap()
+                    as *const std::os::raw::c_void
+            }
+        });
+
+        // Check if OpenGL functions are loaded
+        if !gl::Clear::is_loaded() {
+            log_error!("Failed to load the OpenGL `Clear' function");
+            return Err(ErrorType::Unknown);
+        }
+        if !gl::ClearColor::is_loaded() {
             log_error!("Failed to load the OpenGL `Clear' function");
             return Err(ErrorType::Unknown);
         }
@@ -967,93 +1563,1149 @@ impl Window for LinuxX11Window {
 }
 
 impl LinuxX11Window {
+    /// Gets the key given by the keyboard layout from an X11 keycode
     fn get_key_from_keysym(&self, keycode: x::Keycode) -> Option<KeyboardKey> {
         let keysym = self.keymap.get(&keycode)?;
 
         match keysym {
             // Alphabet keys
-            0x0061 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::A)),
-            0x0062 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::B)),
-            0x0063 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::C)),
-            0x0064 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::D)),
-            0x0065 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::E)),
-            0x0066 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::F)),
-            0x0067 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::G)),
-            0x0068 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::H)),
-            0x0069 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::I)),
-            0x006A => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::J)),
-            0x006B => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::K)),
-            0x006C => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::L)),
-            0x006D => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::M)),
-            0x006E => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::N)),
-            0x006F => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::O)),
-            0x0070 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::P)),
-            0x0071 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::Q)),
-            0x0072 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::R)),
-            0x0073 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::S)),
-            0x0074 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::T)),
-            0x0075 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::U)),
-            0x0076 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::V)),
-            0x0077 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::W)),
-            0x0078 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::X)),
-            0x0079 => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::Y)),
-            0x007A => Some(KeyboardKey::AlphaNumeric(crate::keyboard::AlphaNumeric::Z)),
+            0x0061 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::A)),
+            0x0062 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::B)),
+            0x0063 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::C)),
+            0x0064 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::D)),
+            0x0065 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::E)),
+            0x0066 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::F)),
+            0x0067 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::G)),
+            0x0068 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::H)),
+            0x0069 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::I)),
+            0x006A => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::J)),
+            0x006B => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::K)),
+            0x006C => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::L)),
+            0x006D => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::M)),
+            0x006E => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::N)),
+            0x006F => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::O)),
+            0x0070 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::P)),
+            0x0071 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Q)),
+            0x0072 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::R)),
+            0x0073 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::S)),
+            0x0074 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::T)),
+            0x0075 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::U)),
+            0x0076 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::V)),
+            0x0077 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::W)),
+            0x0078 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::X)),
+            0x0079 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Y)),
+            0x007A => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Z)),
 
             // Digit keys (0-9)
-            0x0030 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Zero,
-            )),
-            0x0031 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::One,
-            )),
-            0x0032 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Two,
-            )),
-            0x0033 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Three,
-            )),
-            0x0034 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Four,
-            )),
-            0x0035 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Five,
-            )),
-            0x0036 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Six,
-            )),
-            0x0037 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Seven,
-            )),
-            0x0038 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Eight,
-            )),
-            0x0039 => Some(KeyboardKey::AlphaNumeric(
-                crate::keyboard::AlphaNumeric::Nine,
-            )),
+            0x0030 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Zero)),
+            0x0031 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::One)),
+            0x0032 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Two)),
+            0x0033 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Three)),
+            0x0034 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Four)),
+            0x0035 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Five)),
+            0x0036 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Six)),
+            0x0037 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Seven)),
+            0x0038 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Eight)),
+            0x0039 => Some(KeyboardKey::AlphaNumeric(AlphaNumeric::Nine)),
+
+            // Punctuation keys
+            0x002D => Some(KeyboardKey::Punctuation(Punctuation::Minus)),
+            0x003D => Some(KeyboardKey::Punctuation(Punctuation::Equal)),
+            0x005B => Some(KeyboardKey::Punctuation(Punctuation::BracketLeft)),
+            0x005D => Some(KeyboardKey::Punctuation(Punctuation::BracketRight)),
+            0x005C => Some(KeyboardKey::Punctuation(Punctuation::Backslash)),
+            0x003B => Some(KeyboardKey::Punctuation(Punctuation::Semicolon)),
+            0x0027 => Some(KeyboardKey::Punctuation(Punctuation::Apostrophe)),
+            0x0060 => Some(KeyboardKey::Punctuation(Punctuation::Grave)),
+            0x002C => Some(KeyboardKey::Punctuation(Punctuation::Comma)),
+            0x002E => Some(KeyboardKey::Punctuation(Punctuation::Period)),
+            0x002F => Some(KeyboardKey::Punctuation(Punctuation::Slash)),
+            0x003C => Some(KeyboardKey::Punctuation(Punctuation::IntlBackslash)), // Less key
 
             // Arrows
-            0xFF51 => Some(KeyboardKey::Arrow(crate::keyboard::Arrow::Left)), // Left arrow key
-            0xFF53 => Some(KeyboardKey::Arrow(crate::keyboard::Arrow::Right)), // Right arrow key
-            0xFF52 => Some(KeyboardKey::Arrow(crate::keyboard::Arrow::Up)),   // Up arrow key
-            0xFF54 => Some(KeyboardKey::Arrow(crate::keyboard::Arrow::Down)), // Down arrow key
+            0xFF51 => Some(KeyboardKey::Arrow(Arrow::Left)), // Left arrow key
+            0xFF53 => Some(KeyboardKey::Arrow(Arrow::Right)), // Right arrow key
+            0xFF52 => Some(KeyboardKey::Arrow(Arrow::Up)),   // Up arrow key
+            0xFF54 => Some(KeyboardKey::Arrow(Arrow::Down)), // Down arrow key
+
+            // Navigation keys
+            0xFF50 => Some(KeyboardKey::Navigation(Navigation::Home)),
+            0xFF57 => Some(KeyboardKey::Navigation(Navigation::End)),
+            0xFF55 => Some(KeyboardKey::Navigation(Navigation::PageUp)), // Prior key
+            0xFF56 => Some(KeyboardKey::Navigation(Navigation::PageDown)), // Next key
+            0xFF63 => Some(KeyboardKey::Navigation(Navigation::Insert)),
 
             // Modifiers
-            0xFFE1 => Some(KeyboardKey::Modifier(crate::keyboard::Modifier::ShiftLeft)), // Shift key
-            0xFFE9 => Some(KeyboardKey::Modifier(crate::keyboard::Modifier::AltLeft)), // Left Alt key
-            0xFFE3 => Some(KeyboardKey::Modifier(
-                crate::keyboard::Modifier::ControlLeft,
-            )), // Left Control key
+            0xFFE1 => Some(KeyboardKey::Modifier(Modifier::ShiftLeft)), // Left Shift key
+            0xFFE2 => Some(KeyboardKey::Modifier(Modifier::ShiftRight)), // Right Shift key
+            0xFFE3 => Some(KeyboardKey::Modifier(Modifier::ControlLeft)), // Left Control key
+            0xFFE4 => Some(KeyboardKey::Modifier(Modifier::ControlRight)), // Right Control key
+            0xFFE9 => Some(KeyboardKey::Modifier(Modifier::AltLeft)),   // Left Alt key
+            0xFFEA | 0xFE03 => Some(KeyboardKey::Modifier(Modifier::AltRight)), // Right Alt or AltGr key
+            0xFFEB => Some(KeyboardKey::Modifier(Modifier::SuperLeft)),         // Left Super key
+            0xFFEC => Some(KeyboardKey::Modifier(Modifier::SuperRight)),        // Right Super key
 
             // Special keys
-            0xFF0D => Some(KeyboardKey::Special(crate::keyboard::Special::Enter)), // Enter key
-            0xFF08 => Some(KeyboardKey::Special(crate::keyboard::Special::Backspace)), // Backspace key
-            0xFFFF => Some(KeyboardKey::Special(crate::keyboard::Special::Delete)),    // Delete key
-            0x0020 => Some(KeyboardKey::Special(crate::keyboard::Special::Spacebar)),  // Space key
-            0xFF09 => Some(KeyboardKey::Special(crate::keyboard::Special::Tab)),       // Tab key
-            0xFF1B => Some(KeyboardKey::Special(crate::keyboard::Special::Escape)),    // Escape key
+            0xFF0D => Some(KeyboardKey::Special(Special::Enter)), // Enter key
+            0xFF08 => Some(KeyboardKey::Special(Special::Backspace)), // Backspace key
+            0xFFFF => Some(KeyboardKey::Special(Special::Delete)), // Delete key
+            0x0020 => Some(KeyboardKey::Special(Special::Spacebar)), // Space key
+            0xFF09 => Some(KeyboardKey::Special(Special::Tab)),   // Tab key
+            0xFF1B => Some(KeyboardKey::Special(Special::Escape)), // Escape key
+            0xFFE5 => Some(KeyboardKey::Special(Special::CapsLock)), // Caps Lock key
+            0xFF7F => Some(KeyboardKey::Special(Special::NumLock)), // Num Lock key
+            0xFF14 => Some(KeyboardKey::Special(Special::ScrollLock)), // Scroll Lock key
+            0xFF61 => Some(KeyboardKey::Special(Special::PrintScreen)), // Print key
+            0xFF13 => Some(KeyboardKey::Special(Special::Pause)), // Pause key
+            0xFF67 => Some(KeyboardKey::Special(Special::Menu)),  // Menu key
+
+            // Function keys (F1-F24)
+            0xFFBE => Some(KeyboardKey::Function(Function::F1)),
+            0xFFBF => Some(KeyboardKey::Function(Function::F2)),
+            0xFFC0 => Some(KeyboardKey::Function(Function::F3)),
+            0xFFC1 => Some(KeyboardKey::Function(Function::F4)),
+            0xFFC2 => Some(KeyboardKey::Function(Function::F5)),
+            0xFFC3 => Some(KeyboardKey::Function(Function::F6)),
+            0xFFC4 => Some(KeyboardKey::Function(Function::F7)),
+            0xFFC5 => Some(KeyboardKey::Function(Function::F8)),
+            0xFFC6 => Some(KeyboardKey::Function(Function::F9)),
+            0xFFC7 => Some(KeyboardKey::Function(Function::F10)),
+            0xFFC8 => Some(KeyboardKey::Function(Function::F11)),
+            0xFFC9 => Some(KeyboardKey::Function(Function::F12)),
+            0xFFCA => Some(KeyboardKey::Function(Function::F13)),
+            0xFFCB => Some(KeyboardKey::Function(Function::F14)),
+            0xFFCC => Some(KeyboardKey::Function(Function::F15)),
+            0xFFCD => Some(KeyboardKey::Function(Function::F16)),
+            0xFFCE => Some(KeyboardKey::Function(Function::F17)),
+            0xFFCF => Some(KeyboardKey::Function(Function::F18)),
+            0xFFD0 => Some(KeyboardKey::Function(Function::F19)),
+            0xFFD1 => Some(KeyboardKey::Function(Function::F20)),
+            0xFFD2 => Some(KeyboardKey::Function(Function::F21)),
+            0xFFD3 => Some(KeyboardKey::Function(Function::F22)),
+            0xFFD4 => Some(KeyboardKey::Function(Function::F23)),
+            0xFFD5 => Some(KeyboardKey::Function(Function::F24)),
+
+            // Numeric keypad keys
+            // Without 'Num Lock', the digits are given as navigation keysyms
+            0xFFB0 | 0xFF9E => Some(KeyboardKey::Numpad(Numpad::Zero)), // KP_0 or KP_Insert
+            0xFFB1 | 0xFF9C => Some(KeyboardKey::Numpad(Numpad::One)),  // KP_1 or KP_End
+            0xFFB2 | 0xFF99 => Some(KeyboardKey::Numpad(Numpad::Two)),  // KP_2 or KP_Down
+            0xFFB3 | 0xFF9B => Some(KeyboardKey::Numpad(Numpad::Three)), // KP_3 or KP_Next
+            0xFFB4 | 0xFF96 => Some(KeyboardKey::Numpad(Numpad::Four)), // KP_4 or KP_Left
+            0xFFB5 | 0xFF9D => Some(KeyboardKey::Numpad(Numpad::Five)), // KP_5 or KP_Begin
+            0xFFB6 | 0xFF98 => Some(KeyboardKey::Numpad(Numpad::Six)),  // KP_6 or KP_Right
+            0xFFB7 | 0xFF95 => Some(KeyboardKey::Numpad(Numpad::Seven)), // KP_7 or KP_Home
+            0xFFB8 | 0xFF97 => Some(KeyboardKey::Numpad(Numpad::Eight)), // KP_8 or KP_Up
+            0xFFB9 | 0xFF9A => Some(KeyboardKey::Numpad(Numpad::Nine)), // KP_9 or KP_Prior
+            0xFFAE | 0xFFAC | 0xFF9F => Some(KeyboardKey::Numpad(Numpad::Decimal)), // KP_Decimal, KP_Separator or KP_Delete
+            0xFFAB => Some(KeyboardKey::Numpad(Numpad::Add)),
+            0xFFAD => Some(KeyboardKey::Numpad(Numpad::Subtract)),
+            0xFFAA => Some(KeyboardKey::Numpad(Numpad::Multiply)),
+            0xFFAF => Some(KeyboardKey::Numpad(Numpad::Divide)),
+            0xFF8D => Some(KeyboardKey::Numpad(Numpad::Enter)),
+            0xFFBD => Some(KeyboardKey::Numpad(Numpad::Equal)),
+
+            // Media keys
+            0x1008FF14 | 0x1008FF31 => Some(KeyboardKey::Media(Media::PlayPause)), // Play or Pause key
+            0x1008FF15 => Some(KeyboardKey::Media(Media::Stop)),
+            0x1008FF17 => Some(KeyboardKey::Media(Media::Next)),
+            0x1008FF16 => Some(KeyboardKey::Media(Media::Previous)),
+            0x1008FF12 => Some(KeyboardKey::Media(Media::Mute)),
+            0x1008FF13 => Some(KeyboardKey::Media(Media::VolumeUp)),
+            0x1008FF11 => Some(KeyboardKey::Media(Media::VolumeDown)),
 
             // If no match, return Unrecognized
             _ => Some(KeyboardKey::Unrecognized),
         }
     }
+
+    /// Gets the key at the same position on a US QWERTY layout from an X11 keycode
+    /// The keycodes are the Linux evdev scancodes shifted by 8, whatever the layout
+    fn get_physical_key(keycode: x::Keycode) -> KeyboardKey {
+        match keycode {
+            // First row
+            9 => KeyboardKey::Special(Special::Escape),
+            10 => KeyboardKey::AlphaNumeric(AlphaNumeric::One),
+            11 => KeyboardKey::AlphaNumeric(AlphaNumeric::Two),
+            12 => KeyboardKey::AlphaNumeric(AlphaNumeric::Three),
+            13 => KeyboardKey::AlphaNumeric(AlphaNumeric::Four),
+            14 => KeyboardKey::AlphaNumeric(AlphaNumeric::Five),
+            15 => KeyboardKey::AlphaNumeric(AlphaNumeric::Six),
+            16 => KeyboardKey::AlphaNumeric(AlphaNumeric::Seven),
+            17 => KeyboardKey::AlphaNumeric(AlphaNumeric::Eight),
+            18 => KeyboardKey::AlphaNumeric(AlphaNumeric::Nine),
+            19 => KeyboardKey::AlphaNumeric(AlphaNumeric::Zero),
+            20 => KeyboardKey::Punctuation(Punctuation::Minus),
+            21 => KeyboardKey::Punctuation(Punctuation::Equal),
+            22 => KeyboardKey::Special(Special::Backspace),
+
+            // Second row
+            23 => KeyboardKey::Special(Special::Tab),
+            24 => KeyboardKey::AlphaNumeric(AlphaNumeric::Q),
+            25 => KeyboardKey::AlphaNumeric(AlphaNumeric::W),
+            26 => KeyboardKey::AlphaNumeric(AlphaNumeric::E),
+            27 => KeyboardKey::AlphaNumeric(AlphaNumeric::R),
+            28 => KeyboardKey::AlphaNumeric(AlphaNumeric::T),
+            29 => KeyboardKey::AlphaNumeric(AlphaNumeric::Y),
+            30 => KeyboardKey::AlphaNumeric(AlphaNumeric::U),
+            31 => KeyboardKey::AlphaNumeric(AlphaNumeric::I),
+            32 => KeyboardKey::AlphaNumeric(AlphaNumeric::O),
+            33 => KeyboardKey::AlphaNumeric(AlphaNumeric::P),
+            34 => KeyboardKey::Punctuation(Punctuation::BracketLeft),
+            35 => KeyboardKey::Punctuation(Punctuation::BracketRight),
+            36 => KeyboardKey::Special(Special::Enter),
+
+            // Third row
+            37 => KeyboardKey::Modifier(Modifier::ControlLeft),
+            38 => KeyboardKey::AlphaNumeric(AlphaNumeric::A),
+            39 => KeyboardKey::AlphaNumeric(AlphaNumeric::S),
+            40 => KeyboardKey::AlphaNumeric(AlphaNumeric::D),
+            41 => KeyboardKey::AlphaNumeric(AlphaNumeric::F),
+            42 => KeyboardKey::AlphaNumeric(AlphaNumeric::G),
+            43 => KeyboardKey::AlphaNumeric(AlphaNumeric::H),
+            44 => KeyboardKey::AlphaNumeric(AlphaNumeric::J),
+            45 => KeyboardKey::AlphaNumeric(AlphaNumeric::K),
+            46 => KeyboardKey::AlphaNumeric(AlphaNumeric::L),
+            47 => KeyboardKey::Punctuation(Punctuation::Semicolon),
+            48 => KeyboardKey::Punctuation(Punctuation::Apostrophe),
+            49 => KeyboardKey::Punctuation(Punctuation::Grave),
+
+            // Fourth row
+            50 => KeyboardKey::Modifier(Modifier::ShiftLeft),
+            51 => KeyboardKey::Punctuation(Punctuation::Backslash),
+            52 => KeyboardKey::AlphaNumeric(AlphaNumeric::Z),
+            53 => KeyboardKey::AlphaNumeric(AlphaNumeric::X),
+            54 => KeyboardKey::AlphaNumeric(AlphaNumeric::C),
+            55 => KeyboardKey::AlphaNumeric(AlphaNumeric::V),
+            56 => KeyboardKey::AlphaNumeric(AlphaNumeric::B),
+            57 => KeyboardKey::AlphaNumeric(AlphaNumeric::N),
+            58 => KeyboardKey::AlphaNumeric(AlphaNumeric::M),
+            59 => KeyboardKey::Punctuation(Punctuation::Comma),
+            60 => KeyboardKey::Punctuation(Punctuation::Period),
+            61 => KeyboardKey::Punctuation(Punctuation::Slash),
+            62 => KeyboardKey::Modifier(Modifier::ShiftRight),
+            94 => KeyboardKey::Punctuation(Punctuation::IntlBackslash),
+
+            // Bottom row
+            64 => KeyboardKey::Modifier(Modifier::AltLeft),
+            65 => KeyboardKey::Special(Special::Spacebar),
+            66 => KeyboardKey::Special(Special::CapsLock),
+            105 => KeyboardKey::Modifier(Modifier::ControlRight),
+            108 => KeyboardKey::Modifier(Modifier::AltRight),
+            133 => KeyboardKey::Modifier(Modifier::SuperLeft),
+            134 => KeyboardKey::Modifier(Modifier::SuperRight),
+            135 => KeyboardKey::Special(Special::Menu),
+
+            // Function keys
+            67 => KeyboardKey::Function(Function::F1),
+            68 => KeyboardKey::Function(Function::F2),
+            69 => KeyboardKey::Function(Function::F3),
+            70 => KeyboardKey::Function(Function::F4),
+            71 => KeyboardKey::Function(Function::F5),
+            72 => KeyboardKey::Function(Function::F6),
+            73 => KeyboardKey::Function(Function::F7),
+            74 => KeyboardKey::Function(Function::F8),
+            75 => KeyboardKey::Function(Function::F9),
+            76 => KeyboardKey::Function(Function::F10),
+            95 => KeyboardKey::Function(Function::F11),
+            96 => KeyboardKey::Function(Function::F12),
+            191 => KeyboardKey::Function(Function::F13),
+            192 => KeyboardKey::Function(Function::F14),
+            193 => KeyboardKey::Function(Function::F15),
+            194 => KeyboardKey::Function(Function::F16),
+            195 => KeyboardKey::Function(Function::F17),
+            196 => KeyboardKey::Function(Function::F18),
+            197 => KeyboardKey::Function(Function::F19),
+            198 => KeyboardKey::Function(Function::F20),
+            199 => KeyboardKey::Function(Function::F21),
+            200 => KeyboardKey::Function(Function::F22),
+            201 => KeyboardKey::Function(Function::F23),
+            202 => KeyboardKey::Function(Function::F24),
+
+            // Numeric keypad
+            77 => KeyboardKey::Special(Special::NumLock),
+            106 => KeyboardKey::Numpad(Numpad::Divide),
+            63 => KeyboardKey::Numpad(Numpad::Multiply),
+            82 => KeyboardKey::Numpad(Numpad::Subtract),
+            86 => KeyboardKey::Numpad(Numpad::Add),
+            104 => KeyboardKey::Numpad(Numpad::Enter),
+            125 => KeyboardKey::Numpad(Numpad::Equal),
+            91 => KeyboardKey::Numpad(Numpad::Decimal),
+            90 => KeyboardKey::Numpad(Numpad::Zero),
+            87 => KeyboardKey::Numpad(Numpad::One),
+            88 => KeyboardKey::Numpad(Numpad::Two),
+            89 => KeyboardKey::Numpad(Numpad::Three),
+            83 => KeyboardKey::Numpad(Numpad::Four),
+            84 => KeyboardKey::Numpad(Numpad::Five),
+            85 => KeyboardKey::Numpad(Numpad::Six),
+            79 => KeyboardKey::Numpad(Numpad::Seven),
+            80 => KeyboardKey::Numpad(Numpad::Eight),
+            81 => KeyboardKey::Numpad(Numpad::Nine),
+
+            // Navigation and editing keys
+            107 => KeyboardKey::Special(Special::PrintScreen),
+            78 => KeyboardKey::Special(Special::ScrollLock),
+            127 => KeyboardKey::Special(Special::Pause),
+            118 => KeyboardKey::Navigation(Navigation::Insert),
+            119 => KeyboardKey::Special(Special::Delete),
+            110 => KeyboardKey::Navigation(Navigation::Home),
+            115 => KeyboardKey::Navigation(Navigation::End),
+            112 => KeyboardKey::Navigation(Navigation::PageUp),
+            117 => KeyboardKey::Navigation(Navigation::PageDown),
+            111 => KeyboardKey::Arrow(Arrow::Up),
+            116 => KeyboardKey::Arrow(Arrow::Down),
+            113 => KeyboardKey::Arrow(Arrow::Left),
+            114 => KeyboardKey::Arrow(Arrow::Right),
+
+            // Media keys
+            121 => KeyboardKey::Media(Media::Mute),
+            122 => KeyboardKey::Media(Media::VolumeDown),
+            123 => KeyboardKey::Media(Media::VolumeUp),
+            171 => KeyboardKey::Media(Media::Next),
+            172 => KeyboardKey::Media(Media::PlayPause),
+            173 => KeyboardKey::Media(Media::Previous),
+            174 => KeyboardKey::Media(Media::Stop),
+
+            _ => KeyboardKey::Unrecognized,
+        }
+    }
+
+    /// Gets the modifiers from the X11 key state of a keyboard event
+    /// The X11 state is the one before the event, so the modifier keys themselves are applied on top
+    fn get_keyboard_modifiers(
+        state: x::KeyButMask,
+        physical_key: KeyboardKey,
+        is_pressed: bool,
+    ) -> KeyboardModifiers {
+        let mut modifiers = KeyboardModifiers {
+            is_shift_down: state.contains(x::KeyButMask::SHIFT),
+            is_control_down: state.contains(x::KeyButMask::CONTROL),
+            // 'AltGr' is usually bound to the fifth modifier
+            is_alt_down: state.intersects(x::KeyButMask::MOD1 | x::KeyButMask::MOD5),
+            is_super_down: state.contains(x::KeyButMask::MOD4),
+            is_caps_lock_on: state.contains(x::KeyButMask::LOCK),
+            is_num_lock_on: state.contains(x::KeyButMask::MOD2),
+        };
+        match physical_key {
+            KeyboardKey::Modifier(Modifier::ShiftLeft | Modifier::ShiftRight) => {
+                modifiers.is_shift_down = is_pressed;
+            }
+            KeyboardKey::Modifier(Modifier::ControlLeft | Modifier::ControlRight) => {
+                modifiers.is_control_down = is_pressed;
+            }
+            KeyboardKey::Modifier(Modifier::AltLeft | Modifier::AltRight) => {
+                modifiers.is_alt_down = is_pressed;
+            }
+            KeyboardKey::Modifier(Modifier::SuperLeft | Modifier::SuperRight) => {
+                modifiers.is_super_down = is_pressed;
+            }
+            _ => {}
+        }
+        modifiers
+    }
+
+    /// Fetches the keycode to keysym map of the X server
+    /// It must be fetched again when the server sends a keyboard mapping event
+    fn get_keymap(
+        connection: &xcb::Connection,
+    ) -> Result<HashMap<x::Keycode, x::Keysym>, xcb::Error> {
+        let setup = connection.get_setup();
+        let min_keycode = setup.min_keycode();
+        let max_keycode = setup.max_keycode();
+        let cookie = connection.send_request(&x::GetKeyboardMapping {
+            first_keycode: min_keycode,
+            count: (max_keycode - min_keycode + 1u8),
+        });
+        let keymap_reply = connection.wait_for_reply(cookie)?;
+
+        let mut keymap = HashMap::new();
+        let keysyms = keymap_reply.keysyms();
+        let keysyms_per_keycode = keymap_reply.keysyms_per_keycode() as usize;
+        for (i, chunk) in keysyms.chunks(keysyms_per_keycode).enumerate() {
+            let keycode = min_keycode + (i as x::Keycode);
+            if let Some(&keysym) = chunk.first()
+                && keymap.insert(keycode, keysym).is_some()
+            {
+                log_warn!(
+                    "Adding the `{:?}' keycode twice to the Linux X11 keymap",
+                    keycode
+                );
+            }
+        }
+        Ok(keymap)
+    }
+
+    /// Gets the Linux evdev scancode of an X11 keycode
+    fn get_scancode(keycode: x::Keycode) -> u32 {
+        u32::from(keycode.saturating_sub(8))
+    }
+
+    /// Converts an X11 key event into a keyboard key, its physical key, its scancode and the modifiers
+    fn get_keyboard_event(
+        &self,
+        keycode: x::Keycode,
+        state: x::KeyButMask,
+        is_pressed: bool,
+    ) -> (KeyboardKey, KeyboardKey, u32, KeyboardModifiers) {
+        let key = self
+            .get_key_from_keysym(keycode)
+            .unwrap_or(KeyboardKey::Unrecognized);
+        let physical_key = Self::get_physical_key(keycode);
+        let modifiers = Self::get_keyboard_modifiers(state, physical_key, is_pressed);
+        (key, physical_key, Self::get_scancode(keycode), modifiers)
+    }
+
+    /// Gets the mouse button from an X11 button index
+    fn get_mouse_button(button: x::Button) -> Option<MouseButton> {
+        match button {
+            1 => Some(MouseButton::Left),
+            2 => Some(MouseButton::Middle),
+            3 => Some(MouseButton::Right),
+            _ => None,
+        }
+    }
+
+    /// Gets the first pressed mouse button from an X11 pointer state
+    fn get_pressed_mouse_button(state: x::KeyButMask) -> Option<MouseButton> {
+        if state.contains(x::KeyButMask::BUTTON1) {
+            Some(MouseButton::Left)
+        } else if state.contains(x::KeyButMask::BUTTON2) {
+            Some(MouseButton::Middle)
+        } else if state.contains(x::KeyButMask::BUTTON3) {
+            Some(MouseButton::Right)
+        } else {
+            None
+        }
+    }
+
+    /// Converts an X11 position relative to the top left of the window
+    /// into pixel coordinates relative to the bottom left of the window
+    /// The positions outside of the window, while a button is held, are clamped
+    fn get_mouse_position(&self, x: i16, y: i16) -> (u16, u16) {
+        // The last pixel of a row or a column is at `width - 1` or `height - 1`
+        let max_x = self.get_framebuffer_width().saturating_sub(1);
+        let max_y = self.get_framebuffer_height().saturating_sub(1);
+        let x = (x.max(0) as u16).min(max_x);
+        let y = max_y - (y.max(0) as u16).min(max_y);
+        (x, y)
+    }
+
+    /// Updates the focus of the window
+    /// Returns the event to send, unrecognized if the focus didn't change
+    fn set_focused(&mut self, is_focused: bool) -> Event {
+        if self.is_focused == is_focused {
+            return Event::Unrecognized;
+        }
+        self.is_focused = is_focused;
+        self.text_input.set_focused(is_focused);
+        // The cursor is released while the window is unfocused
+        if self.cursor_grab_mode != CursorGrabMode::None
+            && let Err(err) = self.apply_cursor_grab()
+        {
+            log_warn!(
+                "Failed to update the X11 linux cursor grab on a focus change: {:?}",
+                err
+            );
+        }
+        if is_focused {
+            Event::WindowFocused
+        } else {
+            Event::WindowUnfocused
+        }
+    }
+
+    /// Updates the display mode of the window when it is minimized or restored
+    /// Returns the event to send, unrecognized if the window was already in this state
+    fn set_minimized(&mut self, is_minimized: bool) -> Event {
+        let was_minimized = self.properties.display_mode == DisplayMode::Minimized;
+        match (was_minimized, is_minimized) {
+            (false, true) => {
+                self.restored_display_mode = self.properties.display_mode;
+                self.properties.display_mode = DisplayMode::Minimized;
+                Event::WindowMinimized
+            }
+            (true, false) => {
+                self.properties.display_mode = self.restored_display_mode;
+                Event::WindowRestored
+            }
+            _ => Event::Unrecognized,
+        }
+    }
+
+    /// Checks if the window manager hides the window
+    /// The `_NET_WM_STATE' property contains `_NET_WM_STATE_HIDDEN' when the window is minimized
+    fn is_hidden(&self) -> Result<bool, ErrorType> {
+        let cookie = self.connection.send_request(&x::GetProperty {
+            delete: false,
+            window: self.window,
+            property: self.atoms.state,
+            r#type: x::ATOM_ATOM,
+            long_offset: 0,
+            long_length: 32,
+        });
+        match self.connection.wait_for_reply(cookie) {
+            Ok(reply) => Ok(reply.value::<x::Atom>().contains(&self.atoms.state_hidden)),
+            Err(err) => {
+                log_error!("Failed to get the X11 linux window state: {:?}", err);
+                Err(ErrorType::Unknown)
+            }
+        }
+    }
+
+    /// Checks if the X server supports XInput 2, needed for the raw mouse motions
+    fn has_xinput2(connection: &xcb::Connection) -> bool {
+        if !connection
+            .active_extensions()
+            .any(|extension| extension == xcb::Extension::Input)
+        {
+            log_warn!("The X11 linux server doesn't have the XInput extension");
+            return false;
+        }
+        let cookie = connection.send_request(&xinput::XiQueryVersion {
+            major_version: 2,
+            minor_version: 0,
+        });
+        match connection.wait_for_reply(cookie) {
+            Ok(reply) if reply.major_version() >= 2 => true,
+            Ok(reply) => {
+                log_warn!(
+                    "The X11 linux server only supports XInput {:?}, the version 2 is needed",
+                    reply.major_version()
+                );
+                false
+            }
+            Err(err) => {
+                log_warn!("Failed to query the X11 linux XInput version: {:?}", err);
+                false
+            }
+        }
+    }
+
+    /// Creates an invisible cursor from an empty 1x1 bitmap
+    fn init_hidden_cursor(
+        connection: &xcb::Connection,
+        window: x::Window,
+    ) -> Result<x::Cursor, ErrorType> {
+        let pixmap: x::Pixmap = connection.generate_id();
+        let cookie = connection.send_request_checked(&x::CreatePixmap {
+            depth: 1,
+            pid: pixmap,
+            drawable: x::Drawable::Window(window),
+            width: 1,
+            height: 1,
+        });
+        if let Err(err) = connection.check_request(cookie) {
+            log_error!(
+                "Failed to create the bitmap of the X11 linux hidden cursor: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+
+        // The content of a new pixmap is undefined
+        let gc: x::Gcontext = connection.generate_id();
+        let _cookie = connection.send_request(&x::CreateGc {
+            cid: gc,
+            drawable: x::Drawable::Pixmap(pixmap),
+            value_list: &[x::Gc::Foreground(0)],
+        });
+        let _cookie = connection.send_request(&x::PolyFillRectangle {
+            drawable: x::Drawable::Pixmap(pixmap),
+            gc,
+            rectangles: &[x::Rectangle {
+                x: 0,
+                y: 0,
+                width: 1,
+                height: 1,
+            }],
+        });
+
+        let cursor: x::Cursor = connection.generate_id();
+        let cookie = connection.send_request_checked(&x::CreateCursor {
+            cid: cursor,
+            source: pixmap,
+            mask: pixmap,
+            fore_red: 0,
+            fore_green: 0,
+            fore_blue: 0,
+            back_red: 0,
+            back_green: 0,
+            back_blue: 0,
+            x: 0,
+            y: 0,
+        });
+        let result = connection.check_request(cookie);
+        let _cookie = connection.send_request(&x::FreeGc { gc });
+        let _cookie = connection.send_request(&x::FreePixmap { pixmap });
+        if let Err(err) = result {
+            log_error!("Failed to create the X11 linux hidden cursor: {:?}", err);
+            return Err(ErrorType::Unknown);
+        }
+        Ok(cursor)
+    }
+
+    /// Finds the XRender picture format of the 32 bits ARGB images
+    fn get_argb32_format(&self) -> Result<render::Pictformat, ErrorType> {
+        let cookie = self.connection.send_request(&render::QueryPictFormats {});
+        let reply = match self.connection.wait_for_reply(cookie) {
+            Ok(reply) => reply,
+            Err(err) => {
+                log_error!(
+                    "Failed to query the X11 linux XRender picture formats: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        let format = reply.formats().iter().find(|format| {
+            let direct = format.direct();
+            format.r#type() == render::PictType::Direct
+                && format.depth() == 32
+                && direct.alpha_shift == 24
+                && direct.alpha_mask == 0xff
+                && direct.red_shift == 16
+                && direct.red_mask == 0xff
+                && direct.green_shift == 8
+                && direct.green_mask == 0xff
+                && direct.blue_shift == 0
+                && direct.blue_mask == 0xff
+        });
+        match format {
+            Some(format) => Ok(format.id()),
+            None => {
+                log_error!("The X11 linux server doesn't have an ARGB32 picture format");
+                Err(ErrorType::NotSupported)
+            }
+        }
+    }
+
+    /// Creates a colored cursor from an image with XRender
+    fn init_cursor(&self, image: &CursorImage) -> Result<x::Cursor, ErrorType> {
+        image.check()?;
+        if !self
+            .connection
+            .active_extensions()
+            .any(|extension| extension == xcb::Extension::Render)
+        {
+            log_error!("The X11 linux colored cursors need the XRender extension");
+            return Err(ErrorType::NotSupported);
+        }
+        let format = self.get_argb32_format()?;
+
+        // The pixels are premultiplied ARGB words in the server's byte order
+        let is_lsb_first =
+            self.connection.get_setup().image_byte_order() == x::ImageOrder::LsbFirst;
+        let data: Vec<u8> = image
+            .pixels
+            .chunks_exact(4)
+            .flat_map(|pixel| {
+                let alpha = pixel[3] as u32;
+                let premultiply = |channel: u8| (channel as u32) * alpha / 255;
+                let word = (alpha << 24)
+                    | (premultiply(pixel[0]) << 16)
+                    | (premultiply(pixel[1]) << 8)
+                    | premultiply(pixel[2]);
+                if is_lsb_first {
+                    word.to_le_bytes()
+                } else {
+                    word.to_be_bytes()
+                }
+            })
+            .collect();
+
+        let pixmap: x::Pixmap = self.connection.generate_id();
+        let gc: x::Gcontext = self.connection.generate_id();
+        let picture: render::Picture = self.connection.generate_id();
+        let cursor: x::Cursor = self.connection.generate_id();
+        let cookies = [
+            self.connection.send_request_checked(&x::CreatePixmap {
+                depth: 32,
+                pid: pixmap,
+                drawable: x::Drawable::Window(self.window),
+                width: image.width,
+                height: image.height,
+            }),
+            self.connection.send_request_checked(&x::CreateGc {
+                cid: gc,
+                drawable: x::Drawable::Pixmap(pixmap),
+                value_list: &[],
+            }),
+            self.connection.send_request_checked(&x::PutImage {
+                format: x::ImageFormat::ZPixmap,
+                drawable: x::Drawable::Pixmap(pixmap),
+                gc,
+                width: image.width,
+                height: image.height,
+                dst_x: 0,
+                dst_y: 0,
+                left_pad: 0,
+                depth: 32,
+                data: &data,
+            }),
+            self.connection
+                .send_request_checked(&render::CreatePicture {
+                    pid: picture,
+                    drawable: x::Drawable::Pixmap(pixmap),
+                    format,
+                    value_list: &[],
+                }),
+            self.connection.send_request_checked(&render::CreateCursor {
+                cid: cursor,
+                source: picture,
+                x: image.hotspot.0,
+                y: image.hotspot.1,
+            }),
+        ];
+        let result = cookies
+            .into_iter()
+            .try_for_each(|cookie| self.connection.check_request(cookie));
+
+        // The cursor keeps its own copy of the image
+        let _cookie = self
+            .connection
+            .send_request(&render::FreePicture { picture });
+        let _cookie = self.connection.send_request(&x::FreeGc { gc });
+        let _cookie = self.connection.send_request(&x::FreePixmap { pixmap });
+        if let Err(err) = result {
+            log_error!(
+                "Failed to create the X11 linux cursor from a {:?}x{:?} image: {:?}",
+                image.width,
+                image.height,
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+        Ok(cursor)
+    }
+
+    /// Shows the cursor matching the visibility, grab mode and image of the window
+    fn apply_cursor(&self) -> Result<(), ErrorType> {
+        let cursor = if !self.is_cursor_visible || self.cursor_grab_mode == CursorGrabMode::Relative
+        {
+            self.hidden_cursor
+        } else {
+            self.custom_cursor
+        };
+        let cookie = self
+            .connection
+            .send_request_checked(&x::ChangeWindowAttributes {
+                window: self.window,
+                value_list: &[x::Cw::Cursor(cursor)],
+            });
+        if let Err(err) = self.connection.check_request(cookie) {
+            log_error!("Failed to change the X11 linux window cursor: {:?}", err);
+            return Err(ErrorType::Unknown);
+        };
+        Ok(())
+    }
+
+    /// Grabs the pointer if the window is focused and wants to hold it, releases it otherwise
+    fn apply_cursor_grab(&self) -> Result<(), ErrorType> {
+        if self.cursor_grab_mode == CursorGrabMode::None || !self.is_focused {
+            let cookie = self.connection.send_request_checked(&x::UngrabPointer {
+                time: x::CURRENT_TIME,
+            });
+            if let Err(err) = self.connection.check_request(cookie) {
+                log_error!("Failed to release the X11 linux pointer: {:?}", err);
+                return Err(ErrorType::Unknown);
+            };
+            return Ok(());
+        }
+
+        let cookie = self.connection.send_request(&x::GrabPointer {
+            owner_events: true,
+            grab_window: self.window,
+            event_mask: x::EventMask::BUTTON_PRESS
+                | x::EventMask::BUTTON_RELEASE
+                | x::EventMask::POINTER_MOTION
+                | x::EventMask::BUTTON_MOTION,
+            pointer_mode: x::GrabMode::Async,
+            keyboard_mode: x::GrabMode::Async,
+            confine_to: self.window,
+            cursor: x::CURSOR_NONE,
+            time: x::CURRENT_TIME,
+        });
+        match self.connection.wait_for_reply(cookie) {
+            Ok(reply) if reply.status() == x::GrabStatus::Success => Ok(()),
+            // The grab is tried again when the window gets the focus back
+            Ok(reply) => {
+                log_warn!(
+                    "The X11 linux pointer can't be grabbed for now: {:?}",
+                    reply.status()
+                );
+                Ok(())
+            }
+            Err(err) => {
+                log_error!("Failed to grab the X11 linux pointer: {:?}", err);
+                Err(ErrorType::Unknown)
+            }
+        }
+    }
+
+    /// Starts or stops listening to the raw mouse motions
+    /// XInput 2 only sends them to the root window
+    fn select_raw_motion(&self, is_enabled: bool) -> Result<(), ErrorType> {
+        let mask = if is_enabled {
+            xinput::XiEventMask::RAW_MOTION
+        } else {
+            xinput::XiEventMask::empty()
+        };
+        let cookie = self
+            .connection
+            .send_request_checked(&xinput::XiSelectEvents {
+                window: self.screen.root,
+                masks: &[xinput::EventMaskBuf::new(
+                    xinput::Device::AllMaster,
+                    &[mask],
+                )],
+            });
+        if let Err(err) = self.connection.check_request(cookie) {
+            log_error!(
+                "Failed to select the X11 linux XInput raw motion events: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        };
+        Ok(())
+    }
+
+    /// Converts a raw XInput 2 motion into a relative mouse motion
+    /// The motions are only sent in the relative cursor mode, while the window is focused
+    fn get_raw_motion(&self, event: &xinput::RawMotionEvent) -> Event {
+        if self.cursor_grab_mode != CursorGrabMode::Relative || !self.is_focused {
+            return Event::Unrecognized;
+        }
+        // The values are only given for the axes in the mask, the x and y axes being the first ones
+        let axes_mask = event.valuator_mask().first().copied().unwrap_or(0);
+        let mut values = event.axisvalues_raw().iter();
+        let mut deltas = [0f32; 2];
+        for (axis, delta) in deltas.iter_mut().enumerate() {
+            if axes_mask & (1 << axis) != 0
+                && let Some(value) = values.next()
+            {
+                *delta = (value.integral as f32) + (value.frac as f32) / ((1u64 << 32) as f32);
+            }
+        }
+        if deltas == [0., 0.] {
+            return Event::Unrecognized;
+        }
+        // The X11 y axis points down
+        Event::MouseMotionRaw(deltas[0], -deltas[1])
+    }
+
+    /// Sets the window's title
+    /// `WM_NAME' is the Latin-1 fallback of the UTF-8 `_NET_WM_NAME' used by modern window managers
+    fn change_title(
+        connection: &xcb::Connection,
+        window: x::Window,
+        atoms: &LinuxX11Atoms,
+        title: &str,
+    ) -> Result<(), xcb::ProtocolError> {
+        let latin1_title: Vec<u8> = title
+            .chars()
+            .map(|character| u8::try_from(u32::from(character)).unwrap_or(b'?'))
+            .collect();
+        let wm_name_cookie = connection.send_request_checked(&x::ChangeProperty {
+            mode: x::PropMode::Replace,
+            window,
+            property: x::ATOM_WM_NAME,
+            r#type: x::ATOM_STRING,
+            data: latin1_title.as_slice(),
+        });
+        let net_wm_name_cookie = connection.send_request_checked(&x::ChangeProperty {
+            mode: x::PropMode::Replace,
+            window,
+            property: atoms.net_wm_name,
+            r#type: atoms.utf8_string,
+            data: title.as_bytes(),
+        });
+        connection.check_request(wm_name_cookie)?;
+        connection.check_request(net_wm_name_cookie)
+    }
+
+    /// Sends a client message about the window to the window manager
+    fn send_to_window_manager(
+        &self,
+        message_type: x::Atom,
+        data: [u32; 5],
+    ) -> Result<(), ErrorType> {
+        let event = x::ClientMessageEvent::new(
+            self.window,
+            message_type,
+            x::ClientMessageData::Data32(data),
+        );
+        let cookie = self.connection.send_request_checked(&x::SendEvent {
+            propagate: false,
+            destination: x::SendEventDest::Window(self.screen.root),
+            event_mask: x::EventMask::SUBSTRUCTURE_NOTIFY | x::EventMask::SUBSTRUCTURE_REDIRECT,
+            event: &event,
+        });
+        if let Err(err) = self.connection.check_request(cookie) {
+            log_error!(
+                "Failed to send a client message to the X11 linux window manager: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        };
+        Ok(())
+    }
+
+    /// Converts an X11 event into a platform event
+    fn convert_event(&mut self, event: xcb::Event) -> Result<Event, ErrorType> {
+        match event {
+            // Keyboard events
+            xcb::Event::X(x::Event::KeyPress(event)) => {
+                // The typed text is sent after the key press
+                let text = self.text_input.get_text(&event, self.window);
+                self.pending_events
+                    .extend(text.into_iter().map(Event::TextInput));
+                let (key, physical_key, scancode, modifiers) =
+                    self.get_keyboard_event(event.detail(), event.state(), true);
+                Ok(Event::KeyboardKeyPressed(
+                    key,
+                    physical_key,
+                    scancode,
+                    modifiers,
+                ))
+            }
+            xcb::Event::X(x::Event::KeyRelease(event)) => {
+                let (key, physical_key, scancode, modifiers) =
+                    self.get_keyboard_event(event.detail(), event.state(), false);
+                Ok(Event::KeyboardKeyReleased(
+                    key,
+                    physical_key,
+                    scancode,
+                    modifiers,
+                ))
+            }
+            // Mouse events
+            xcb::Event::X(x::Event::ButtonPress(event)) => Ok(match event.detail() {
+                // The wheel notches are sent as presses of the buttons 4 to 7
+                4 => Event::MouseScrolled(1.),
+                5 => Event::MouseScrolled(-1.),
+                6 => Event::MouseScrolledHorizontally(-1.),
+                7 => Event::MouseScrolledHorizontally(1.),
+                button => match Self::get_mouse_button(button) {
+                    Some(button) => Event::MouseButtonPressed(button),
+                    None => Event::Unrecognized,
+                },
+            }),
+            xcb::Event::X(x::Event::ButtonRelease(event)) => {
+                match Self::get_mouse_button(event.detail()) {
+                    Some(button) => Ok(Event::MouseButtonReleased(button)),
+                    None => Ok(Event::Unrecognized),
+                }
+            }
+            // The absolute positions are not sent in the relative cursor mode
+            xcb::Event::X(x::Event::MotionNotify(_))
+                if self.cursor_grab_mode == CursorGrabMode::Relative =>
+            {
+                Ok(Event::Unrecognized)
+            }
+            xcb::Event::X(x::Event::MotionNotify(event)) => {
+                let (x, y) = self.get_mouse_position(event.event_x(), event.event_y());
+                match Self::get_pressed_mouse_button(event.state()) {
+                    Some(button) => Ok(Event::MouseMovedAndButton(x, y, button)),
+                    None => Ok(Event::MouseMoved(x, y)),
+                }
+            }
+            xcb::Event::Input(xinput::Event::RawMotion(event)) => Ok(self.get_raw_motion(&event)),
+            // The crossings caused by pointer grabs are ignored
+            xcb::Event::X(x::Event::EnterNotify(event)) => {
+                if event.mode() != x::NotifyMode::Normal {
+                    return Ok(Event::Unrecognized);
+                }
+                let (x, y) = self.get_mouse_position(event.event_x(), event.event_y());
+                Ok(Event::MouseEnteredWindow(x, y))
+            }
+            xcb::Event::X(x::Event::LeaveNotify(event)) => {
+                if event.mode() != x::NotifyMode::Normal {
+                    return Ok(Event::Unrecognized);
+                }
+                let (x, y) = self.get_mouse_position(event.event_x(), event.event_y());
+                Ok(Event::MouseLeftWindow(x, y))
+            }
+            // Client message events
+            xcb::Event::X(x::Event::ClientMessage(event)) => {
+                if event.r#type() == self.atoms.state {
+                    if let x::ClientMessageData::Data32([_, first_property, second_property, ..]) =
+                        event.data()
+                    {
+                        // Window maximized
+                        if first_property == self.atoms.state_maximized_horz.resource_id()
+                            || first_property == self.atoms.state_maximized_vert.resource_id()
+                            || second_property == self.atoms.state_maximized_horz.resource_id()
+                            || second_property == self.atoms.state_maximized_vert.resource_id()
+                        {
+                            return Ok(self.set_minimized(false));
+                        }
+                        // Window minimized
+                        else if first_property == self.atoms.state_hidden.resource_id() {
+                            return Ok(self.set_minimized(true));
+                        }
+                    }
+                } else if event.r#type() == self.atoms.protocols
+                    && let x::ClientMessageData::Data32([atom, ..]) = event.data()
+                {
+                    // Window closed
+                    if atom == self.atoms.delete_window.resource_id() {
+                        return Ok(Event::WindowClosed);
+                    }
+                } else if event.window() != self.window {
+                    // Input method server messages
+                    let text = self.text_input.filter_client_message(&event);
+                    self.pending_events
+                        .extend(text.into_iter().map(Event::TextInput));
+                    return Ok(Event::Unrecognized);
+                }
+                log_debug!("Unknown X11 linux client message event");
+                Ok(Event::Unrecognized)
+            }
+            // Configure notify events
+            xcb::Event::X(x::Event::ConfigureNotify(event)) => {
+                let width = (event.width() as f32) / (self.screen.width as f32);
+                let height = (event.height() as f32) / (self.screen.height as f32);
+
+                // Detect if the size has changed and trigger the corresponding event
+                if width != self.properties.width || height != self.properties.height {
+                    // Update the window's properties
+                    self.properties.width = width;
+                    self.properties.height = height;
+
+                    return Ok(Event::WindowResized(width, height));
+                }
+                Ok(Event::Unrecognized)
+            }
+            xcb::Event::X(x::Event::Expose(_)) => Ok(Event::Expose),
+
+            // Focus events
+            // The focus changes caused by keyboard grabs, like the window manager's shortcuts, are ignored
+            xcb::Event::X(x::Event::FocusIn(event)) => match event.mode() {
+                x::NotifyMode::Grab | x::NotifyMode::Ungrab => Ok(Event::Unrecognized),
+                _ => Ok(self.set_focused(true)),
+            },
+            xcb::Event::X(x::Event::FocusOut(event)) => match event.mode() {
+                x::NotifyMode::Grab | x::NotifyMode::Ungrab => Ok(Event::Unrecognized),
+                _ => Ok(self.set_focused(false)),
+            },
+
+            // Keyboard layout events
+            xcb::Event::X(x::Event::MappingNotify(event)) => {
+                if event.request() == x::Mapping::Pointer {
+                    return Ok(Event::Unrecognized);
+                }
+                self.text_input.refresh_keyboard_mapping(&event);
+                if event.request() == x::Mapping::Keyboard {
+                    self.keymap = match Self::get_keymap(&self.connection) {
+                        Ok(keymap) => keymap,
+                        Err(err) => {
+                            log_error!(
+                                "Failed to fetch the keymap when handling an X11 linux mapping event: {:?}",
+                                err
+                            );
+                            return Err(ErrorType::Unknown);
+                        }
+                    };
+                }
+                Ok(Event::Unrecognized)
+            }
+
+            // Visibility events
+            xcb::Event::X(x::Event::MapNotify(_)) => Ok(self.set_minimized(false)),
+            xcb::Event::X(x::Event::UnmapNotify(_)) => Ok(self.set_minimized(true)),
+            xcb::Event::X(x::Event::PropertyNotify(event)) => {
+                if event.atom() != self.atoms.state {
+                    return Ok(Event::Unrecognized);
+                }
+                let is_hidden = match self.is_hidden() {
+                    Ok(is_hidden) => is_hidden,
+                    Err(err) => {
+                        log_error!(
+                            "Failed to read the window state when handling an X11 linux property event: {:?}",
+                            err
+                        );
+                        return Err(ErrorType::Unknown);
+                    }
+                };
+                Ok(self.set_minimized(is_hidden))
+            }
+
+            // TODO: other events
+            _ => {
+                // log_debug!("Unknown X11 linux window event");
+                Ok(Event::Unrecognized)
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////
+///////////////   X11 window tests   /////////////////////
+//////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn physical_key() {
+        // The keycodes don't depend on the layout
+        assert_eq!(
+            LinuxX11Window::get_physical_key(25),
+            KeyboardKey::AlphaNumeric(AlphaNumeric::W)
+        );
+        assert_eq!(LinuxX11Window::get_scancode(25), 17);
+        assert_eq!(
+            LinuxX11Window::get_physical_key(50),
+            KeyboardKey::Modifier(Modifier::ShiftLeft)
+        );
+        assert_eq!(
+            LinuxX11Window::get_physical_key(9),
+            KeyboardKey::Special(Special::Escape)
+        );
+        assert_eq!(
+            LinuxX11Window::get_physical_key(255),
+            KeyboardKey::Unrecognized
+        );
+        assert_eq!(LinuxX11Window::get_scancode(255), 247);
+    }
+
+    #[test]
+    fn keyboard_modifiers() {
+        let shift = KeyboardKey::Modifier(Modifier::ShiftLeft);
+        // The state doesn't include the modifier key of the event yet
+        let modifiers = LinuxX11Window::get_keyboard_modifiers(x::KeyButMask::empty(), shift, true);
+        assert!(modifiers.is_shift_down);
+        let modifiers = LinuxX11Window::get_keyboard_modifiers(x::KeyButMask::SHIFT, shift, false);
+        assert!(!modifiers.is_shift_down);
+
+        let modifiers = LinuxX11Window::get_keyboard_modifiers(
+            x::KeyButMask::CONTROL | x::KeyButMask::MOD2 | x::KeyButMask::LOCK,
+            KeyboardKey::AlphaNumeric(AlphaNumeric::W),
+            true,
+        );
+        assert_eq!(
+            modifiers,
+            KeyboardModifiers {
+                is_control_down: true,
+                is_caps_lock_on: true,
+                is_num_lock_on: true,
+                ..Default::default()
+            }
+        );
+
+        // Both 'Alt' keys count, 'AltGr' being held through the fifth modifier
+        let alt_right = KeyboardKey::Modifier(Modifier::AltRight);
+        let modifiers =
+            LinuxX11Window::get_keyboard_modifiers(x::KeyButMask::empty(), alt_right, true);
+        assert!(modifiers.is_alt_down);
+        let modifiers =
+            LinuxX11Window::get_keyboard_modifiers(x::KeyButMask::MOD5, alt_right, false);
+        assert!(!modifiers.is_alt_down);
+        let modifiers = LinuxX11Window::get_keyboard_modifiers(
+            x::KeyButMask::MOD5,
+            KeyboardKey::AlphaNumeric(AlphaNumeric::E),
+            true,
+        );
+        assert!(modifiers.is_alt_down);
+    }
 }
@@ -1,7 +1,15 @@
 /// Implements the Linux platforms
-#[cfg(linux_platform)]
+#[cfg(all(linux_platform, not(headless_platform)))]
 mod linux;
-#[cfg(linux_platform)]
+#[cfg(all(linux_platform, not(headless_platform)))]
 pub use linux::PlatformLayerImpl;
-#[cfg(linux_platform)]
+#[cfg(all(linux_platform, not(headless_platform)))]
+pub use std::sync::RwLock as PlatformLayerRwLock;
+
+/// Implements a platform without any window system
+#[cfg(headless_platform)]
+mod headless;
+#[cfg(headless_platform)]
+pub use headless::PlatformLayerImpl;
+#[cfg(headless_platform)]
 pub use std::sync::RwLock as PlatformLayerRwLock;
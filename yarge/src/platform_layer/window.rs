@@ -1,10 +1,18 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
-use crate::{config::Config, maths::Vector2f32};
+use crate::{
+    config::Config,
+    maths::Vector2f32,
+    mouse::{CursorGrabMode, CursorImage},
+};
 
 use super::event::Event;
 
+/// The id of the window created with the platform layer
+/// The application quits when it is closed
+pub(crate) const MAIN_WINDOW_ID: u8 = 0;
+
 #[allow(unused)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 /// Tells how the window should be displayed
@@ -56,13 +64,14 @@ pub(crate) struct WindowCommonProperties {
     pub(crate) display_mode: DisplayMode,
 }
 
+#[allow(private_interfaces)]
 /// Abstract trait for a window
 /// The window's position is such that:
 /// [0.,0.] is the top left corner of the monitor
 /// [1.,0.] is the top right corner of the monitor
 /// [0.,1.] is the bottom left corner of the monitor
 /// [1.,1.] is the bottom right corner of the monitor
-pub(crate) trait Window {
+pub trait Window {
     /// The type of the struct implementing the trait
     /// This would often be `Self`
     type WindowType;
@@ -81,8 +90,41 @@ pub(crate) trait Window {
     /// Gets the framebuffer's height in pixels
     fn get_framebuffer_height(&self) -> u16;
 
-    /// Poll the next event
-    fn poll_event(&mut self) -> Result<Event, ErrorType>;
+    /// Poll the next pending event without blocking
+    /// Returns None when there is no pending event
+    fn poll_event(&mut self) -> Result<Option<Event>, ErrorType>;
+
+    /// Sets the window's title
+    fn set_title(&mut self, title: &str) -> Result<(), ErrorType>;
+
+    /// Moves and resizes the window
+    /// The position, width and height are given between 0. and 1.
+    fn set_geometry(
+        &mut self,
+        position: Vector2f32,
+        width: f32,
+        height: f32,
+    ) -> Result<(), ErrorType>;
+
+    /// Changes how the window is displayed
+    /// The geometry of a floating mode, if any, moves and resizes the window
+    fn set_display_mode(&mut self, display_mode: DisplayMode) -> Result<(), ErrorType>;
+
+    /// Shows or hides the decorations drawn by the window manager, like the title bar
+    fn set_decorations(&mut self, has_decorations: bool) -> Result<(), ErrorType>;
+
+    /// Sets the width of the window's border in pixels
+    fn set_border_width(&mut self, border_width: u16) -> Result<(), ErrorType>;
+
+    /// Shows or hides the cursor when it is over the window
+    fn set_cursor_visible(&mut self, is_visible: bool) -> Result<(), ErrorType>;
+
+    /// Changes how the cursor is held by the window
+    /// The grab is released while the window is unfocused
+    fn set_cursor_grab(&mut self, grab_mode: CursorGrabMode) -> Result<(), ErrorType>;
+
+    /// Sets the cursor image shown over the window, None to use the default one
+    fn set_cursor_image(&mut self, image: Option<&CursorImage>) -> Result<(), ErrorType>;
 
     /// Swaps the color buffer and show it as output to the screen
     #[cfg(opengl_renderer)]
@@ -35,7 +35,11 @@ pub(crate) fn culling_system(
                 }
             };
             // TODO: handle multiple meshes in obj file
-            let aabb = match AABB::from_mesh(&obj_file.meshes[0]) {
+            // An empty obj file, like the fallback of a missing one, has nothing to draw
+            let Some(first_mesh) = obj_file.meshes.first() else {
+                continue 'all_entities_loop;
+            };
+            let aabb = match AABB::from_mesh(first_mesh) {
                 Ok(mut aabb) => {
                     aabb.as_world(&mesh_transform.get_model());
                     aabb.as_view(&camera.get_view(camera_transform));
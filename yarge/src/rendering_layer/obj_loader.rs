@@ -166,4 +166,9 @@ impl ResourceLoadingParameters<ObjFile> for std::path::PathBuf {
 
         Ok(ObjFile { meshes })
     }
+
+    /// A missing obj file is replaced by an empty one, nothing is drawn
+    fn get_fallback_resource(&self) -> Option<ObjFile> {
+        Some(ObjFile { meshes: Vec::new() })
+    }
 }
@@ -26,4 +26,24 @@ pub(crate) trait RenderingLayer<'a> {
     /// Ends a frame just before rendering
     /// TODO: add frame data as parameter
     fn end_frame(&mut self, platform_layer: &mut PlatformLayerImpl) -> Result<(), ErrorType>;
+
+    /// Applies the present mode of a reloaded configuration
+    fn update_present_mode(
+        &mut self,
+        config: &Config,
+        platform_layer: &mut PlatformLayerImpl,
+    ) -> Result<(), ErrorType>;
+
+    /// Creates what is needed to render to a secondary window
+    /// The window must already be created by the platform layer
+    fn init_window(
+        &mut self,
+        config: &Config,
+        platform_layer: &mut PlatformLayerImpl,
+        window_id: u8,
+    ) -> Result<(), ErrorType>;
+
+    /// Destroys what is needed to render to a secondary window
+    /// It must be called before the platform layer destroys the window
+    fn shutdown_window(&mut self, window_id: u8) -> Result<(), ErrorType>;
 }
@@ -5,7 +5,10 @@ use crate::{
     PlatformLayer, PlatformLayerImpl, RenderingLayer,
     rendering_layer::types::RendererBeginFrameOutput,
 };
-use crate::{config::Config, platform_layer::window::Window};
+use crate::{
+    config::Config,
+    platform_layer::window::{MAIN_WINDOW_ID, Window},
+};
 
 use super::context::OpenglContext;
 
@@ -28,7 +31,18 @@ impl<'a> RenderingLayer<'a> for OpenglRenderingLayer<'a> {
             }
         };
 
-        if let Err(err) = platform_layer.get_window(0).opengl_make_context_current() {
+        let window = match platform_layer.get_window(MAIN_WINDOW_ID) {
+            Ok(window) => window,
+            Err(err) => {
+                log_error!(
+                    "Failed to get the main window when initializing OpenGL: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+
+        if let Err(err) = window.opengl_make_context_current() {
             log_error!(
                 "Failed to make the context current when initializing OpenGL: {:?}",
                 err
@@ -36,7 +50,7 @@ impl<'a> RenderingLayer<'a> for OpenglRenderingLayer<'a> {
             return Err(ErrorType::Unknown);
         };
 
-        if let Err(err) = platform_layer.get_window(0).opengl_load_functions() {
+        if let Err(err) = window.opengl_load_functions() {
             log_error!(
                 "Failed to load functions when initializing OpenGL: {:?}",
                 err
@@ -60,7 +74,17 @@ impl<'a> RenderingLayer<'a> for OpenglRenderingLayer<'a> {
     }
 
     fn end_frame(&mut self, platform_layer: &mut PlatformLayerImpl) -> Result<(), ErrorType> {
-        if let Err(err) = platform_layer.get_window(0).opengl_swap_buffers() {
+        let window = match platform_layer.get_window(MAIN_WINDOW_ID) {
+            Ok(window) => window,
+            Err(err) => {
+                log_error!(
+                    "Failed to get the main window when ending an OpenGL frame: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+        if let Err(err) = window.opengl_swap_buffers() {
             log_error!(
                 "Failed to swap the buffers when ending an OpenGL frame: {:?}",
                 err
@@ -70,4 +94,30 @@ impl<'a> RenderingLayer<'a> for OpenglRenderingLayer<'a> {
 
         Ok(())
     }
+
+    fn update_present_mode(
+        &mut self,
+        _config: &Config,
+        _platform_layer: &mut PlatformLayerImpl,
+    ) -> Result<(), ErrorType> {
+        log_warn!("The OpenGL renderer doesn't support changing the present mode");
+        Ok(())
+    }
+
+    fn init_window(
+        &mut self,
+        _config: &Config,
+        _platform_layer: &mut PlatformLayerImpl,
+        window_id: u8,
+    ) -> Result<(), ErrorType> {
+        log_warn!(
+            "The OpenGL renderer only renders to the main window, the window `{:?}' stays empty",
+            window_id
+        );
+        Ok(())
+    }
+
+    fn shutdown_window(&mut self, _window_id: u8) -> Result<(), ErrorType> {
+        Ok(())
+    }
 }
@@ -1,9 +1,12 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Serialize};
+
 use crate::{config::Version, rendering_layer::types::formats::ImageFormat};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 /// The config for an OpenGl context
 pub(crate) struct OpenglConfig {
     /// The opengl version
@@ -4,12 +4,24 @@ use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 use crate::{
     PlatformLayerImpl,
     config::Config,
+    platform_layer::window::MAIN_WINDOW_ID,
     rendering_layer::rendering_impl::vulkan::init::{
         allocator, commands, debug_messenger, device, entry, instance, physical_device, surface,
         swapchain,
     },
 };
 
+/// The surface and swapchain of a secondary window
+pub(in crate::rendering_layer::rendering_impl::vulkan) struct VkWindowTarget {
+    /// The id of the window in the platform layer
+    pub(in crate::rendering_layer::rendering_impl::vulkan) window_id: u8,
+    /// The window surface
+    pub(in crate::rendering_layer::rendering_impl::vulkan) surface_wrapper: surface::VkSurface,
+    /// The swapchain
+    pub(in crate::rendering_layer::rendering_impl::vulkan) swapchain_wrapper:
+        swapchain::VkSwapchain,
+}
+
 /// The Vulkan context
 pub(in crate::rendering_layer::rendering_impl::vulkan) struct VulkanContext<'a> {
     /// The entry
@@ -22,15 +34,17 @@ pub(in crate::rendering_layer::rendering_impl::vulkan) struct VulkanContext<'a>
     /// The debug messenger
     pub(in crate::rendering_layer::rendering_impl::vulkan) debug_messenger:
         Option<debug_messenger::VkDebugMessenger>,
-    /// The window surface
+    /// The main window surface
     pub(in crate::rendering_layer::rendering_impl::vulkan) surface_wrapper: surface::VkSurface,
     /// The physical device
     pub(in crate::rendering_layer::rendering_impl::vulkan) physical_device: ash::vk::PhysicalDevice,
     /// The logical device
     pub(in crate::rendering_layer::rendering_impl::vulkan) device_wrapper: device::VkDevice,
-    /// The swapchain
+    /// The main window swapchain
     pub(in crate::rendering_layer::rendering_impl::vulkan) swapchain_wrapper:
         swapchain::VkSwapchain,
+    /// The surfaces and swapchains of the secondary windows
+    pub(in crate::rendering_layer::rendering_impl::vulkan) secondary_windows: Vec<VkWindowTarget>,
     /// The command pool
     pub(in crate::rendering_layer::rendering_impl::vulkan) command_pool: commands::VkCommandPool,
 }
@@ -86,17 +100,22 @@ impl VulkanContext<'_> {
                 }
             };
 
-        let surface_wrapper =
-            match surface::init_surface(platform_layer, &entry, &instance, allocator.as_ref()) {
-                Ok(surface) => surface,
-                Err(err) => {
-                    log_error!(
-                        "Failed to initialize the surface in the Vulkan context: {:?}",
-                        err
-                    );
-                    return Err(ErrorType::Unknown);
-                }
-            };
+        let surface_wrapper = match surface::init_surface(
+            platform_layer,
+            MAIN_WINDOW_ID,
+            &entry,
+            &instance,
+            allocator.as_ref(),
+        ) {
+            Ok(surface) => surface,
+            Err(err) => {
+                log_error!(
+                    "Failed to initialize the surface in the Vulkan context: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
 
         let physical_device = match physical_device::init_physical_device(config, &instance) {
             Ok(device) => device,
@@ -129,6 +148,7 @@ impl VulkanContext<'_> {
         let swapchain_wrapper = match swapchain::init_swapchain(
             config,
             platform_layer,
+            MAIN_WINDOW_ID,
             &entry,
             &instance,
             &physical_device,
@@ -167,10 +187,204 @@ impl VulkanContext<'_> {
             physical_device,
             device_wrapper,
             swapchain_wrapper,
+            secondary_windows: Vec::new(),
             command_pool,
         })
     }
 
+    /// Recreates the swapchains of every window, for instance when their configuration changed
+    pub(in crate::rendering_layer::rendering_impl::vulkan) fn recreate_swapchain(
+        &mut self,
+        config: &Config,
+        platform_layer: &PlatformLayerImpl,
+    ) -> Result<(), ErrorType> {
+        // The swapchain images may still be in use
+        if let Err(err) = unsafe { self.device_wrapper.device.device_wait_idle() } {
+            log_error!(
+                "Failed to wait for the Vulkan device when recreating the swapchain: {:?}",
+                err
+            );
+            return Err(ErrorType::VulkanError);
+        }
+
+        let allocator = self.allocator.as_ref();
+        swapchain::shutdown_swapchain(&self.device_wrapper, &self.swapchain_wrapper, allocator);
+
+        self.swapchain_wrapper = match swapchain::init_swapchain(
+            config,
+            platform_layer,
+            MAIN_WINDOW_ID,
+            &self.entry,
+            &self.instance,
+            &self.physical_device,
+            &self.device_wrapper,
+            &self.surface_wrapper,
+            allocator,
+        ) {
+            Ok(swapchain) => swapchain,
+            Err(err) => {
+                log_error!(
+                    "Failed to initialize the swapchain when recreating it in the Vulkan context: {:?}",
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+
+        for target in &mut self.secondary_windows {
+            swapchain::shutdown_swapchain(
+                &self.device_wrapper,
+                &target.swapchain_wrapper,
+                allocator,
+            );
+
+            target.swapchain_wrapper = match swapchain::init_swapchain(
+                config,
+                platform_layer,
+                target.window_id,
+                &self.entry,
+                &self.instance,
+                &self.physical_device,
+                &self.device_wrapper,
+                &target.surface_wrapper,
+                allocator,
+            ) {
+                Ok(swapchain) => swapchain,
+                Err(err) => {
+                    log_error!(
+                        "Failed to initialize the swapchain of the window `{:?}' when recreating it in the Vulkan context: {:?}",
+                        target.window_id,
+                        err
+                    );
+                    return Err(ErrorType::Unknown);
+                }
+            };
+        }
+
+        log_info!("Vulkan swapchain recreated");
+        Ok(())
+    }
+
+    /// Creates the surface and swapchain of a secondary window
+    pub(in crate::rendering_layer::rendering_impl::vulkan) fn init_window(
+        &mut self,
+        config: &Config,
+        platform_layer: &PlatformLayerImpl,
+        window_id: u8,
+    ) -> Result<(), ErrorType> {
+        let allocator = self.allocator.as_ref();
+        let surface_wrapper = match surface::init_surface(
+            platform_layer,
+            window_id,
+            &self.entry,
+            &self.instance,
+            allocator,
+        ) {
+            Ok(surface) => surface,
+            Err(err) => {
+                log_error!(
+                    "Failed to initialize the surface of the window `{:?}' in the Vulkan context: {:?}",
+                    window_id,
+                    err
+                );
+                return Err(ErrorType::Unknown);
+            }
+        };
+
+        // The present queue was selected for the main surface
+        let present_family = self.device_wrapper.queue_families.present.index;
+        match device::check_present_support(&self.physical_device, &surface_wrapper, present_family)
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                log_error!(
+                    "The Vulkan present queue can't present to the surface of the window `{:?}'",
+                    window_id
+                );
+                surface::shutdown_surface(&surface_wrapper, allocator);
+                return Err(ErrorType::NotSupported);
+            }
+            Err(err) => {
+                log_error!(
+                    "Failed to check the present support of the window `{:?}' in the Vulkan context: {:?}",
+                    window_id,
+                    err
+                );
+                surface::shutdown_surface(&surface_wrapper, allocator);
+                return Err(ErrorType::Unknown);
+            }
+        }
+
+        let swapchain_wrapper = match swapchain::init_swapchain(
+            config,
+            platform_layer,
+            window_id,
+            &self.entry,
+            &self.instance,
+            &self.physical_device,
+            &self.device_wrapper,
+            &surface_wrapper,
+            allocator,
+        ) {
+            Ok(swapchain) => swapchain,
+            Err(err) => {
+                log_error!(
+                    "Failed to initialize the swapchain of the window `{:?}' in the Vulkan context: {:?}",
+                    window_id,
+                    err
+                );
+                surface::shutdown_surface(&surface_wrapper, allocator);
+                return Err(ErrorType::Unknown);
+            }
+        };
+
+        self.secondary_windows.push(VkWindowTarget {
+            window_id,
+            surface_wrapper,
+            swapchain_wrapper,
+        });
+        log_info!("Vulkan surface of the window `{:?}' initialized", window_id);
+        Ok(())
+    }
+
+    /// Destroys the surface and swapchain of a secondary window
+    pub(in crate::rendering_layer::rendering_impl::vulkan) fn shutdown_window(
+        &mut self,
+        window_id: u8,
+    ) -> Result<(), ErrorType> {
+        let Some(index) = self
+            .secondary_windows
+            .iter()
+            .position(|target| target.window_id == window_id)
+        else {
+            log_error!(
+                "The window `{:?}' doesn't have a Vulkan surface to shut down",
+                window_id
+            );
+            return Err(ErrorType::DoesNotExist);
+        };
+
+        // The swapchain images may still be in use
+        if let Err(err) = unsafe { self.device_wrapper.device.device_wait_idle() } {
+            log_error!(
+                "Failed to wait for the Vulkan device when shutting down the window `{:?}': {:?}",
+                window_id,
+                err
+            );
+            return Err(ErrorType::VulkanError);
+        }
+
+        let target = self.secondary_windows.remove(index);
+        let allocator = self.allocator.as_ref();
+        swapchain::shutdown_swapchain(&self.device_wrapper, &target.swapchain_wrapper, allocator);
+        surface::shutdown_surface(&target.surface_wrapper, allocator);
+        log_info!(
+            "Vulkan surface of the window `{:?}' shutted down",
+            window_id
+        );
+        Ok(())
+    }
+
     pub(in crate::rendering_layer::rendering_impl::vulkan) fn shutdown(
         &mut self,
     ) -> Result<(), ErrorType> {
@@ -178,6 +392,15 @@ impl VulkanContext<'_> {
 
         commands::shutdown_command_pool(&self.device_wrapper, &mut self.command_pool, allocator);
 
+        for target in self.secondary_windows.drain(..) {
+            swapchain::shutdown_swapchain(
+                &self.device_wrapper,
+                &target.swapchain_wrapper,
+                allocator,
+            );
+            surface::shutdown_surface(&target.surface_wrapper, allocator);
+        }
+
         swapchain::shutdown_swapchain(&self.device_wrapper, &self.swapchain_wrapper, allocator);
 
         device::shutdown_device(&self.device_wrapper, allocator);
@@ -35,12 +35,11 @@ unsafe extern "system" fn pfn_user_callback(
             );
         }
         ash::vk::DebugUtilsMessageSeverityFlagsEXT::WARNING => {
-            log_warn!(
-                "[Vulkan Warning][{:?}][{:?}]\n\t\t{:?}",
-                message_id,
-                types,
-                message
-            );
+            // Logged by the warning collector, repeated warnings are rate limited
+            crate::error::report_warning(crate::error::WarningType::ValidationLayer(format!(
+                "[{:?}][{:?}] {:?}",
+                message_id, types, message
+            )));
         }
         ash::vk::DebugUtilsMessageSeverityFlagsEXT::INFO => {
             log_debug!(
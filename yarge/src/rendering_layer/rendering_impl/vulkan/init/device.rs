@@ -195,7 +195,7 @@ const TRANSFER_QUEUE_PRIORITY: f32 = 1.;
 const PRESENT_QUEUE_PRIORITY: f32 = 1.;
 
 /// Checks if the given physical device supports presenting to a surface
-fn check_present_support(
+pub(in crate::rendering_layer::rendering_impl::vulkan) fn check_present_support(
     physical_device: &ash::vk::PhysicalDevice,
     surface: &VkSurface,
     index: usize,
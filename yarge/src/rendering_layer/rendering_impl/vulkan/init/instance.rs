@@ -2,9 +2,9 @@
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
 use crate::{
-    PlatformLayerImpl,
+    PlatformLayer, PlatformLayerImpl,
     config::{Config, Version},
-    platform_layer::window::Window,
+    platform_layer::window::{MAIN_WINDOW_ID, Window},
     rendering_layer::rendering_impl::types::{
         VkNames,
         extensions::{VkExtension, VkInstanceExtensions},
@@ -228,10 +228,18 @@ fn get_required_extensions(
     entry: &ash::Entry,
     platform_layer: &PlatformLayerImpl,
 ) -> Result<(Vec<VkInstanceExtensions>, VkNames), ErrorType> {
-    let mut required_extensions = match platform_layer
-        .window
-        .vulkan_get_required_instance_extensions()
-    {
+    let window = match platform_layer.get_window_ref(MAIN_WINDOW_ID) {
+        Ok(window) => window,
+        Err(err) => {
+            log_error!(
+                "Failed to get the main window when initializing the Vulkan instance create info: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+    };
+    // Every window shares the same window system, thus the same extensions
+    let mut required_extensions = match window.vulkan_get_required_instance_extensions() {
         Ok(extensions) => extensions,
         Err(err) => {
             log_error!(
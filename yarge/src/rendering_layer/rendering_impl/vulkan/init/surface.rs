@@ -11,19 +11,28 @@ pub(in crate::rendering_layer::rendering_impl::vulkan) struct VkSurface {
     pub(in crate::rendering_layer::rendering_impl::vulkan) surface: ash::vk::SurfaceKHR,
 }
 
-/// Initializes the surface
+/// Initializes the surface of a window
 pub(in crate::rendering_layer::rendering_impl::vulkan) fn init_surface(
     platform_layer: &PlatformLayerImpl,
+    window_id: u8,
     entry: &ash::Entry,
     instance: &ash::Instance,
     allocator: Option<&ash::vk::AllocationCallbacks<'_>>,
 ) -> Result<VkSurface, ErrorType> {
     let surface_instance = ash::khr::surface::Instance::new(entry, instance);
 
-    let surface = match platform_layer
-        .get_window_ref(0u8)
-        .vulkan_get_surface(entry, instance, allocator)
-    {
+    let window = match platform_layer.get_window_ref(window_id) {
+        Ok(window) => window,
+        Err(err) => {
+            log_error!(
+                "Failed to get the window `{:?}' when initializing the Vulkan surface: {:?}",
+                window_id,
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+    };
+    let surface = match window.vulkan_get_surface(entry, instance, allocator) {
         Ok(surface) => surface,
         Err(err) => {
             log_error!(
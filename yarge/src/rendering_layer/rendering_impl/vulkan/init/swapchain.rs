@@ -101,7 +101,7 @@ fn select_surface_format(
         'inner_loop: for (index, prefered) in config
             .renderer_config
             .vulkan_parameters
-            .prefered_swapchain_formats
+            .preferred_swapchain_formats
             .iter()
             .enumerate()
         {
@@ -143,7 +143,7 @@ fn select_present_mode(
         'inner_loop: for (index, prefered) in config
             .renderer_config
             .vulkan_parameters
-            .prefered_swapchain_present_modes
+            .preferred_swapchain_present_modes
             .iter()
             .enumerate()
         {
@@ -167,19 +167,15 @@ fn select_present_mode(
 
 /// Selects the best extent
 fn select_extent(
-    platform_layer_impl: &PlatformLayerImpl,
+    window: &impl Window,
     capabilities: &ash::vk::SurfaceCapabilitiesKHR,
 ) -> ash::vk::Extent2D {
     if capabilities.current_extent.width != u32::MAX {
         return capabilities.current_extent;
     }
 
-    let width = platform_layer_impl
-        .get_window_ref(0)
-        .get_framebuffer_width() as u32;
-    let height = platform_layer_impl
-        .get_window_ref(0)
-        .get_framebuffer_height() as u32;
+    let width = window.get_framebuffer_width() as u32;
+    let height = window.get_framebuffer_height() as u32;
 
     ash::vk::Extent2D {
         width: width.clamp(
@@ -202,7 +198,7 @@ fn select_min_image_count(
     let min_image_count = config
         .renderer_config
         .vulkan_parameters
-        .prefered_swapchain_min_image_count;
+        .preferred_swapchain_min_image_count;
     let min_image_count = std::cmp::max(min_image_count, capabilities.min_image_count + 1);
     // If can't take at least `min_image_count', take the max image count
     if capabilities.max_image_count > 0 && min_image_count < capabilities.max_image_count {
@@ -263,11 +259,12 @@ fn select_pre_transform(
     }
 }
 
-/// Initializes the Vulkan swapchain
+/// Initializes the Vulkan swapchain of a window
 #[allow(clippy::too_many_arguments)]
 pub(in crate::rendering_layer::rendering_impl::vulkan) fn init_swapchain(
     config: &crate::config::Config,
     platform_layer_impl: &PlatformLayerImpl,
+    window_id: u8,
     entry: &ash::Entry,
     instance: &ash::Instance,
     physical_device: &ash::vk::PhysicalDevice,
@@ -303,7 +300,18 @@ pub(in crate::rendering_layer::rendering_impl::vulkan) fn init_swapchain(
     log_info!("\t- {:?}", best_surface_format);
     let best_present_mode = select_present_mode(config, &available_present_modes);
     log_info!("\t- {:?}", best_present_mode);
-    let best_extent = select_extent(platform_layer_impl, &surface_capabilities);
+    let window = match platform_layer_impl.get_window_ref(window_id) {
+        Ok(window) => window,
+        Err(err) => {
+            log_error!(
+                "Failed to get the window `{:?}' when initializing the Vulkan swapchain: {:?}",
+                window_id,
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+    };
+    let best_extent = select_extent(window, &surface_capabilities);
     log_info!("\t- {:?}", best_extent);
     let min_image_count = select_min_image_count(config, &surface_capabilities);
     log_info!("\t- Image count: {:?}", min_image_count);
@@ -383,4 +383,49 @@ impl<'a> RenderingLayer<'a> for VulkanRenderingLayer<'a> {
         // TODO: swap buffer and present to screen
         Ok(())
     }
+
+    fn update_present_mode(
+        &mut self,
+        config: &Config,
+        platform_layer: &mut PlatformLayerImpl,
+    ) -> Result<(), ErrorType> {
+        // The present mode is chosen when creating the swapchain
+        if let Err(err) = self.context.recreate_swapchain(config, platform_layer) {
+            log_error!(
+                "Failed to recreate the Vulkan swapchain when updating the present mode: {:?}",
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
+
+    fn init_window(
+        &mut self,
+        config: &Config,
+        platform_layer: &mut PlatformLayerImpl,
+        window_id: u8,
+    ) -> Result<(), ErrorType> {
+        if let Err(err) = self.context.init_window(config, platform_layer, window_id) {
+            log_error!(
+                "Failed to initialize the Vulkan context of the window `{:?}': {:?}",
+                window_id,
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
+
+    fn shutdown_window(&mut self, window_id: u8) -> Result<(), ErrorType> {
+        if let Err(err) = self.context.shutdown_window(window_id) {
+            log_error!(
+                "Failed to shutdown the Vulkan context of the window `{:?}': {:?}",
+                window_id,
+                err
+            );
+            return Err(ErrorType::Unknown);
+        }
+        Ok(())
+    }
 }
@@ -1,6 +1,8 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
     config::Version,
     renderer_types::{formats::ImageFormat, present::PresentMode, usages::ImageUsage},
@@ -13,7 +15,8 @@ use crate::{
     },
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 /// The config for a Vulkan context
 pub(crate) struct VulkanConfig {
     /// The Vulkan version
@@ -40,12 +43,12 @@ pub(crate) struct VulkanConfig {
     pub(crate) required_device_features_ext: Vec<VkFeaturesExt>,
     /// The required device extensions in normal mode
     pub(crate) required_device_extensions: Vec<VkDeviceExtensions>,
-    /// The prefered swapchain formats in order of preference
-    pub(crate) prefered_swapchain_formats: Vec<ImageFormat>,
-    /// The prefered swapchain present modes in order of preference
-    pub(crate) prefered_swapchain_present_modes: Vec<PresentMode>,
-    /// The prefered minimum number of images in the swapchain
-    pub(crate) prefered_swapchain_min_image_count: u32,
+    /// The preferred swapchain formats in order of preference
+    pub(crate) preferred_swapchain_formats: Vec<ImageFormat>,
+    /// The preferred swapchain present modes in order of preference
+    pub(crate) preferred_swapchain_present_modes: Vec<PresentMode>,
+    /// The preferred minimum number of images in the swapchain
+    pub(crate) preferred_swapchain_min_image_count: u32,
     /// The image usage flags for the swapchain
     pub(crate) swapchain_image_usages: Vec<ImageUsage>,
 }
@@ -122,13 +125,13 @@ impl Default for VulkanConfig {
             VkDeviceExtensions::KhrCreateRenderpass2,
         ];
 
-        let prefered_swapchain_formats =
+        let preferred_swapchain_formats =
             vec![ImageFormat::R8G8B8A8_SRGB, ImageFormat::B8G8R8A8_SRGB];
 
-        let prefered_swapchain_present_modes =
+        let preferred_swapchain_present_modes =
             vec![PresentMode::TripleBuffering, PresentMode::Vsync];
 
-        let prefered_swapchain_min_image_count = 3u32;
+        let preferred_swapchain_min_image_count = 3u32;
 
         let swapchain_image_usages = vec![
             ImageUsage::ColorAttachment,
@@ -150,9 +153,9 @@ impl Default for VulkanConfig {
             required_physical_device_features_1_4,
             required_device_features_ext,
             required_device_extensions,
-            prefered_swapchain_formats,
-            prefered_swapchain_present_modes,
-            prefered_swapchain_min_image_count,
+            preferred_swapchain_formats,
+            preferred_swapchain_present_modes,
+            preferred_swapchain_min_image_count,
             swapchain_image_usages,
         }
     }
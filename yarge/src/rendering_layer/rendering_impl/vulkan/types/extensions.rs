@@ -1,6 +1,8 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Serialize};
+
 use crate::rendering_layer::rendering_impl::types::{VkNames, convert_string_to_vknames};
 
 /// Custom trait for Vulkan extensions
@@ -18,7 +20,7 @@ pub(crate) trait VkExtension: Sized {
 }
 
 /// Custom enum for Vulkan Instance Extensions
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkInstanceExtensions {
     /// Khronos surface instance extension
@@ -42,6 +44,9 @@ pub(crate) enum VkInstanceExtensions {
     /// Extension to setup a debug messenger callback
     /// https://docs.vulkan.org/refpages/latest/refpages/source/VK_EXT_debug_utils.html
     ExtDebugUtils,
+    /// Extension to create surfaces without any window system
+    /// https://docs.vulkan.org/refpages/latest/refpages/source/VK_EXT_headless_surface.html
+    ExtHeadlessSurface,
 }
 
 impl VkExtension for VkInstanceExtensions {
@@ -54,12 +59,13 @@ impl VkExtension for VkInstanceExtensions {
             VkInstanceExtensions::KhrXcbSurface => String::from("VK_KHR_xcb_surface"),
             VkInstanceExtensions::KhrXlibSurface => String::from("VK_KHR_xlib_surface"),
             VkInstanceExtensions::ExtDebugUtils => String::from("VK_EXT_debug_utils"),
+            VkInstanceExtensions::ExtHeadlessSurface => String::from("VK_EXT_headless_surface"),
         }
     }
 }
 
 /// Custom enum for Vulkan Device Extensions
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkDeviceExtensions {
     /// Enables the use of SwapchainKHR objects which provide the ability to present rendering results to a surface
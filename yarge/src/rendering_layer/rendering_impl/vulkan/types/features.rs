@@ -1,9 +1,11 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Serialize};
+
 /// Custom enum for Vulkan core 1.0 features names
 /// https://docs.vulkan.org/refpages/latest/refpages/source/VkPhysicalDeviceFeatures.html
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkFeatures10 {
     /// Enables robust bounds checking for buffer accesses in shaders
@@ -325,7 +327,7 @@ impl VkFeatures10 {
 
 /// Custom enum for Vulkan 1.1 features names
 /// https://docs.vulkan.org/spec/latest/chapters/features.html#VkPhysicalDeviceVulkan11Features
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkFeatures11 {
     /// Allows protected memory allocations and resources
@@ -420,7 +422,7 @@ impl VkFeatures11 {
 
 /// Custom enum for Vulkan 1.2 features names
 /// https://docs.vulkan.org/spec/latest/chapters/features.html#VkPhysicalDeviceVulkan12Features
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkFeatures12 {
     /// Enables sampler mirror clamp-to-edge addressing mode
@@ -730,7 +732,7 @@ impl VkFeatures12 {
 
 /// Custom enum for Vulkan 1.3 features names
 /// https://docs.vulkan.org/spec/latest/chapters/features.html#VkPhysicalDeviceVulkan13Features
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkFeatures13 {
     /// Enables robust image access bounds checking for shader image loads
@@ -843,7 +845,7 @@ impl VkFeatures13 {
 
 /// Custom enum for Vulkan 1.4 features names
 /// https://docs.vulkan.org/spec/latest/chapters/features.html#VkPhysicalDeviceVulkan14Features
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkFeatures14 {
     /// Support querying global queue priorities
@@ -949,7 +951,7 @@ pub(crate) enum VkFeatures14 {
 // }
 
 /// Custom enum for Vulkan features names coming from extensions
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkFeaturesExt {
     /// https://docs.vulkan.org/refpages/latest/refpages/source/VkPhysicalDeviceExtendedDynamicStateFeaturesEXT.html
@@ -1,10 +1,12 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
+use serde::{Deserialize, Serialize};
+
 use crate::rendering_layer::rendering_impl::types::{VkNames, convert_string_to_vknames};
 
 /// Custom enum for Vulkan layers names
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkLayers {
     /// Khronos validation layers
@@ -40,7 +42,7 @@ impl VkLayers {
 }
 
 /// Custom enum for Vulkan validation layers settings
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkValidationLayerSettings {
     /// Checks threads
@@ -67,7 +69,7 @@ pub(crate) enum VkValidationLayerSettings {
 }
 
 /// Custom enum for Vulkan validation layers synchronization settings
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkValidationLayerSyncValSettings {
     /// Takes into account memory accesses performed by the shader based on SPIR-V static analysis
@@ -79,7 +81,7 @@ pub(crate) enum VkValidationLayerSyncValSettings {
 }
 
 /// Custom enum for Vulkan validation layers debug printf settings
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkValidationLayerPrintfSettings {
     /// Enables redirection of Debug Printf messages from the debug callback to stdout
@@ -91,7 +93,7 @@ pub(crate) enum VkValidationLayerPrintfSettings {
 }
 
 /// Custom enum for Vulkan validation layers gpu assisted settings
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkValidationLayerGpuavSettings {
     /// Tries to prevent crashes
@@ -106,7 +108,7 @@ pub(crate) enum VkValidationLayerGpuavSettings {
 }
 
 /// Custom enum for Vulkan validation layers best practices settings
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[allow(unused)]
 pub(crate) enum VkValidationLayerBestPracticesSettings {
     /// Specific for Arm GPUs
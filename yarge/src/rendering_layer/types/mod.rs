@@ -1,8 +1,10 @@
 #[allow(unused)]
 use crate::{error::ErrorType, log_debug, log_error, log_info, log_warn};
 
-pub(crate) mod formats;
-pub(crate) mod present;
+use serde::{Deserialize, Serialize};
+
+pub mod formats;
+pub mod present;
 pub(crate) mod usages;
 
 /// The possible output for the begin frame
@@ -31,8 +33,8 @@ pub(crate) enum PrimitiveTopology {
 }
 
 /// The type of rendering application
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub(crate) enum RenderingApplicationType {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum RenderingApplicationType {
     /// For most of 3D applications, when displaying to a single screen, no AR, no VR, ...
     #[default]
     Classic3D,
@@ -43,7 +45,7 @@ pub(crate) enum RenderingApplicationType {
 }
 
 /// The transform to apply to the final image before presentation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub(crate) enum FinalTransform {
     /// Automatic depending on the hardware
     #[default]